@@ -2,22 +2,31 @@
 
 use crate::error::Result;
 use crate::events::{key_event_to_action, Action, AppEvent};
-use crate::systemd::{JournalReader, LogLine, ServiceController, SystemdClient};
-use crate::ui::{DashboardState, DetailAction, DetailState, LogsAction, LogsState, HelpState, NewServiceForm};
+use crate::diff::{diff_lines, diff_stats, unified};
+use crate::systemd::dropin::{self, DropInEdit};
+use crate::systemd::lint::{self, LintIssue};
+use crate::systemd::{JobResult, JournalReader, UnitFile, UnitKind, KillSignal, KillTarget, LogLine, MetricsCollector, ResourceLimit, ServiceController, ServiceScope, SystemdClient, TransientSpec};
+use crate::ui::{ColumnSettings, DashboardState, DetailAction, DetailState, DiffAction, DiffState, ListTab, LogsAction, LogsState, HelpState, JobsAction, JobsState, NewServiceForm, SocketsAction, SocketsState, TimersAction, TimersState, UnitFileAction, UnitFileView};
 use crossterm::event::Event as CrosstermEvent;
 use ratatui::{layout::{Constraint, Direction, Layout}, style::Style, widgets::{Block, Borders}};
 use ratatui::Frame;
-use tokio::sync::mpsc;
+use std::time::Duration;
+use tokio::sync::{mpsc, watch};
 use anyhow;
 
 /// Application views
 #[derive(Debug)]
 pub enum View {
     Dashboard(DashboardState),
+    Timers(TimersState),
+    Sockets(SocketsState),
+    Jobs(JobsState),
     Detail(Box<DetailState>),
     Logs(LogsState),
     Help(HelpState),
     NewService(NewServiceForm),
+    Diff(DiffState),
+    UnitFile(UnitFileView),
 }
 
 impl View {
@@ -48,6 +57,18 @@ pub struct App {
     pub journal_reader: Option<JournalReader>,
     pub status_message: Option<String>,
     pub needs_full_redraw: bool,
+    /// True once systemd signals keep the dashboard current (no tick polling)
+    pub live_updates: bool,
+    /// List tab that detail views return to
+    pub list_tab: ListTab,
+    /// Override edit waiting for the main loop to hand the terminal to $EDITOR
+    pub pending_edit: Option<DropInEdit>,
+    /// Unit the metrics poller samples; None until the poller is started
+    metrics_target: Option<watch::Sender<Option<(String, ServiceScope)>>>,
+    /// Units the dashboard's resource columns need sampled
+    resources_target: Option<watch::Sender<Vec<(String, ServiceScope)>>>,
+    /// Dashboard columns and sort order, kept across view switches
+    dashboard_columns: ColumnSettings,
 }
 
 impl App {
@@ -64,6 +85,12 @@ impl App {
             journal_reader: None,
             status_message: None,
             needs_full_redraw: true,
+            live_updates: false,
+            list_tab: ListTab::Services,
+            pending_edit: None,
+            metrics_target: None,
+            resources_target: None,
+            dashboard_columns: ColumnSettings::default(),
         })
     }
 
@@ -77,9 +104,52 @@ impl App {
                     dashboard.set_services(services);
                 }
             }
+            AppEvent::TimersLoaded(timers) => {
+                if let View::Timers(timers_view) = &mut self.view {
+                    timers_view.set_timers(timers);
+                }
+            }
+            AppEvent::SocketsLoaded(sockets) => {
+                if let View::Sockets(sockets_view) = &mut self.view {
+                    sockets_view.set_sockets(sockets);
+                }
+            }
+            AppEvent::JobsLoaded(jobs) => {
+                if let View::Jobs(jobs_view) = &mut self.view {
+                    jobs_view.set_jobs(jobs);
+                }
+            }
+            AppEvent::JobsChanged => {
+                if matches!(self.view, View::Jobs(_)) {
+                    self.reload_jobs();
+                }
+            }
+            AppEvent::UnitWatchStopped(scope) => {
+                tracing::warn!("Lost {} manager signals, polling instead", scope.label());
+                self.live_updates = false;
+            }
+            AppEvent::ServiceUpdated(service) => {
+                match &mut self.view {
+                    View::Dashboard(dashboard) => dashboard.upsert_service(service),
+                    View::Detail(detail) => detail.update_service(service),
+                    _ => {}
+                }
+            }
+            AppEvent::ServiceRemoved { id } => {
+                if let View::Dashboard(dashboard) = &mut self.view {
+                    dashboard.remove_service(&id);
+                }
+            }
             AppEvent::ServiceDetailLoaded(detail) => {
                 if let View::Detail(detail_view) = &mut self.view {
-                    detail_view.set_detail(*detail);
+                    // A background refresh must not replace another unit's detail
+                    let same_unit = match &detail_view.detail {
+                        Some(current) => current.service.name == detail.service.name,
+                        None => true,
+                    };
+                    if same_unit {
+                        detail_view.set_detail(*detail);
+                    }
                 }
             }
             AppEvent::LogLine(line) => {
@@ -105,10 +175,20 @@ impl App {
                 self.journal_reader = Some(reader);
             }
             AppEvent::Tick => {
-                // Reload services on tick (only in dashboard view)
-                if matches!(self.view, View::Dashboard(_)) {
+                // Reload services on tick (only in dashboard view, and only
+                // when systemd signals are not already keeping it current)
+                if !self.live_updates && matches!(self.view, View::Dashboard(_)) {
                     self.reload_services().await?;
                 }
+                // Timers and sockets are not signal-driven; poll so elapse
+                // times and connection counters stay current
+                match self.view {
+                    View::Timers(_) => self.reload_timers(),
+                    View::Sockets(_) => self.reload_sockets(),
+                    // Without the signal watcher, poll the job queue instead
+                    View::Jobs(_) if !self.live_updates => self.reload_jobs(),
+                    _ => {}
+                }
             }
             AppEvent::Quit => {
                 self.should_quit = true;
@@ -120,7 +200,7 @@ impl App {
                     if detail.loading {
                         self.status_message = Some(format!("✗ {}", err));
                         self.needs_full_redraw = true;
-                        self.view = View::Dashboard(DashboardState::new());
+                        self.view = View::Dashboard(self.new_dashboard());
                         // Reload services
                         let tx = self.tx.clone();
                         let client = self.client.clone();
@@ -132,8 +212,9 @@ impl App {
                     }
                 }
             }
-            AppEvent::ServiceOperationCompleted { service: _, operation: _, success: _ } => {
-                // Service operation completed - could show temporary status here
+            AppEvent::ServiceOperationCompleted { service, operation, success, message } => {
+                tracing::info!("{} {} finished (success: {}): {}", operation, service, success, message);
+                self.status_message = Some(message);
             }
             AppEvent::RequestConfirmation { service: _, operation: _, message: _ } => {
                 // Could implement global confirmation dialog here
@@ -144,12 +225,40 @@ impl App {
             }
             AppEvent::ServiceCreated { name } => {
                 self.needs_full_redraw = true;
-                self.view = View::Dashboard(DashboardState::new());
+                self.view = View::Dashboard(self.new_dashboard());
                 self.status_message = Some(format!("✓ Service {} created successfully", name));
                 // Reload services to show the new one
                 let services = self.client.list_services().await?;
                 self.tx.send(AppEvent::ServicesLoaded(services)).await.ok();
             }
+            AppEvent::MetricsUpdated { service, scope, history } => {
+                if let View::Detail(detail) = &mut self.view {
+                    detail.set_metrics_history(&service, scope, history);
+                }
+            }
+            AppEvent::CgroupUpdated { service, scope, stats } => {
+                if let View::Detail(detail) = &mut self.view {
+                    detail.set_cgroup_stats(&service, scope, stats);
+                }
+            }
+            AppEvent::ResourcesUpdated(resources) => {
+                if let View::Dashboard(dashboard) = &mut self.view {
+                    if dashboard.columns.show_resources {
+                        dashboard.set_resources(resources);
+                    }
+                }
+            }
+            AppEvent::TransientStarted { name, scope } => {
+                self.switch_to_logs(name.clone(), scope);
+                self.status_message = Some(format!("✓ Started transient unit {}", name));
+            }
+            AppEvent::UnitDeleted { name } => {
+                self.needs_full_redraw = true;
+                self.view = View::Dashboard(self.new_dashboard());
+                self.status_message = Some(format!("✓ Removed {}", name));
+                let services = self.client.list_services().await?;
+                self.tx.send(AppEvent::ServicesLoaded(services)).await.ok();
+            }
             AppEvent::ServiceCreationFailed { error } => {
                 // Show error in the form
                 if let View::NewService(form) = &mut self.view {
@@ -162,11 +271,98 @@ impl App {
             }
         }
 
+        if let Some(dashboard) = self.view.dashboard() {
+            self.dashboard_columns = dashboard.columns;
+        }
+        self.update_metrics_target();
         Ok(())
     }
 
+    /// Fresh dashboard with the columns and sort order last used
+    fn new_dashboard(&self) -> DashboardState {
+        let mut dashboard = DashboardState::new();
+        dashboard.columns = self.dashboard_columns;
+        dashboard
+    }
+
+    /// Start polling metrics for the detail view and the dashboard's resource columns
+    pub async fn start_metrics_poller(&mut self, interval: Duration) {
+        match MetricsCollector::new().await {
+            Ok(collector) => {
+                let (target, rx) = watch::channel(None);
+                let (units, units_rx) = watch::channel(Vec::new());
+                collector.clone().spawn_batch_poller(interval, units_rx, self.tx.clone());
+                collector.spawn_poller(interval, rx, self.tx.clone());
+                self.metrics_target = Some(target);
+                self.resources_target = Some(units);
+            }
+            Err(e) => tracing::warn!("Live metrics unavailable: {}", e),
+        }
+    }
+
+    /// Point the metrics pollers at what the current view shows, or pause them
+    fn update_metrics_target(&self) {
+        if let Some(units) = &self.resources_target {
+            let wanted = self.view.dashboard().map(|d| d.resource_targets()).unwrap_or_default();
+            units.send_if_modified(|current| {
+                if *current == wanted {
+                    return false;
+                }
+                *current = wanted;
+                true
+            });
+        }
+
+        let Some(target) = &self.metrics_target else {
+            return;
+        };
+        let wanted = match &self.view {
+            View::Detail(detail) => detail
+                .detail
+                .as_ref()
+                .filter(|d| d.service.kind.has_cgroup())
+                .map(|d| (d.service.name.clone(), d.service.scope)),
+            _ => None,
+        };
+        target.send_if_modified(|current| {
+            if *current == wanted {
+                return false;
+            }
+            *current = wanted;
+            true
+        });
+    }
+
     fn handle_input(&mut self, event: CrosstermEvent) -> Result<()> {
         if let CrosstermEvent::Key(key_event) = event {
+            // Special handling for the run dialog in dashboard
+            if let View::Dashboard(dashboard) = &mut self.view {
+                if let Some(dialog) = &mut dashboard.run_dialog {
+                    use crossterm::event::{KeyCode, KeyModifiers};
+                    match key_event.code {
+                        KeyCode::Char(c) if key_event.modifiers == KeyModifiers::NONE || key_event.modifiers == KeyModifiers::SHIFT => {
+                            dialog.handle_char(c);
+                        }
+                        KeyCode::Backspace => dialog.backspace(),
+                        KeyCode::Down | KeyCode::Tab => dialog.next_field(),
+                        KeyCode::Up | KeyCode::BackTab => dialog.prev_field(),
+                        KeyCode::Left | KeyCode::Right => dialog.toggle_choice(),
+                        KeyCode::Enter => {
+                            if let Some(spec) = dialog.spec() {
+                                let scope = dialog.scope;
+                                dashboard.run_dialog = None;
+                                self.spawn_run_transient(spec, scope);
+                            }
+                        }
+                        KeyCode::Esc => {
+                            dashboard.run_dialog = None;
+                        }
+                        _ => {}
+                    }
+                    return Ok(());
+                }
+            }
+
             // Special handling for search mode in dashboard
             if let View::Dashboard(dashboard) = &mut self.view {
                 if dashboard.searching {
@@ -214,6 +410,10 @@ impl App {
                         form.handle_key('\n');
                         return Ok(());
                     }
+                    KeyCode::BackTab => {
+                        form.handle_special_key("backtab");
+                        return Ok(());
+                    }
                     KeyCode::Up => {
                         form.handle_special_key("up");
                         return Ok(());
@@ -234,6 +434,56 @@ impl App {
                 }
             }
 
+            // Special handling for the remove prompt and resource limit editor in detail view
+            if let View::Detail(detail) = &mut self.view {
+                if let Some(dialog) = &mut detail.remove_dialog {
+                    use crossterm::event::{KeyCode, KeyModifiers};
+                    match key_event.code {
+                        KeyCode::Char(c) if key_event.modifiers == KeyModifiers::NONE || key_event.modifiers == KeyModifiers::SHIFT => {
+                            dialog.handle_char(c);
+                        }
+                        KeyCode::Backspace => dialog.backspace(),
+                        KeyCode::Enter if dialog.confirmed() => {
+                            let (service, scope, timer) = (dialog.service.clone(), dialog.scope, dialog.timer.clone());
+                            detail.remove_dialog = None;
+                            self.spawn_remove_unit(service, scope, timer);
+                        }
+                        KeyCode::Esc => {
+                            detail.remove_dialog = None;
+                        }
+                        _ => {}
+                    }
+                    return Ok(());
+                }
+
+                if let Some(editor) = &mut detail.limits_editor {
+                    use crossterm::event::{KeyCode, KeyModifiers};
+                    match key_event.code {
+                        KeyCode::Char('r') if key_event.modifiers == KeyModifiers::CONTROL => {
+                            editor.toggle_runtime();
+                        }
+                        KeyCode::Char(c) if key_event.modifiers == KeyModifiers::NONE || key_event.modifiers == KeyModifiers::SHIFT => {
+                            editor.handle_char(c);
+                        }
+                        KeyCode::Backspace => editor.backspace(),
+                        KeyCode::Down | KeyCode::Tab => editor.next_field(),
+                        KeyCode::Up | KeyCode::BackTab => editor.prev_field(),
+                        KeyCode::Enter => {
+                            if let Some(limits) = editor.changes() {
+                                let (service, scope, runtime) = (editor.service.clone(), editor.scope, editor.runtime);
+                                detail.limits_editor = None;
+                                self.spawn_set_limits(service, scope, runtime, limits);
+                            }
+                        }
+                        KeyCode::Esc => {
+                            detail.limits_editor = None;
+                        }
+                        _ => {}
+                    }
+                    return Ok(());
+                }
+            }
+
             let action = key_event_to_action(key_event);
 
             match action {
@@ -252,13 +502,20 @@ impl App {
                     if let View::NewService(form) = &mut self.view {
                         if let Err(e) = form.validate() {
                             form.set_error(e);
+                        } else if !form.check_overwrite() {
+                            // Diff of the existing file is now shown; Ctrl+S again confirms
                         } else {
                             // Spawn async task to create the service
                             let form_data = form.clone();
                             let tx = self.tx.clone();
+                            let controller = self.controller.clone();
 
                             tokio::spawn(async move {
-                                match create_user_service_async(&form_data).await {
+                                let result = match form_data.scope {
+                                    ServiceScope::User => create_user_service_async(&form_data).await,
+                                    ServiceScope::System => create_system_service_async(&form_data, &controller).await,
+                                };
+                                match result {
                                     Ok(()) => {
                                         tx.send(AppEvent::ServiceCreated { name: form_data.name.clone() }).await.ok();
                                     }
@@ -270,6 +527,16 @@ impl App {
                         }
                     }
                 }
+                Action::NextTab | Action::PrevTab if matches!(self.view, View::Dashboard(_) | View::Timers(_) | View::Sockets(_) | View::Jobs(_)) => {
+                    let current = match self.view {
+                        View::Timers(_) => ListTab::Timers,
+                        View::Sockets(_) => ListTab::Sockets,
+                        View::Jobs(_) => ListTab::Jobs,
+                        _ => ListTab::Services,
+                    };
+                    let tab = if action == Action::NextTab { current.next() } else { current.prev() };
+                    self.switch_to_tab(tab);
+                }
                 Action::Back | Action::GoBack if matches!(self.view, View::Dashboard(_) | View::NewService(_) | View::Help(_)) => {
                     // Handle back/escape for Dashboard, NewService, and Help views
                     match &mut self.view {
@@ -285,7 +552,7 @@ impl App {
                         }
                         View::NewService(_) | View::Help(_) => {
                             self.needs_full_redraw = true;
-                            self.view = View::Dashboard(DashboardState::new());
+                            self.view = View::Dashboard(self.new_dashboard());
                             let tx = self.tx.clone();
                             let client = self.client.clone();
                             tokio::spawn(async move {
@@ -302,21 +569,25 @@ impl App {
                     // Delegate to current view
                     match &mut self.view {
                         View::Dashboard(dashboard) => {
-                            if let Some(service_name) = dashboard.handle_action(action) {
+                            if let Some((service_name, scope)) = dashboard.handle_action(action) {
                                 // Switch to detail view
-                                self.switch_to_detail(service_name);
+                                self.list_tab = ListTab::Services;
+                                self.switch_to_detail(service_name, scope);
                             }
                         }
                         View::Detail(detail) => {
                             let detail_action = detail.handle_action(action);
                             match detail_action {
+                                DetailAction::GoBack if self.list_tab != ListTab::Services => {
+                                    self.switch_to_tab(self.list_tab);
+                                }
                                 DetailAction::GoBack => {
                                     // Switch back to dashboard, preserving existing state
                                     let preserved_filter = self.view.dashboard().map(|d| d.filter.clone());
                                     let preserved_search = self.view.dashboard().map(|d| d.search_term.clone());
                                     let preserved_selection = self.view.dashboard().and_then(|d| d.table_state.selected());
 
-                                    let mut new_dashboard = DashboardState::new();
+                                    let mut new_dashboard = self.new_dashboard();
                                     if let Some(filter) = preserved_filter {
                                         new_dashboard.filter = filter;
                                     }
@@ -341,61 +612,90 @@ impl App {
                                         }
                                     });
                                 }
-                                DetailAction::ViewLogs(service_name) => {
+                                DetailAction::ViewLogs(service_name, scope) => {
                                     // Switch to logs view
-                                    self.switch_to_logs(service_name);
+                                    self.switch_to_logs(service_name, scope);
                                 }
-                                DetailAction::ExecuteServiceControl { service, operation } => {
-                                    // Execute service control operation in background
-                                    let controller = self.controller.clone();
-                                    let tx = self.tx.clone();
-                                    let client = self.client.clone();
-
-                                    tokio::spawn(async move {
-                                        let result = match operation.as_str() {
-                                            "start" => controller.start_service(&service).await,
-                                            "stop" => controller.stop_service(&service).await,
-                                            "restart" => controller.restart_service(&service).await,
-                                            "enable" => controller.enable_service(&service).await,
-                                            "disable" => controller.disable_service(&service).await,
-                                            "reload" => controller.reload_service(&service).await,
-                                            _ => Err(anyhow::anyhow!("Unknown operation: {}", operation)),
-                                        };
-
-                                        let success = result.is_ok();
-
-                                        // Send completion event
-                                        tx.send(AppEvent::ServiceOperationCompleted {
-                                            service: service.clone(),
-                                            operation: operation.clone(),
-                                            success,
-                                        }).await.ok();
-
-                                        // Send status message
-                                        let message = if success {
-                                            format!("✓ Service '{}' {} successfully", service, operation)
-                                        } else {
-                                            let error = result.unwrap_err().to_string();
-                                            // Truncate very long error messages
-                                            let error_display = if error.len() > 150 {
-                                                format!("{}...", &error[..150])
-                                            } else {
-                                                error
-                                            };
-                                            format!("✗ Failed to {} service '{}': {}", operation, service, error_display)
-                                        };
-
-                                        tx.send(AppEvent::StatusMessage(message)).await.ok();
-
-                                        // Reload services to update status
-                                        if let Ok(services) = client.list_services().await {
-                                            tx.send(AppEvent::ServicesLoaded(services)).await.ok();
+                                DetailAction::ExecuteServiceControl { service, operation, scope } => {
+                                    self.spawn_service_control(service, operation, scope);
+                                }
+                                DetailAction::KillUnit { service, scope, target, signal } => {
+                                    self.spawn_kill(service, scope, target, signal);
+                                }
+                                DetailAction::ViewUnitFile { service, scope, fragment, drop_ins } => {
+                                    // Unit files are small; read them directly
+                                    let files = UnitFile::read_all(&fragment, &drop_ins);
+                                    self.status_message = None;
+                                    self.needs_full_redraw = true;
+                                    self.view = View::UnitFile(UnitFileView::new(service, scope, files));
+                                }
+                                DetailAction::VendorDiff { service, scope, vendor, local, notes } => {
+                                    let vendor_file = UnitFile::read(&vendor);
+                                    let local_file = UnitFile::read(&local);
+                                    match vendor_file.error.as_ref().or(local_file.error.as_ref()) {
+                                        Some(e) => {
+                                            self.status_message = Some(format!("✗ Cannot diff '{}': {}", service, e));
                                         }
-                                    });
+                                        None => {
+                                            let lines = unified(&diff_lines(&vendor_file.content, &local_file.content), 3);
+                                            let mut diff = DiffState::new(format!("{} → {}", vendor, local), service, scope, lines);
+                                            diff.notes = notes;
+                                            self.status_message = None;
+                                            self.needs_full_redraw = true;
+                                            self.view = View::Diff(diff);
+                                        }
+                                    }
+                                }
+                                DetailAction::EditOverride { service, scope } => {
+                                    match DropInEdit::prepare(&service, scope) {
+                                        Ok(edit) => self.pending_edit = Some(edit),
+                                        Err(e) => {
+                                            self.status_message = Some(format!("✗ Cannot edit override for '{}': {}", service, e));
+                                        }
+                                    }
                                 }
                                 DetailAction::None => {}
                             }
                         }
+                        View::Timers(timers) => {
+                            match timers.handle_action(action) {
+                                TimersAction::ViewUnit(unit, scope) => {
+                                    self.list_tab = ListTab::Timers;
+                                    self.switch_to_detail(unit, scope);
+                                }
+                                TimersAction::GoBack => self.switch_to_tab(ListTab::Services),
+                                TimersAction::ExecuteServiceControl { service, operation, scope } => {
+                                    self.spawn_service_control(service, operation, scope);
+                                }
+                                TimersAction::None => {}
+                            }
+                        }
+                        View::Sockets(sockets) => {
+                            match sockets.handle_action(action) {
+                                SocketsAction::ViewUnit(unit, scope) => {
+                                    self.list_tab = ListTab::Sockets;
+                                    self.switch_to_detail(unit, scope);
+                                }
+                                SocketsAction::GoBack => self.switch_to_tab(ListTab::Services),
+                                SocketsAction::ExecuteServiceControl { service, operation, scope } => {
+                                    self.spawn_service_control(service, operation, scope);
+                                }
+                                SocketsAction::None => {}
+                            }
+                        }
+                        View::Jobs(jobs) => {
+                            match jobs.handle_action(action) {
+                                JobsAction::ViewUnit(unit, scope) => {
+                                    self.list_tab = ListTab::Jobs;
+                                    self.switch_to_detail(unit, scope);
+                                }
+                                JobsAction::GoBack => self.switch_to_tab(ListTab::Services),
+                                JobsAction::CancelJob { id, unit, scope } => {
+                                    self.spawn_cancel_job(id, unit, scope);
+                                }
+                                JobsAction::None => {}
+                            }
+                        }
                         View::Logs(logs) => {
                             let logs_action = logs.handle_action(action.into());
                             // Handle filter actions that require journal restart
@@ -408,12 +708,13 @@ impl App {
                                     });
                                 }
                                 let service_name = logs.service_name.clone();
+                                let scope = logs.scope;
                                 let tx = self.tx.clone();
                                 let min_priority = logs.filters.min_priority;
                                 let since = logs.filters.since.clone();
                                 let until = logs.filters.until.clone();
                                 tokio::spawn(async move {
-                                    match JournalReader::stream_logs(service_name, tx.clone(), true, min_priority, since, until).await {
+                                    match JournalReader::stream_logs(service_name, scope, tx.clone(), true, min_priority, since, until).await {
                                         Ok(reader) => {
                                             tx.send(AppEvent::JournalReaderStarted(reader)).await.ok();
                                         }
@@ -435,7 +736,7 @@ impl App {
                                 let preserved_search = self.view.dashboard().map(|d| d.search_term.clone());
                                 let preserved_selection = self.view.dashboard().and_then(|d| d.table_state.selected());
                                 
-                                let mut new_dashboard = DashboardState::new();
+                                let mut new_dashboard = self.new_dashboard();
                                 if let Some(filter) = preserved_filter {
                                     new_dashboard.filter = filter;
                                 }
@@ -466,7 +767,7 @@ impl App {
                                     // Clear status message when switching views
                                     self.status_message = None;
                                     self.needs_full_redraw = true;
-                                    self.view = View::Dashboard(DashboardState::new());
+                                    self.view = View::Dashboard(self.new_dashboard());
                                     let tx = self.tx.clone();
                                     let client = self.client.clone();
                                     tokio::spawn(async move {
@@ -482,6 +783,16 @@ impl App {
                             // Form input is handled at the top level before action conversion
                             // No actions to handle here
                         }
+                        View::Diff(diff) => {
+                            if let DiffAction::GoBack(unit, scope) = diff.handle_action(action) {
+                                self.switch_to_detail(unit, scope);
+                            }
+                        }
+                        View::UnitFile(unit_file) => {
+                            if let UnitFileAction::GoBack(unit, scope) = unit_file.handle_action(action) {
+                                self.switch_to_detail(unit, scope);
+                            }
+                        }
                     }
                 }
             }
@@ -490,7 +801,278 @@ impl App {
         Ok(())
     }
 
-    fn switch_to_detail(&mut self, service_name: String) {
+    /// Switch to the list view for a tab and load its units
+    fn switch_to_tab(&mut self, tab: ListTab) {
+        self.list_tab = tab;
+        self.status_message = None;
+        self.needs_full_redraw = true;
+
+        match tab {
+            ListTab::Services => {
+                self.view = View::Dashboard(self.new_dashboard());
+                let tx = self.tx.clone();
+                let client = self.client.clone();
+                tokio::spawn(async move {
+                    if let Ok(services) = client.list_services().await {
+                        tx.send(AppEvent::ServicesLoaded(services)).await.ok();
+                    }
+                });
+            }
+            ListTab::Timers => {
+                self.view = View::Timers(TimersState::new());
+                self.reload_timers();
+            }
+            ListTab::Sockets => {
+                self.view = View::Sockets(SocketsState::new());
+                self.reload_sockets();
+            }
+            ListTab::Jobs => {
+                self.view = View::Jobs(JobsState::new());
+                self.reload_jobs();
+            }
+        }
+    }
+
+    /// Install the override saved by the editor, reload systemd and show the diff
+    ///
+    /// Called with the terminal still in cooked mode so a pkexec prompt can
+    /// ask for a password.
+    pub async fn finish_drop_in_edit(&mut self, edit: DropInEdit, editor_status: std::io::Result<std::process::ExitStatus>) {
+        self.needs_full_redraw = true;
+        let result = self.apply_drop_in_edit(&edit, editor_status).await;
+        edit.cleanup();
+
+        match result {
+            Ok(Some((content, warnings))) => {
+                let lines = diff_lines(&edit.original, &content);
+                let (added, removed) = diff_stats(&lines);
+                let warning_note = match warnings.first() {
+                    Some(first) => format!(" - ⚠ {} warning(s), first: {}", warnings.len(), first),
+                    None => String::new(),
+                };
+                self.status_message = Some(format!(
+                    "✓ Saved {} and reloaded systemd (+{} −{}){}",
+                    edit.path.display(),
+                    added,
+                    removed,
+                    warning_note
+                ));
+                let mut diff = DiffState::new(edit.path.display().to_string(), edit.unit.clone(), edit.scope, lines);
+                diff.notes = warnings.iter().map(|w| format!("⚠ {}", w)).collect();
+                self.view = View::Diff(diff);
+            }
+            Ok(None) => {
+                self.status_message = Some(format!("No changes to {}", edit.path.display()));
+            }
+            Err(e) => {
+                self.status_message = Some(format!("✗ Override for '{}' not saved: {}", edit.unit, e));
+            }
+        }
+    }
+
+    /// Returns the new override content and lint warnings, or None if nothing changed
+    async fn apply_drop_in_edit(
+        &self,
+        edit: &DropInEdit,
+        editor_status: std::io::Result<std::process::ExitStatus>,
+    ) -> Result<Option<(String, Vec<LintIssue>)>> {
+        let status = editor_status.map_err(|e| anyhow::anyhow!("Failed to start editor: {}", e))?;
+        if !status.success() {
+            return Err(anyhow::anyhow!("Editor exited with {}", status));
+        }
+
+        let content = edit.edited_content()?;
+        if content == edit.initial {
+            return Ok(None);
+        }
+
+        // Errors keep the old override; warnings are reported after saving
+        let (errors, warnings): (Vec<_>, Vec<_>) = lint::lint_unit(&content, UnitKind::from_unit_name(&edit.unit), true)
+            .into_iter()
+            .partition(LintIssue::is_error);
+        if let Some(first) = errors.first() {
+            return Err(match errors.len() {
+                1 => anyhow::anyhow!("{}", first),
+                n => anyhow::anyhow!("{} (and {} more errors)", first, n - 1),
+            });
+        }
+
+        if dropin::is_effectively_empty(&content) {
+            // Like systemctl edit: an override without settings is removed
+            dropin::remove_file(&edit.path, edit.scope).await?;
+        } else {
+            dropin::install_file(&edit.temp_path, &edit.path, edit.scope).await?;
+        }
+        self.controller.reload_daemon(edit.scope).await?;
+
+        Ok(Some((content, warnings)))
+    }
+
+    /// Apply resource limits in the background, then refresh the detail view
+    fn spawn_set_limits(&self, service: String, scope: ServiceScope, runtime: bool, limits: Vec<(ResourceLimit, u64)>) {
+        let controller = self.controller.clone();
+        let client = self.client.clone();
+        let tx = self.tx.clone();
+
+        tokio::spawn(async move {
+            let applied = limits
+                .iter()
+                .map(|(limit, value)| format!("{}={}", limit.label(), limit.format(*value)))
+                .collect::<Vec<_>>()
+                .join(" ");
+            let mode = if runtime { "runtime" } else { "persistent" };
+
+            let message = match controller.set_resource_limits(&service, scope, runtime, &limits).await {
+                Ok(()) => format!("✓ Set {} on '{}' ({})", applied, service, mode),
+                Err(e) => format!("✗ Failed to set limits on '{}': {}", service, e),
+            };
+            tx.send(AppEvent::StatusMessage(message)).await.ok();
+
+            if let Ok(detail) = client.get_service_detail(&service, scope).await {
+                tx.send(AppEvent::ServiceDetailLoaded(Box::new(detail))).await.ok();
+            }
+        });
+    }
+
+    /// Send a signal to a unit's processes in the background and report it
+    fn spawn_kill(&self, service: String, scope: ServiceScope, target: KillTarget, signal: KillSignal) {
+        let controller = self.controller.clone();
+        let tx = self.tx.clone();
+
+        tokio::spawn(async move {
+            let message = match controller.kill_service(&service, scope, target, signal).await {
+                Ok(()) => format!("✓ Sent {} to {} of '{}'", signal.name(), target.description(), service),
+                Err(e) => format!("✗ Failed to send {} to '{}': {}", signal.name(), service, e),
+            };
+            tx.send(AppEvent::StatusMessage(message)).await.ok();
+        });
+    }
+
+    /// Cancel a queued job in the background and refresh the queue
+    fn spawn_cancel_job(&self, id: u32, unit: String, scope: ServiceScope) {
+        let controller = self.controller.clone();
+        let tx = self.tx.clone();
+        let client = self.client.clone();
+
+        tokio::spawn(async move {
+            let message = match controller.cancel_job(id, scope).await {
+                Ok(()) => format!("✓ Canceled job {} ({})", id, unit),
+                Err(e) => format!("✗ Failed to cancel job {} ({}): {}", id, unit, e),
+            };
+            tx.send(AppEvent::StatusMessage(message)).await.ok();
+
+            if let Ok(jobs) = client.list_jobs().await {
+                tx.send(AppEvent::JobsLoaded(jobs)).await.ok();
+            }
+        });
+    }
+
+    /// Start a transient unit in the background and open its logs once it runs
+    fn spawn_run_transient(&self, spec: TransientSpec, scope: ServiceScope) {
+        let controller = self.controller.clone();
+        let tx = self.tx.clone();
+
+        tokio::spawn(async move {
+            match controller.start_transient_unit(&spec, scope).await {
+                Ok(()) => {
+                    tx.send(AppEvent::TransientStarted { name: spec.name, scope }).await.ok();
+                }
+                Err(e) => {
+                    let message = format!("✗ Failed to run {}: {}", spec.name, e);
+                    tx.send(AppEvent::StatusMessage(message)).await.ok();
+                }
+            }
+        });
+    }
+
+    /// Stop, disable and delete a locally created unit and its timer, then reload the manager
+    fn spawn_remove_unit(&self, service: String, scope: ServiceScope, timer: Option<String>) {
+        let controller = self.controller.clone();
+        let tx = self.tx.clone();
+
+        tokio::spawn(async move {
+            // The timer goes first so it cannot start the service again meanwhile
+            let units: Vec<String> = timer.into_iter().chain(std::iter::once(service.clone())).collect();
+
+            // The units may already be stopped or not enabled; only file removal must succeed
+            for unit in &units {
+                if let Err(e) = controller.stop_service(unit, scope).await {
+                    tracing::warn!("Failed to stop {} before removal: {}", unit, e);
+                }
+                if let Err(e) = controller.disable_service(unit, scope).await {
+                    tracing::warn!("Failed to disable {} before removal: {}", unit, e);
+                }
+            }
+
+            let result = async {
+                for unit in &units {
+                    dropin::remove_unit_files(unit, scope).await?;
+                }
+                controller.reload_daemon(scope).await
+            }
+            .await;
+
+            match result {
+                Ok(()) => {
+                    tx.send(AppEvent::UnitDeleted { name: service }).await.ok();
+                }
+                Err(e) => {
+                    let message = format!("✗ Failed to remove {}: {}", service, e);
+                    tx.send(AppEvent::StatusMessage(message)).await.ok();
+                }
+            }
+        });
+    }
+
+    /// Run a start/stop/... operation in the background and report the outcome
+    fn spawn_service_control(&self, service: String, operation: String, scope: ServiceScope) {
+        let controller = self.controller.clone();
+        let tx = self.tx.clone();
+        let client = self.client.clone();
+
+        tokio::spawn(async move {
+            let result = match operation.as_str() {
+                "start" => controller.start_service(&service, scope).await,
+                "stop" => controller.stop_service(&service, scope).await,
+                "restart" => controller.restart_service(&service, scope).await,
+                "enable" => controller.enable_service(&service, scope).await,
+                "disable" => controller.disable_service(&service, scope).await,
+                "reload" => controller.reload_service(&service, scope).await,
+                "mask" => controller.mask_service(&service, scope).await,
+                "unmask" => controller.unmask_service(&service, scope).await,
+                "reset-failed" => controller.reset_failed_service(&service, scope).await,
+                "freeze" => controller.freeze_service(&service, scope).await,
+                "thaw" => controller.thaw_service(&service, scope).await,
+                _ => Err(anyhow::anyhow!("Unknown operation: {}", operation)),
+            };
+
+            let success = matches!(&result, Ok(job) if !job.is_failure());
+            let message = operation_status_message(&service, &operation, &result);
+
+            // Report the job outcome as a status toast
+            tx.send(AppEvent::ServiceOperationCompleted {
+                service: service.clone(),
+                operation: operation.clone(),
+                success,
+                message,
+            }).await.ok();
+
+            // Reload the affected list to update status
+            if service.ends_with(".timer") {
+                if let Ok(timers) = client.list_timers().await {
+                    tx.send(AppEvent::TimersLoaded(timers)).await.ok();
+                }
+            } else if service.ends_with(".socket") {
+                if let Ok(sockets) = client.list_sockets().await {
+                    tx.send(AppEvent::SocketsLoaded(sockets)).await.ok();
+                }
+            } else if let Ok(services) = client.list_services().await {
+                tx.send(AppEvent::ServicesLoaded(services)).await.ok();
+            }
+        });
+    }
+
+    fn switch_to_detail(&mut self, service_name: String, scope: ServiceScope) {
         // Clear status message when switching views
         self.status_message = None;
         self.needs_full_redraw = true;
@@ -503,7 +1085,7 @@ impl App {
         let client = self.client.clone();
 
         tokio::spawn(async move {
-            match client.get_service_detail(&service_name).await {
+            match client.get_service_detail(&service_name, scope).await {
                 Ok(detail) => {
                     tx.send(AppEvent::ServiceDetailLoaded(Box::new(detail))).await.ok();
                 }
@@ -514,19 +1096,19 @@ impl App {
         });
     }
 
-    fn switch_to_logs(&mut self, service_name: String) {
+    fn switch_to_logs(&mut self, service_name: String, scope: ServiceScope) {
         // Clear status message when switching views
         self.status_message = None;
         self.needs_full_redraw = true;
 
         // Switch to logs view
-        self.view = View::Logs(LogsState::new(service_name.clone()));
+        self.view = View::Logs(LogsState::new(service_name.clone(), scope));
 
         // Start journal reader - store in channel to keep alive
         let tx = self.tx.clone();
         let tx_reader = self.tx.clone();
         tokio::spawn(async move {
-            match JournalReader::stream_logs(service_name, tx.clone(), true, None, None, None).await {
+            match JournalReader::stream_logs(service_name, scope, tx.clone(), true, None, None, None).await {
                 Ok(reader) => {
                     // Send reader back to app to store it
                     tx_reader.send(AppEvent::JournalReaderStarted(reader)).await.ok();
@@ -547,6 +1129,51 @@ impl App {
         Ok(())
     }
 
+    fn reload_timers(&self) {
+        let tx = self.tx.clone();
+        let client = self.client.clone();
+        tokio::spawn(async move {
+            match client.list_timers().await {
+                Ok(timers) => {
+                    tx.send(AppEvent::TimersLoaded(timers)).await.ok();
+                }
+                Err(e) => {
+                    tx.send(AppEvent::Error(e)).await.ok();
+                }
+            }
+        });
+    }
+
+    fn reload_sockets(&self) {
+        let tx = self.tx.clone();
+        let client = self.client.clone();
+        tokio::spawn(async move {
+            match client.list_sockets().await {
+                Ok(sockets) => {
+                    tx.send(AppEvent::SocketsLoaded(sockets)).await.ok();
+                }
+                Err(e) => {
+                    tx.send(AppEvent::Error(e)).await.ok();
+                }
+            }
+        });
+    }
+
+    fn reload_jobs(&self) {
+        let tx = self.tx.clone();
+        let client = self.client.clone();
+        tokio::spawn(async move {
+            match client.list_jobs().await {
+                Ok(jobs) => {
+                    tx.send(AppEvent::JobsLoaded(jobs)).await.ok();
+                }
+                Err(e) => {
+                    tx.send(AppEvent::Error(e)).await.ok();
+                }
+            }
+        });
+    }
+
     pub fn render(&mut self, frame: &mut Frame) {
         let area = frame.area();
 
@@ -571,6 +1198,15 @@ impl App {
             View::Dashboard(dashboard) => {
                 dashboard.render(frame, content_area, show_dashboard_footer);
             }
+            View::Timers(timers) => {
+                timers.render(frame, content_area, show_dashboard_footer);
+            }
+            View::Sockets(sockets) => {
+                sockets.render(frame, content_area, show_dashboard_footer);
+            }
+            View::Jobs(jobs) => {
+                jobs.render(frame, content_area, show_dashboard_footer);
+            }
             View::Detail(detail) => {
                 detail.render(frame, content_area);
             }
@@ -583,6 +1219,12 @@ impl App {
             View::NewService(form) => {
                 form.render(frame, content_area);
             }
+            View::Diff(diff) => {
+                diff.render(frame, content_area);
+            }
+            View::UnitFile(unit_file) => {
+                unit_file.render(frame, content_area);
+            }
         }
 
         // Render status message if present
@@ -619,25 +1261,52 @@ impl App {
     }
 }
 
+/// Describe the outcome of a control operation for the status bar
+///
+/// Messages start with ✓ for success, ✗ for failure and carry no marker for
+/// jobs systemd skipped, which the status bar shows as informational.
+pub(crate) fn operation_status_message(service: &str, operation: &str, result: &Result<JobResult>) -> String {
+    match result {
+        Ok(job) if job.is_success() => format!("✓ {} '{}': {}", capitalize(operation), service, job.description()),
+        Ok(job) if !job.is_failure() => format!("{} '{}': {}", capitalize(operation), service, job.description()),
+        Ok(job) => format!("✗ Failed to {} '{}': {}", operation, service, job.description()),
+        Err(e) => {
+            let error = e.to_string();
+            // Truncate very long error messages
+            let error_display = if error.chars().count() > 150 {
+                format!("{}...", error.chars().take(150).collect::<String>())
+            } else {
+                error
+            };
+            format!("✗ Failed to {} '{}': {}", operation, service, error_display)
+        }
+    }
+}
+
+fn capitalize(word: &str) -> String {
+    let mut chars = word.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().collect::<String>() + chars.as_str(),
+        None => String::new(),
+    }
+}
+
 // Standalone function for creating user services (can be called from async tasks)
 async fn create_user_service_async(form: &NewServiceForm) -> Result<()> {
     use std::fs;
-    use std::path::PathBuf;
     use tokio::process::Command;
 
-    // Get user's home directory
-    let home_dir = std::env::var("HOME")
-        .map_err(|_| anyhow::anyhow!("Could not determine home directory"))?;
+    // Same path the overwrite check looked at ($XDG_CONFIG_HOME/systemd/user)
+    let service_file_path = form.target_path()?;
+    let systemd_user_dir = service_file_path
+        .parent()
+        .ok_or_else(|| anyhow::anyhow!("Invalid service file path {:?}", service_file_path))?
+        .to_path_buf();
 
     // Create systemd user directory if it doesn't exist
-    let systemd_user_dir = PathBuf::from(&home_dir).join(".config/systemd/user");
     fs::create_dir_all(&systemd_user_dir)
         .map_err(|e| anyhow::anyhow!("Failed to create systemd user directory: {}", e))?;
 
-    // Generate service file path
-    let service_file_name = format!("{}.service", form.name);
-    let service_file_path = systemd_user_dir.join(&service_file_name);
-
     // Generate service file content
     let service_content = form.generate_service_file();
 
@@ -647,6 +1316,14 @@ async fn create_user_service_async(form: &NewServiceForm) -> Result<()> {
 
     tracing::info!("Created user service file: {:?}", service_file_path);
 
+    // Companion timer, if a schedule was given
+    if let Some(timer_content) = form.generate_timer_file() {
+        let timer_file_path = systemd_user_dir.join(format!("{}.timer", form.name));
+        fs::write(&timer_file_path, timer_content)
+            .map_err(|e| anyhow::anyhow!("Failed to write timer file: {}", e))?;
+        tracing::info!("Created user timer file: {:?}", timer_file_path);
+    }
+
     // Check if we're running as root - user systemd won't work
     let current_user = std::env::var("USER").unwrap_or_default();
     let is_root = current_user == "root" || std::env::var("SUDO_USER").is_ok();
@@ -685,3 +1362,26 @@ async fn create_user_service_async(form: &NewServiceForm) -> Result<()> {
 
     Ok(())
 }
+
+// System services go to /etc/systemd/system, through pkexec when not running as root
+async fn create_system_service_async(form: &NewServiceForm, controller: &ServiceController) -> Result<()> {
+    let unit_dir = dropin::unit_dir(ServiceScope::System)?;
+
+    let mut files = vec![(format!("{}.service", form.name), form.generate_service_file())];
+    if let Some(timer_content) = form.generate_timer_file() {
+        files.push((format!("{}.timer", form.name), timer_content));
+    }
+
+    for (file_name, content) in files {
+        // The temp directory must outlive the root install of the file
+        let (_temp_dir, temp_path) = dropin::private_temp_file(&file_name, &content)?;
+
+        let target = unit_dir.join(&file_name);
+        dropin::install_file(&temp_path, &target, ServiceScope::System).await?;
+
+        tracing::info!("Created system unit file: {:?}", target);
+    }
+
+    controller.reload_daemon(ServiceScope::System).await?;
+    Ok(())
+}
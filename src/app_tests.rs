@@ -3,7 +3,7 @@ mod tests {
     use crate::app::*;
     use crate::error::Result;
     use crate::events::{Action, AppEvent, FilterAction, key_event_to_action};
-    use crate::systemd::{Service, ServiceScope};
+    use crate::systemd::{Service, ServiceScope, UnitKind};
     use crate::ui::{DashboardState, DetailState, LogsState};
     use chrono::Utc;
 
@@ -31,6 +31,8 @@ mod tests {
             pid: 1234,
             enabled: true,
             scope: ServiceScope::System,
+            kind: UnitKind::Service,
+            freezer_state: String::new(),
             loaded_at: Utc::now(),
         }];
 
@@ -77,6 +79,16 @@ mod tests {
 
         let running_action = key_event_to_action(KeyEvent::new(KeyCode::Char('r'), KeyModifiers::NONE));
         assert_eq!(running_action, Action::ToggleFilter(FilterAction::Running));
+
+        // Test dashboard columns and sorting
+        let columns_action = key_event_to_action(KeyEvent::new(KeyCode::Char('v'), KeyModifiers::NONE));
+        assert_eq!(columns_action, Action::ToggleResourceColumns);
+
+        let sort_action = key_event_to_action(KeyEvent::new(KeyCode::Char('o'), KeyModifiers::NONE));
+        assert_eq!(sort_action, Action::CycleSort);
+
+        let reverse_action = key_event_to_action(KeyEvent::new(KeyCode::Char('O'), KeyModifiers::SHIFT));
+        assert_eq!(reverse_action, Action::ReverseSort);
     }
 
     #[test]
@@ -127,6 +139,8 @@ mod tests {
             pid: 1234,
             enabled: true,
             scope: ServiceScope::System,
+            kind: UnitKind::Service,
+            freezer_state: String::new(),
             loaded_at: Utc::now(),
         };
 
@@ -149,6 +163,29 @@ mod tests {
         assert!(activating_service.is_transitioning());
     }
 
+    #[test]
+    fn test_operation_status_message() {
+        use crate::app::operation_status_message;
+        use crate::systemd::JobResult;
+
+        let done = operation_status_message("nginx.service", "restart", &Ok(JobResult::Done));
+        assert_eq!(done, "✓ Restart 'nginx.service': job completed");
+
+        let failed = operation_status_message("nginx.service", "start", &Ok(JobResult::Failed));
+        assert!(failed.starts_with("✗ Failed to start 'nginx.service'"));
+
+        let dependency = operation_status_message("app.service", "start", &Ok(JobResult::Dependency));
+        assert!(dependency.starts_with("✗") && dependency.contains("dependency"));
+
+        // Skipped jobs are informational, neither success nor failure
+        let skipped = operation_status_message("app.service", "reload", &Ok(JobResult::Skipped));
+        assert!(!skipped.contains('✓') && !skipped.contains('✗'));
+
+        let error = operation_status_message("app.service", "stop", &Err(anyhow::anyhow!("x".repeat(400))));
+        assert!(error.starts_with("✗ Failed to stop 'app.service'"));
+        assert!(error.ends_with("..."));
+    }
+
     #[tokio::test]
     async fn test_app_lifecycle() -> Result<()> {
         let (tx, _rx) = tokio::sync::mpsc::channel(100);
@@ -169,7 +206,7 @@ mod tests {
     fn test_view_states() {
         let dashboard = View::Dashboard(DashboardState::new());
         let detail = View::Detail(Box::new(DetailState::new()));
-        let logs = View::Logs(LogsState::new("test.service".to_string()));
+        let logs = View::Logs(LogsState::new("test.service".to_string(), ServiceScope::System));
 
         assert!(matches!(dashboard, View::Dashboard(_)));
         assert!(matches!(detail, View::Detail(_)));
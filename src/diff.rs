@@ -0,0 +1,125 @@
+// Line diff - shows what changed between two versions of a text file
+
+/// One line of a diff
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffLine {
+    Same(String),
+    Added(String),
+    Removed(String),
+    /// Hunk header in unified output, e.g. "@@ -3,4 +3,5 @@"
+    Hunk(String),
+}
+
+impl DiffLine {
+    /// Prefix as in unified diff output
+    pub fn marker(&self) -> char {
+        match self {
+            DiffLine::Same(_) => ' ',
+            DiffLine::Added(_) => '+',
+            DiffLine::Removed(_) => '-',
+            DiffLine::Hunk(_) => '@',
+        }
+    }
+
+    pub fn text(&self) -> &str {
+        match self {
+            DiffLine::Same(text) | DiffLine::Added(text) | DiffLine::Removed(text) | DiffLine::Hunk(text) => text,
+        }
+    }
+
+    pub fn is_change(&self) -> bool {
+        matches!(self, DiffLine::Added(_) | DiffLine::Removed(_))
+    }
+}
+
+/// Diff two texts line by line (longest common subsequence)
+///
+/// Unit files are small, so the quadratic table is fine.
+pub fn diff_lines(old: &str, new: &str) -> Vec<DiffLine> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // lcs[i][j] = length of the LCS of old[i..] and new[j..]
+    let mut lcs = vec![vec![0usize; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            lcs[i][j] = if old[i] == new[j] {
+                lcs[i + 1][j + 1] + 1
+            } else {
+                lcs[i + 1][j].max(lcs[i][j + 1])
+            };
+        }
+    }
+
+    let mut lines = Vec::with_capacity(old.len().max(new.len()));
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() && j < new.len() {
+        if old[i] == new[j] {
+            lines.push(DiffLine::Same(old[i].to_string()));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            lines.push(DiffLine::Removed(old[i].to_string()));
+            i += 1;
+        } else {
+            lines.push(DiffLine::Added(new[j].to_string()));
+            j += 1;
+        }
+    }
+    lines.extend(old[i..].iter().map(|l| DiffLine::Removed(l.to_string())));
+    lines.extend(new[j..].iter().map(|l| DiffLine::Added(l.to_string())));
+
+    lines
+}
+
+/// Reduce a full diff to unified hunks with `context` unchanged lines around each change
+pub fn unified(lines: &[DiffLine], context: usize) -> Vec<DiffLine> {
+    // Line numbers in the old and new text before each diff line
+    let mut positions = Vec::with_capacity(lines.len());
+    let (mut old_no, mut new_no) = (0, 0);
+    for line in lines {
+        positions.push((old_no, new_no));
+        match line {
+            DiffLine::Same(_) => {
+                old_no += 1;
+                new_no += 1;
+            }
+            DiffLine::Removed(_) => old_no += 1,
+            DiffLine::Added(_) => new_no += 1,
+            DiffLine::Hunk(_) => {}
+        }
+    }
+
+    // Merge the context windows of nearby changes into hunks
+    let mut ranges: Vec<(usize, usize)> = Vec::new();
+    for (i, _) in lines.iter().enumerate().filter(|(_, l)| l.is_change()) {
+        let start = i.saturating_sub(context);
+        let end = (i + context + 1).min(lines.len());
+        match ranges.last_mut() {
+            Some(last) if start <= last.1 => last.1 = end,
+            _ => ranges.push((start, end)),
+        }
+    }
+
+    let mut output = Vec::new();
+    for (start, end) in ranges {
+        let hunk = &lines[start..end];
+        let old_len = hunk.iter().filter(|l| matches!(l, DiffLine::Same(_) | DiffLine::Removed(_))).count();
+        let new_len = hunk.iter().filter(|l| matches!(l, DiffLine::Same(_) | DiffLine::Added(_))).count();
+        let (old_start, new_start) = positions[start];
+        // Ranges are 1-based; an empty range names the line before it
+        let old_start = if old_len == 0 { old_start } else { old_start + 1 };
+        let new_start = if new_len == 0 { new_start } else { new_start + 1 };
+
+        output.push(DiffLine::Hunk(format!("@@ -{},{} +{},{} @@", old_start, old_len, new_start, new_len)));
+        output.extend_from_slice(hunk);
+    }
+    output
+}
+
+/// Count added and removed lines
+pub fn diff_stats(lines: &[DiffLine]) -> (usize, usize) {
+    let added = lines.iter().filter(|l| matches!(l, DiffLine::Added(_))).count();
+    let removed = lines.iter().filter(|l| matches!(l, DiffLine::Removed(_))).count();
+    (added, removed)
+}
@@ -1,9 +1,39 @@
 // Event handling for the TUI application
 
-use crate::systemd::{JournalReader, LogLine, Service, ServiceDetail};
+use crate::systemd::{CgroupStats, Job, JournalReader, LogLine, MetricsSnapshot, Service, ServiceDetail, ServiceScope, Socket, Timer, UnitResources};
 use crossterm::event::{Event as CrosstermEvent, KeyCode, KeyEvent, KeyModifiers};
+use std::collections::HashMap;
 use std::time::Duration;
-use tokio::sync::mpsc;
+use tokio::sync::{mpsc, oneshot};
+
+/// Requests from the main loop to the input task
+enum InputCommand {
+    /// Stop reading; the sender is answered once no poll is in flight
+    Pause(oneshot::Sender<()>),
+    Resume,
+}
+
+/// Handle for handing the terminal to an external program (e.g. $EDITOR)
+#[derive(Clone)]
+pub struct InputControl {
+    commands: mpsc::UnboundedSender<InputCommand>,
+}
+
+impl InputControl {
+    /// Stop reading terminal input; returns once the input task has stopped
+    pub async fn pause(&self) {
+        let (ack, acked) = oneshot::channel();
+        if self.commands.send(InputCommand::Pause(ack)).is_ok() {
+            // Err only if the input task is gone, which also means it is not reading
+            let _ = acked.await;
+        }
+    }
+
+    /// Resume reading terminal input after the external program exits
+    pub fn resume(&self) {
+        let _ = self.commands.send(InputCommand::Resume);
+    }
+}
 
 /// Application events
 #[derive(Debug)]
@@ -11,6 +41,27 @@ pub enum AppEvent {
     /// Services loaded from systemd
     ServicesLoaded(Vec<Service>),
 
+    /// Single unit changed state (from systemd signals)
+    ServiceUpdated(Service),
+
+    /// Unit unloaded by systemd (from systemd signals)
+    ServiceRemoved { id: String },
+
+    /// Timer units loaded from systemd
+    TimersLoaded(Vec<Timer>),
+
+    /// Socket units loaded from systemd
+    SocketsLoaded(Vec<Socket>),
+
+    /// Job queue loaded from systemd
+    JobsLoaded(Vec<Job>),
+
+    /// A job was queued or finished (from systemd signals)
+    JobsChanged,
+
+    /// A systemd signal stream ended; views fall back to tick polling
+    UnitWatchStopped(ServiceScope),
+
     /// Service detail loaded
     ServiceDetailLoaded(Box<ServiceDetail>),
 
@@ -35,8 +86,8 @@ pub enum AppEvent {
     /// Request to quit
     Quit,
 
-    /// Service operation completed
-    ServiceOperationCompleted { service: String, operation: String, success: bool },
+    /// Service operation's job finished (message describes the JobRemoved result)
+    ServiceOperationCompleted { service: String, operation: String, success: bool, message: String },
 
     /// Confirmation dialog request
     RequestConfirmation { service: String, operation: String, message: String },
@@ -50,6 +101,21 @@ pub enum AppEvent {
     /// User service creation failed
     ServiceCreationFailed { error: String },
 
+    /// Unit stopped, disabled and its files deleted
+    UnitDeleted { name: String },
+
+    /// Transient unit started from the run dialog
+    TransientStarted { name: String, scope: ServiceScope },
+
+    /// New metrics sample for the unit in the detail view, with its retained history
+    MetricsUpdated { service: String, scope: ServiceScope, history: Vec<MetricsSnapshot> },
+
+    /// Pressure and memory breakdown read from the detail unit's cgroup (None if unreadable)
+    CgroupUpdated { service: String, scope: ServiceScope, stats: Option<CgroupStats> },
+
+    /// Resource usage of the dashboard's active units, keyed by Service id
+    ResourcesUpdated(HashMap<String, UnitResources>),
+
     /// Show help
     ShowHelp,
 }
@@ -66,6 +132,10 @@ pub enum Action {
     GoBack,
     ToggleFilter(FilterAction),
     ToggleScope,
+    CycleUnitKind,
+    ToggleResourceColumns,
+    CycleSort,
+    ReverseSort,
     Search(String),
     ClearSearch,
     ViewLogs,
@@ -79,6 +149,9 @@ pub enum Action {
     CancelAction,
 
     ShowHelp,
+    // Switch between unit lists
+    NextTab,
+    PrevTab,
     // Service control actions
     StartService,
     StopService,
@@ -86,6 +159,18 @@ pub enum Action {
     EnableService,
     DisableService,
     ReloadService,
+    MaskService,
+    UnmaskService,
+    ResetFailed,
+    KillService,
+    ToggleFreeze,
+    EditLimits,
+    EditOverride,
+    ViewUnitFile,
+    VendorDiff,
+    RemoveUnit,
+    CancelJob,
+    RunTransient,
 
     // Service creation
     CreateService,
@@ -120,6 +205,8 @@ pub fn key_event_to_action(key: KeyEvent) -> Action {
         // Selection
         (KeyCode::Enter, _) => Action::Select,
         (KeyCode::Esc, _) | (KeyCode::Left, _) => Action::GoBack,
+        (KeyCode::Tab, KeyModifiers::NONE) => Action::NextTab,
+        (KeyCode::BackTab, _) => Action::PrevTab,
 
         // Filtering
         (KeyCode::Char('a'), KeyModifiers::NONE) => Action::ToggleFilter(FilterAction::All),
@@ -127,6 +214,12 @@ pub fn key_event_to_action(key: KeyEvent) -> Action {
         (KeyCode::Char('s'), KeyModifiers::NONE) => Action::ToggleFilter(FilterAction::Stopped),
         (KeyCode::Char('f'), KeyModifiers::NONE) => Action::ToggleFilter(FilterAction::Failed),
         (KeyCode::Char('m'), KeyModifiers::NONE) => Action::ToggleScope,
+        (KeyCode::Char('u'), KeyModifiers::NONE) => Action::CycleUnitKind,
+
+        // Dashboard columns and sorting
+        (KeyCode::Char('v'), KeyModifiers::NONE) => Action::ToggleResourceColumns,
+        (KeyCode::Char('o'), KeyModifiers::NONE) => Action::CycleSort,
+        (KeyCode::Char('O'), KeyModifiers::SHIFT) => Action::ReverseSort,
 
         // Service control
         (KeyCode::Char('S'), KeyModifiers::SHIFT) => Action::StartService,
@@ -135,6 +228,18 @@ pub fn key_event_to_action(key: KeyEvent) -> Action {
         (KeyCode::Char('E'), KeyModifiers::SHIFT) => Action::EnableService,
         (KeyCode::Char('D'), KeyModifiers::SHIFT) => Action::DisableService,
         (KeyCode::Char('L'), KeyModifiers::SHIFT) => Action::ReloadService,
+        (KeyCode::Char('M'), KeyModifiers::SHIFT) => Action::MaskService,
+        (KeyCode::Char('U'), KeyModifiers::SHIFT) => Action::UnmaskService,
+        (KeyCode::Char('F'), KeyModifiers::SHIFT) => Action::ResetFailed,
+        (KeyCode::Char('K'), KeyModifiers::SHIFT) => Action::KillService,
+        (KeyCode::Char('Z'), KeyModifiers::SHIFT) => Action::ToggleFreeze,
+        (KeyCode::Char('C'), KeyModifiers::SHIFT) => Action::EditLimits,
+        (KeyCode::Char('e'), KeyModifiers::NONE) => Action::EditOverride,
+        (KeyCode::Char('i'), KeyModifiers::NONE) => Action::ViewUnitFile,
+        (KeyCode::Char('d'), KeyModifiers::NONE) => Action::VendorDiff,
+        (KeyCode::Char('x'), KeyModifiers::NONE) => Action::CancelJob,
+        (KeyCode::Char('X'), KeyModifiers::SHIFT) => Action::RemoveUnit,
+        (KeyCode::Char('!'), _) => Action::RunTransient,
 
         // Confirmation
         (KeyCode::Char('y'), KeyModifiers::NONE) => Action::ConfirmAction,
@@ -162,9 +267,24 @@ pub fn key_event_to_action(key: KeyEvent) -> Action {
 }
 
 /// Spawn input event handler task
-pub async fn spawn_input_handler(tx: mpsc::Sender<AppEvent>) {
+pub async fn spawn_input_handler(tx: mpsc::Sender<AppEvent>) -> InputControl {
+    let (commands, mut pending) = mpsc::unbounded_channel();
     tokio::spawn(async move {
         loop {
+            // Commands are only taken between polls, so acking a pause
+            // guarantees stdin is no longer being read
+            if let Ok(InputCommand::Pause(ack)) = pending.try_recv() {
+                let _ = ack.send(());
+                loop {
+                    match pending.recv().await {
+                        Some(InputCommand::Resume) => break,
+                        Some(InputCommand::Pause(ack)) => {
+                            let _ = ack.send(());
+                        }
+                        None => return,
+                    }
+                }
+            }
             if crossterm::event::poll(Duration::from_millis(100)).unwrap_or(false) {
                 if let Ok(event) = crossterm::event::read() {
                     if tx.send(AppEvent::Input(event)).await.is_err() {
@@ -174,6 +294,7 @@ pub async fn spawn_input_handler(tx: mpsc::Sender<AppEvent>) {
             }
         }
     });
+    InputControl { commands }
 }
 
 /// Spawn periodic tick task
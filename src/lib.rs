@@ -3,6 +3,7 @@
 
 pub mod app;
 pub mod config;
+pub mod diff;
 pub mod error;
 pub mod events;
 pub mod systemd;
@@ -91,7 +91,7 @@ async fn run_tui(config_path: Option<String>) -> Result<()> {
     let (tx, mut rx) = mpsc::channel::<AppEvent>(100);
 
     // Spawn input handler
-    spawn_input_handler(tx.clone()).await;
+    let input = spawn_input_handler(tx.clone()).await;
 
     // Spawn ticker for periodic refresh (use config setting)
     spawn_ticker(tx.clone(), Duration::from_secs(config.service_list_refresh_secs)).await;
@@ -99,6 +99,15 @@ async fn run_tui(config_path: Option<String>) -> Result<()> {
     // Create app
     let mut app = App::new(tx.clone()).await?;
 
+    // Sample the detail view's unit for the live graphs
+    app.start_metrics_poller(Duration::from_secs(config.metrics_refresh_secs.max(1))).await;
+
+    // Subscribe to systemd signals; fall back to tick polling if unavailable
+    match app.client.watch_units(tx.clone()).await {
+        Ok(()) => app.live_updates = true,
+        Err(e) => tracing::warn!("Live unit updates unavailable, polling instead: {}", e),
+    }
+
     // Initial service load
     let services = app.client.list_services().await?;
     tx.send(AppEvent::ServicesLoaded(services)).await.ok();
@@ -121,6 +130,23 @@ async fn run_tui(config_path: Option<String>) -> Result<()> {
             if app.should_quit {
                 break;
             }
+
+            // Hand the terminal to $EDITOR for a drop-in override edit
+            if let Some(edit) = app.pending_edit.take() {
+                // Wait for the input task to stop reading before the editor reads stdin
+                input.pause().await;
+                disable_raw_mode()?;
+                execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
+                terminal.show_cursor()?;
+
+                let status = run_editor(&edit.temp_path).await;
+                app.finish_drop_in_edit(edit, status).await;
+
+                enable_raw_mode()?;
+                execute!(terminal.backend_mut(), EnterAlternateScreen)?;
+                terminal.clear()?;
+                input.resume();
+            }
         }
     }
 
@@ -132,4 +158,19 @@ async fn run_tui(config_path: Option<String>) -> Result<()> {
     println!("Sysprox exited. Goodbye!");
 
     Ok(())
-}
\ No newline at end of file
+}
+
+/// Run $VISUAL or $EDITOR (default vi) on a file and wait for it to exit
+async fn run_editor(path: &std::path::Path) -> io::Result<std::process::ExitStatus> {
+    let editor = std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".to_string());
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().unwrap_or("vi");
+
+    tokio::process::Command::new(program)
+        .args(parts)
+        .arg(path)
+        .status()
+        .await
+}
@@ -0,0 +1,164 @@
+// cgroup v2 reader - pressure stall information and memory breakdown from /sys/fs/cgroup
+
+use crate::error::Result;
+use std::path::{Path, PathBuf};
+
+/// Where the unified cgroup hierarchy is mounted
+pub const CGROUP_ROOT: &str = "/sys/fs/cgroup";
+
+/// One line of a PSI file: share of time stalled, in percent, and total stall time
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PressureLine {
+    pub avg10: f64,
+    pub avg60: f64,
+    pub avg300: f64,
+    pub total_usec: u64,
+}
+
+/// A cpu/memory/io.pressure file
+///
+/// "some" is time at least one task was stalled; "full" is time all were.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct Pressure {
+    pub some: PressureLine,
+    /// Absent from cpu.pressure before Linux 5.13
+    pub full: Option<PressureLine>,
+}
+
+/// Memory use by kind, from memory.stat, in bytes
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct MemoryBreakdown {
+    pub anon: u64,
+    pub file: u64,
+    pub kernel: u64,
+    pub sock: u64,
+}
+
+/// Counts from memory.events since the cgroup was created
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct MemoryEvents {
+    pub oom: u64,
+    pub oom_kill: u64,
+    /// Times reclaim was forced by MemoryHigh=
+    pub high: u64,
+    /// Times usage hit MemoryMax=
+    pub max: u64,
+}
+
+/// Everything read from one unit's cgroup; files the kernel does not offer are None
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CgroupStats {
+    pub cpu_pressure: Option<Pressure>,
+    pub memory_pressure: Option<Pressure>,
+    pub io_pressure: Option<Pressure>,
+    pub memory_stat: Option<MemoryBreakdown>,
+    pub memory_events: Option<MemoryEvents>,
+}
+
+/// Directory of a ControlGroup property value, e.g. "/system.slice/nginx.service"
+pub fn cgroup_path(root: &Path, control_group: &str) -> PathBuf {
+    root.join(control_group.trim_start_matches('/'))
+}
+
+/// Read a unit's cgroup from the real hierarchy
+pub fn read_unit_cgroup(control_group: &str) -> Result<CgroupStats> {
+    read_cgroup(Path::new(CGROUP_ROOT), control_group)
+}
+
+/// Read pressure, memory.stat and memory.events under `root`
+///
+/// Fails when the directory is missing: the unit is not running, or the
+/// host uses the legacy cgroup v1 hierarchy.
+pub fn read_cgroup(root: &Path, control_group: &str) -> Result<CgroupStats> {
+    if control_group.is_empty() {
+        return Err(anyhow::anyhow!("Unit has no control group"));
+    }
+    let dir = cgroup_path(root, control_group);
+    if !dir.join("cgroup.controllers").is_file() {
+        return Err(anyhow::anyhow!("{} is not a cgroup v2 directory", dir.display()));
+    }
+
+    let read = |file: &str| std::fs::read_to_string(dir.join(file)).ok();
+    Ok(CgroupStats {
+        cpu_pressure: read("cpu.pressure").and_then(|s| parse_pressure(&s)),
+        memory_pressure: read("memory.pressure").and_then(|s| parse_pressure(&s)),
+        io_pressure: read("io.pressure").and_then(|s| parse_pressure(&s)),
+        memory_stat: read("memory.stat").map(|s| parse_memory_stat(&s)),
+        memory_events: read("memory.events").map(|s| parse_memory_events(&s)),
+    })
+}
+
+/// Parse a PSI file:
+///
+/// ```text
+/// some avg10=0.12 avg60=0.05 avg300=0.00 total=123456
+/// full avg10=0.00 avg60=0.00 avg300=0.00 total=0
+/// ```
+pub fn parse_pressure(content: &str) -> Option<Pressure> {
+    let mut some = None;
+    let mut full = None;
+    for line in content.lines() {
+        let mut fields = line.split_whitespace();
+        let kind = fields.next();
+        let mut parsed = PressureLine::default();
+        for field in fields {
+            let (key, value) = field.split_once('=')?;
+            match key {
+                "avg10" => parsed.avg10 = value.parse().ok()?,
+                "avg60" => parsed.avg60 = value.parse().ok()?,
+                "avg300" => parsed.avg300 = value.parse().ok()?,
+                "total" => parsed.total_usec = value.parse().ok()?,
+                _ => {}
+            }
+        }
+        match kind {
+            Some("some") => some = Some(parsed),
+            Some("full") => full = Some(parsed),
+            _ => {}
+        }
+    }
+
+    Some(Pressure { some: some?, full })
+}
+
+/// Key/value pairs of a flat keyed file like memory.stat
+fn keyed_values(content: &str) -> impl Iterator<Item = (&str, u64)> {
+    content.lines().filter_map(|line| {
+        let (key, value) = line.split_once(' ')?;
+        Some((key, value.trim().parse().ok()?))
+    })
+}
+
+/// Parse memory.stat; kernels before 5.18 lack "kernel", so it is summed from its parts
+pub fn parse_memory_stat(content: &str) -> MemoryBreakdown {
+    let mut breakdown = MemoryBreakdown::default();
+    let mut kernel = None;
+    let mut kernel_parts = 0;
+    for (key, value) in keyed_values(content) {
+        match key {
+            "anon" => breakdown.anon = value,
+            "file" => breakdown.file = value,
+            "sock" => breakdown.sock = value,
+            "kernel" => kernel = Some(value),
+            "kernel_stack" | "pagetables" | "percpu" | "slab" => kernel_parts += value,
+            _ => {}
+        }
+    }
+    breakdown.kernel = kernel.unwrap_or(kernel_parts);
+    breakdown
+}
+
+/// Parse memory.events
+pub fn parse_memory_events(content: &str) -> MemoryEvents {
+    let mut events = MemoryEvents::default();
+    for (key, value) in keyed_values(content) {
+        match key {
+            "oom" => events.oom = value,
+            "oom_kill" => events.oom_kill = value,
+            "high" => events.high = value,
+            "max" => events.max = value,
+            _ => {}
+        }
+    }
+    events
+}
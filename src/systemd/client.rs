@@ -1,9 +1,46 @@
 // Systemd D-Bus client using zbus
 
 use crate::error::{Result, SysproxError};
-use crate::systemd::{ConnectionManager, Metrics, Service, ServiceDetail, ServiceScope, ServiceStatusExtended};
+use crate::events::AppEvent;
+use crate::systemd::models::is_enabled_state;
+use crate::systemd::models::{format_usec_span, timestamp_from_usec};
+use crate::systemd::metrics::metrics_from_properties;
+use crate::systemd::unit_file;
+use crate::systemd::{ConnectionManager, Job, Metrics, Service, ServiceDetail, ServiceScope, ServiceStatusExtended, Socket, Timer, UnitKind};
 use chrono::Utc;
-use zbus::Connection;
+use futures::StreamExt;
+use tokio::sync::mpsc;
+use zbus::{Connection, MessageStream};
+
+/// Upper bound on in-flight per-unit D-Bus calls
+pub(crate) const MAX_CONCURRENT_CALLS: usize = 16;
+
+/// Row returned by ListUnits / ListUnitsByPatterns:
+/// (name, description, load_state, active_state, sub_state,
+///  followed, unit_path, job_id, job_type, job_path)
+type ListedUnit = (
+    String,
+    String,
+    String,
+    String,
+    String,
+    String,
+    zbus::zvariant::OwnedObjectPath,
+    u32,
+    String,
+    zbus::zvariant::OwnedObjectPath,
+);
+
+/// One row of the Manager's ListJobs reply:
+/// (id, unit, job type, state, job path, unit path)
+pub(crate) type ListedJob = (
+    u32,
+    String,
+    String,
+    String,
+    zbus::zvariant::OwnedObjectPath,
+    zbus::zvariant::OwnedObjectPath,
+);
 
 /// Systemd D-Bus client with resilient connection handling
 pub struct SystemdClient {
@@ -53,7 +90,7 @@ impl SystemdClient {
         })
     }
 
-    /// List all systemd service units from all scopes (system + user)
+    /// List all systemd units shown in the dashboard from all scopes (system + user)
     pub async fn list_services(&self) -> Result<Vec<Service>> {
         let mut all_services = Vec::new();
 
@@ -71,118 +108,329 @@ impl SystemdClient {
         Ok(all_services)
     }
 
-    /// List systemd service units from a specific scope
+    /// List systemd units of every listed UnitKind from a specific scope
+    ///
+    /// Uses one `ListUnitsByPatterns` call for the loaded units and one
+    /// `ListUnitFiles` call for enablement, joined in memory. Per-unit
+    /// `GetUnitFileState` calls are only made (concurrently) for loaded
+    /// template instances, which have no entry of their own in the unit file
+    /// list. Other units missing from it (transient units, runtime mounts,
+    /// session scopes) have no unit file, so the lookup would fail anyway.
     pub async fn list_services_by_scope(&self, scope: ServiceScope) -> Result<Vec<Service>> {
         self.connection_manager.with_retry("list_services_by_scope", || async {
-            // Select the appropriate connection
-            let connection = match scope {
-                ServiceScope::System => &self.connection,
-                ServiceScope::User => self.user_connection.as_ref()
-                    .ok_or_else(|| SysproxError::SystemdConnection("User bus not available".to_string()))?,
-            };
-
-            // Call systemd's ListUnits method via D-Bus
-            let proxy = zbus::Proxy::new(
-                connection,
-                "org.freedesktop.systemd1",
-                "/org/freedesktop/systemd1",
-                "org.freedesktop.systemd1.Manager",
-            )
-            .await
-            .map_err(|e| SysproxError::SystemdConnection(e.to_string()))?;
+            let patterns: Vec<String> = UnitKind::LISTED
+                .iter()
+                .map(|kind| format!("*.{}", kind.label()))
+                .collect();
+            let patterns: Vec<&str> = patterns.iter().map(String::as_str).collect();
+            let units = self.list_units_by_patterns(scope, &patterns).await?;
+
+            // One round trip for the enablement of every unit file in this scope
+            let unit_file_states = self.unit_file_states(scope).await.unwrap_or_else(|e| {
+                tracing::warn!("Failed to list {} unit files: {}", scope.label(), e);
+                std::collections::HashMap::new()
+            });
+
+            // Loaded instances are not in the unit file list and need a per-unit lookup
+            let missing: Vec<String> = units
+                .iter()
+                .filter(|unit| unit.2 == "loaded" && needs_unit_file_lookup(&unit_file_states, &unit.0))
+                .map(|unit| unit.0.clone())
+                .collect();
 
-            // ListUnits returns array of (name, description, load_state, active_state, sub_state,
-            //                             followed, unit_path, job_id, job_type, job_path)
-            #[allow(clippy::type_complexity)]
-            let units: Vec<(
-                String, // name
-                String, // description
-                String, // load_state
-                String, // active_state
-                String, // sub_state
-                String, // followed
-                zbus::zvariant::OwnedObjectPath, // unit_path
-                u32,    // job_id
-                String, // job_type
-                zbus::zvariant::OwnedObjectPath, // job_path
-            )> = proxy
-                .call("ListUnits", &())
-                .await
-                .map_err(|e| SysproxError::ServiceInfo(e.to_string()))?;
+            let fetched: std::collections::HashMap<String, String> = futures::stream::iter(missing)
+                .map(|name| async move {
+                    let state = self.get_unit_file_state_in_scope(&name, scope).await.ok();
+                    (name, state)
+                })
+                .buffer_unordered(MAX_CONCURRENT_CALLS)
+                .filter_map(|(name, state)| async move { state.map(|state| (name, state)) })
+                .collect()
+                .await;
 
-            let mut services = Vec::new();
+            let mut services = Vec::with_capacity(units.len());
             let mut service_names = std::collections::HashSet::new();
 
             // First, add all loaded units
             for (name, description, load_state, active_state, sub_state, _, _, _, _, _) in units {
-                // Filter for service units only
-                if name.ends_with(".service") {
-                    service_names.insert(name.clone());
-                    // Check if service is enabled
-                    let is_enabled = self.check_service_enabled_in_scope(&name, scope).await.unwrap_or(false);
-
-                    services.push(Service {
-                        id: format!("{}:{}", scope.label(), name),
+                service_names.insert(name.clone());
+
+                let state = unit_file_state_for(&unit_file_states, &name)
+                    .or_else(|| fetched.get(&name).map(String::as_str))
+                    .unwrap_or_default();
+
+                let kind = UnitKind::from_unit_name(&name).unwrap_or_default();
+                services.push(Service {
+                    id: format!("{}:{}", scope.label(), name),
+                    name,
+                    description,
+                    load_state,
+                    active_state,
+                    sub_state,
+                    pid: 0, // Will be filled by get_service_detail if needed
+                    enabled: is_enabled_state(state),
+                    scope,
+                    kind,
+                    // Not in ListUnits; the detail view and unit watcher fill it in
+                    freezer_state: String::new(),
+                    loaded_at: Utc::now(), // TODO: Get actual load time
+                });
+            }
+
+            // Then, add unloaded unit files (for user scope)
+            if scope == ServiceScope::User {
+                for (name, state) in &unit_file_states {
+                    if is_listed_unit(name) && !service_names.contains(name) {
+                        services.push(not_loaded_service(name, state, scope));
+                    }
+                }
+            }
+
+            Ok(services)
+        }).await
+    }
+
+    /// List timer units from all scopes (system + user)
+    pub async fn list_timers(&self) -> Result<Vec<Timer>> {
+        let mut all_timers = self.list_timers_by_scope(ServiceScope::System).await?;
+
+        if self.user_connection.is_some() {
+            if let Ok(user_timers) = self.list_timers_by_scope(ServiceScope::User).await {
+                all_timers.extend(user_timers);
+            }
+        }
+
+        Ok(all_timers)
+    }
+
+    /// List timer units from a specific scope with their schedule and trigger times
+    pub async fn list_timers_by_scope(&self, scope: ServiceScope) -> Result<Vec<Timer>> {
+        self.connection_manager.with_retry("list_timers_by_scope", || async {
+            let connection = self.connection_for(scope)?;
+            let units = self.list_units_by_patterns(scope, &["*.timer"]).await?;
+
+            // Timer properties live on a separate interface, one GetAll per timer
+            let timers = futures::stream::iter(units)
+                .map(|(name, description, _, active_state, sub_state, _, unit_path, _, _, _)| async move {
+                    let props = get_interface_properties(
+                        connection,
+                        unit_path.as_str(),
+                        "org.freedesktop.systemd1.Timer",
+                    )
+                    .await
+                    .unwrap_or_default();
+
+                    timer_from_properties(scope, name, description, active_state, sub_state, &props)
+                })
+                .buffered(MAX_CONCURRENT_CALLS)
+                .collect()
+                .await;
+
+            Ok(timers)
+        }).await
+    }
+
+    /// List socket units from all scopes (system + user)
+    pub async fn list_sockets(&self) -> Result<Vec<Socket>> {
+        let mut all_sockets = self.list_sockets_by_scope(ServiceScope::System).await?;
+
+        if self.user_connection.is_some() {
+            if let Ok(user_sockets) = self.list_sockets_by_scope(ServiceScope::User).await {
+                all_sockets.extend(user_sockets);
+            }
+        }
+
+        Ok(all_sockets)
+    }
+
+    /// List socket units from a specific scope with listen addresses and counters
+    pub async fn list_sockets_by_scope(&self, scope: ServiceScope) -> Result<Vec<Socket>> {
+        self.connection_manager.with_retry("list_sockets_by_scope", || async {
+            let connection = self.connection_for(scope)?;
+            let units = self.list_units_by_patterns(scope, &["*.socket"]).await?;
+
+            // Counters live on the Socket interface, Triggers on the Unit interface
+            let sockets = futures::stream::iter(units)
+                .map(|(name, description, _, active_state, sub_state, _, unit_path, _, _, _)| async move {
+                    let (socket_props, unit_props) = futures::join!(
+                        get_interface_properties(connection, unit_path.as_str(), "org.freedesktop.systemd1.Socket"),
+                        get_interface_properties(connection, unit_path.as_str(), "org.freedesktop.systemd1.Unit"),
+                    );
+
+                    socket_from_properties(
+                        scope,
                         name,
                         description,
-                        load_state,
                         active_state,
                         sub_state,
-                        pid: 0, // Will be filled by get_service_detail if needed
-                        enabled: is_enabled,
-                        scope,
-                        loaded_at: Utc::now(), // TODO: Get actual load time
-                    });
-                }
+                        &socket_props.unwrap_or_default(),
+                        &unit_props.unwrap_or_default(),
+                    )
+                })
+                .buffered(MAX_CONCURRENT_CALLS)
+                .collect()
+                .await;
+
+            Ok(sockets)
+        }).await
+    }
+
+    /// List queued jobs from all scopes (system + user)
+    pub async fn list_jobs(&self) -> Result<Vec<Job>> {
+        let mut all_jobs = self.list_jobs_by_scope(ServiceScope::System).await?;
+
+        if self.user_connection.is_some() {
+            if let Ok(user_jobs) = self.list_jobs_by_scope(ServiceScope::User).await {
+                all_jobs.extend(user_jobs);
             }
+        }
 
-            // Then, add unloaded service files (for user scope)
-            if scope == ServiceScope::User {
-                let unit_files: Vec<(String, String)> = proxy
-                    .call("ListUnitFiles", &())
+        Ok(all_jobs)
+    }
+
+    /// List the job queue of a specific scope's manager
+    pub async fn list_jobs_by_scope(&self, scope: ServiceScope) -> Result<Vec<Job>> {
+        self.connection_manager.with_retry("list_jobs_by_scope", || async {
+            let proxy = manager_proxy(self.connection_for(scope)?).await?;
+
+            let jobs: Vec<ListedJob> = proxy
+                .call("ListJobs", &())
+                .await
+                .map_err(|e| SysproxError::ServiceInfo(e.to_string()))?;
+
+            Ok(jobs
+                .into_iter()
+                .map(|(id, unit, job_type, state, _, _)| Job {
+                    id,
+                    unit,
+                    job_type,
+                    state,
+                    scope,
+                })
+                .collect())
+        }).await
+    }
+
+    /// List loaded units whose names match glob patterns (one D-Bus call)
+    async fn list_units_by_patterns(&self, scope: ServiceScope, patterns: &[&str]) -> Result<Vec<ListedUnit>> {
+        let proxy = manager_proxy(self.connection_for(scope)?).await?;
+
+        // ListUnitsByPatterns(states, patterns); empty states means "any state"
+        match proxy
+            .call::<_, _, Vec<ListedUnit>>("ListUnitsByPatterns", &(&[] as &[&str], patterns))
+            .await
+        {
+            Ok(units) => Ok(units),
+            Err(e) => {
+                // systemd < 230 has no ListUnitsByPatterns; filter ListUnits locally
+                tracing::debug!("ListUnitsByPatterns unavailable, falling back to ListUnits: {}", e);
+                let units: Vec<ListedUnit> = proxy
+                    .call("ListUnits", &())
                     .await
-                    .unwrap_or_default();
+                    .map_err(|e| SysproxError::ServiceInfo(e.to_string()))?;
 
-                for (path, state) in unit_files {
-                    // Extract service name from path
-                    if let Some(name) = path.split('/').next_back() {
-                        if name.ends_with(".service") && !service_names.contains(name) {
-                            let is_enabled = state == "enabled";
-                            services.push(Service {
-                                id: format!("{}:{}", scope.label(), name),
-                                name: name.to_string(),
-                                description: "(not loaded)".to_string(),
-                                load_state: "not-loaded".to_string(),
-                                active_state: "inactive".to_string(),
-                                sub_state: "dead".to_string(),
-                                pid: 0,
-                                enabled: is_enabled,
-                                scope,
-                                loaded_at: Utc::now(),
-                            });
-                        }
-                    }
+                Ok(units
+                    .into_iter()
+                    .filter(|unit| patterns.iter().any(|p| matches_unit_pattern(p, &unit.0)))
+                    .collect())
+            }
+        }
+    }
+
+    /// Map unit file name to its state (enabled, disabled, static, masked, ...)
+    async fn unit_file_states(&self, scope: ServiceScope) -> Result<std::collections::HashMap<String, String>> {
+        let proxy = manager_proxy(self.connection_for(scope)?).await?;
+
+        // ListUnitFiles returns (path, state) for every installed unit file
+        let unit_files: Vec<(String, String)> = proxy
+            .call("ListUnitFiles", &())
+            .await
+            .map_err(|e| SysproxError::ServiceInfo(format!("Failed to list unit files: {}", e)))?;
+
+        Ok(unit_files
+            .into_iter()
+            .filter_map(|(path, state)| {
+                path.rsplit('/').next().map(|name| (name.to_string(), state))
+            })
+            .collect())
+    }
+
+    /// Subscribe to systemd signals and push incremental unit updates
+    ///
+    /// Listens for `UnitNew`, `UnitRemoved`, `JobRemoved` and per-unit
+    /// `PropertiesChanged` on every available bus. Fails only if the system
+    /// manager subscription fails; the user bus is best effort.
+    pub async fn watch_units(&self, tx: mpsc::Sender<AppEvent>) -> Result<()> {
+        let system_stream = self.subscribe_scope(ServiceScope::System).await?;
+        spawn_unit_watcher(self.connection.clone(), ServiceScope::System, system_stream, tx.clone());
+
+        if let Some(user_connection) = &self.user_connection {
+            match self.subscribe_scope(ServiceScope::User).await {
+                Ok(user_stream) => {
+                    spawn_unit_watcher(user_connection.clone(), ServiceScope::User, user_stream, tx);
+                }
+                Err(e) => {
+                    tracing::warn!("Failed to subscribe to user manager signals: {}", e);
                 }
             }
+        }
 
-            Ok(services)
-        }).await
+        Ok(())
     }
 
-    /// Get detailed information for a specific service
-    pub async fn get_service_detail(&self, service_name: &str) -> Result<ServiceDetail> {
+    /// Call Manager.Subscribe and open a signal stream for a scope
+    async fn subscribe_scope(&self, scope: ServiceScope) -> Result<MessageStream> {
+        let connection = self.connection_for(scope)?;
+
+        // Register the match rule before subscribing so no signal is missed
+        let rule = zbus::MatchRule::builder()
+            .msg_type(zbus::message::Type::Signal)
+            .sender("org.freedesktop.systemd1")?
+            .build();
+        let stream = MessageStream::for_match_rule(rule, connection, Some(1024))
+            .await
+            .map_err(|e| SysproxError::SystemdConnection(e.to_string()))?;
+
+        let proxy = zbus::Proxy::new(
+            connection,
+            "org.freedesktop.systemd1",
+            "/org/freedesktop/systemd1",
+            "org.freedesktop.systemd1.Manager",
+        )
+        .await
+        .map_err(|e| SysproxError::SystemdConnection(e.to_string()))?;
+
+        // Without Subscribe, systemd does not emit unit/job signals
+        proxy
+            .call::<_, _, ()>("Subscribe", &())
+            .await
+            .map_err(|e| SysproxError::SystemdConnection(format!("Failed to subscribe: {}", e)))?;
+
+        tracing::info!("Subscribed to {} manager signals", scope.label());
+
+        Ok(stream)
+    }
+
+    /// Get the D-Bus connection for a scope
+    fn connection_for(&self, scope: ServiceScope) -> Result<&Connection> {
+        match scope {
+            ServiceScope::System => Ok(&self.connection),
+            ServiceScope::User => self.user_connection.as_ref().ok_or_else(|| {
+                SysproxError::SystemdConnection("User bus not available".to_string()).into()
+            }),
+        }
+    }
+
+    /// Get detailed information for one unit, looked up on the manager that owns it
+    pub async fn get_service_detail(&self, service_name: &str, scope: ServiceScope) -> Result<ServiceDetail> {
         self.connection_manager.with_retry("get_service_detail", || async {
-            // First get basic service info from ListUnits
-            let services = self.list_services().await?;
-            let service = services
-                .into_iter()
-                .find(|s| s.name == service_name)
-                .ok_or_else(|| {
-                    SysproxError::ServiceInfo(format!("Service '{}' not found", service_name))
-                })?;
+            // Query the manager that owns the unit (user units live on the session bus)
+            let props = self.get_unit_properties(service_name, scope).await?;
 
-            // Get unit properties
-            let props = self.get_unit_properties(service_name).await?;
+            let service = service_from_properties(&props, scope);
+            if service.load_state == "not-found" {
+                return Err(SysproxError::ServiceInfo(format!("Service '{}' not found", service_name)).into());
+            }
 
             // Extract properties with safe unwrapping
             let main_pid = props
@@ -200,14 +448,27 @@ impl SystemdClient {
                 .and_then(|v| v.downcast_ref::<String>().ok())
                 .unwrap_or_default();
 
+            let drop_in_paths = extract_string_array(&props, "DropInPaths");
+
+            let unit_file_state = props
+                .get("UnitFileState")
+                .and_then(|v| v.downcast_ref::<String>().ok())
+                .unwrap_or_default();
+
+            // A copy in /etc hides the packaged unit; upgrades to it go unnoticed
+            let vendor_path = unit_file::shadowed_vendor_copy(&load_path, service.scope).unwrap_or_default();
+
             let memory_current = props
                 .get("MemoryCurrent")
                 .and_then(|v| v.downcast_ref::<u64>().ok())
                 .unwrap_or(0);
 
+            // MemoryMax is the cgroup v2 limit; MemoryLimit is its legacy alias
             let memory_limit = props
-                .get("MemoryLimit")
+                .get("MemoryMax")
                 .and_then(|v| v.downcast_ref::<u64>().ok())
+                .filter(|limit| *limit != u64::MAX)
+                .or_else(|| props.get("MemoryLimit").and_then(|v| v.downcast_ref::<u64>().ok()))
                 .unwrap_or(u64::MAX);
 
             let cpu_usage_nsec = props
@@ -227,6 +488,17 @@ impl SystemdClient {
                 .and_then(|v| v.downcast_ref::<u64>().ok())
                 .unwrap_or(0);
 
+            let get_limit = |key: &str| {
+                props
+                    .get(key)
+                    .and_then(|v| v.downcast_ref::<u64>().ok())
+                    .unwrap_or(u64::MAX)
+            };
+            let memory_high = get_limit("MemoryHigh");
+            let cpu_quota_per_sec_usec = get_limit("CPUQuotaPerSecUSec");
+            let cpu_weight = get_limit("CPUWeight");
+            let io_weight = get_limit("IOWeight");
+
             let n_restarts = props
                 .get("NRestarts")
                 .and_then(|v| v.downcast_ref::<u32>().ok())
@@ -244,8 +516,10 @@ impl SystemdClient {
             let before = extract_string_array(&props, "Before");
 
             // Extract service configuration details
+            // Mounts have a Type too (the filesystem); only a service's is its start type
             let service_type = props
                 .get("Type")
+                .filter(|_| service.kind == UnitKind::Service)
                 .and_then(|v| v.downcast_ref::<String>().ok())
                 .unwrap_or_default();
 
@@ -271,6 +545,18 @@ impl SystemdClient {
 
             let environment = extract_string_array(&props, "Environment");
 
+            // Type-specific properties (Where for mounts, Paths for paths, ...)
+            let type_properties = service
+                .kind
+                .detail_properties()
+                .iter()
+                .filter_map(|key| {
+                    props
+                        .get(*key)
+                        .map(|value| (key.to_string(), format_property_value(key, value)))
+                })
+                .collect();
+
             // Extract ExecStart command
             let exec_main_start = if let Some(exec_start_value) = props.get("ExecStart") {
                 // Convert to string and try to extract the path
@@ -376,6 +662,9 @@ impl SystemdClient {
                 main_pid,
                 control_pid,
                 load_path,
+                drop_in_paths,
+                unit_file_state,
+                vendor_path,
                 exec_main_start,
                 exec_main_status: String::new(),
                 memory_current,
@@ -383,6 +672,10 @@ impl SystemdClient {
                 cpu_usage_nsec,
                 tasks_current,
                 tasks_max,
+                memory_high,
+                cpu_quota_per_sec_usec,
+                cpu_weight,
+                io_weight,
                 n_restarts,
                 active_enter_time,
                 active_exit_time,
@@ -399,6 +692,7 @@ impl SystemdClient {
                 group,
                 working_directory,
                 environment,
+                type_properties,
             };
 
             Ok(detail)
@@ -409,10 +703,12 @@ impl SystemdClient {
     async fn get_unit_properties(
         &self,
         unit_name: &str,
+        scope: ServiceScope,
     ) -> Result<std::collections::HashMap<String, zbus::zvariant::OwnedValue>> {
         self.connection_manager.with_retry("get_unit_properties", || async {
+            let connection = self.connection_for(scope)?;
             let proxy = zbus::Proxy::new(
-                &self.connection,
+                connection,
                 "org.freedesktop.systemd1",
                 "/org/freedesktop/systemd1",
                 "org.freedesktop.systemd1.Manager",
@@ -427,20 +723,11 @@ impl SystemdClient {
                 .await
                 .map_err(|e| SysproxError::ServiceInfo(e.to_string()))?;
 
-            // GetAll on the Properties interface
-            let props_proxy = zbus::fdo::PropertiesProxy::builder(&self.connection)
-                .destination("org.freedesktop.systemd1")?
-                .path(unit_path.as_str())?
-                .build()
-                .await
-                .map_err(|e| SysproxError::SystemdConnection(e.to_string()))?;
-
-            // Get all properties from the Service interface
-            use zbus::zvariant::Optional;
-            let props = props_proxy
-                .get_all(Optional::default())  // Get all properties
-                .await
-                .map_err(|e| SysproxError::ServiceInfo(e.to_string()))?;
+            // Generic Unit properties, plus those of the unit's own type
+            let mut props = get_interface_properties(connection, unit_path.as_str(), "org.freedesktop.systemd1.Unit").await?;
+            if let Some(interface) = UnitKind::from_unit_name(unit_name).and_then(|kind| kind.interface()) {
+                props.extend(get_interface_properties(connection, unit_path.as_str(), interface).await?);
+            }
 
             Ok(props)
         }).await
@@ -449,34 +736,9 @@ impl SystemdClient {
     /// Get current metrics for a service
     pub async fn get_service_metrics(&self, service_name: &str) -> Result<Metrics> {
         self.connection_manager.with_retry("get_service_metrics", || async {
-            let props = self.get_unit_properties(service_name).await?;
+            let props = self.get_unit_properties(service_name, ServiceScope::System).await?;
 
-            let cpu_usage_nsec = props
-                .get("CPUUsageNSec")
-                .and_then(|v| v.downcast_ref::<u64>().ok())
-                .unwrap_or(0);
-
-            let memory_current = props
-                .get("MemoryCurrent")
-                .and_then(|v| v.downcast_ref::<u64>().ok())
-                .unwrap_or(0);
-
-            let tasks_current = props
-                .get("TasksCurrent")
-                .and_then(|v| v.downcast_ref::<u64>().ok())
-                .unwrap_or(0);
-
-            let n_restarts = props
-                .get("NRestarts")
-                .and_then(|v| v.downcast_ref::<u32>().ok())
-                .unwrap_or(0);
-
-            Ok(Metrics {
-                cpu_usage_nsec,
-                memory_current,
-                tasks_current,
-                n_restarts,
-            })
+            Ok(metrics_from_properties(&props))
         }).await
     }
 
@@ -498,7 +760,7 @@ impl SystemdClient {
                 .await
                 .map_err(|e| SysproxError::ServiceInfo(format!("Failed to get unit file state: {}", e)))?;
 
-            Ok(state == "enabled" || state == "enabled-runtime")
+            Ok(is_enabled_state(&state))
         }).await
     }
 
@@ -523,7 +785,7 @@ impl SystemdClient {
             let enabled_services = unit_files
                 .into_iter()
                 .filter_map(|(path, state)| {
-                    if path.ends_with(".service") && (state == "enabled" || state == "enabled-runtime") {
+                    if path.ends_with(".service") && is_enabled_state(&state) {
                         Some(path)
                     } else {
                         None
@@ -537,7 +799,7 @@ impl SystemdClient {
 
     /// Get service status with enable/disable information
     pub async fn get_service_status_extended(&self, service_name: &str) -> Result<ServiceStatusExtended> {
-        let service = self.get_service_detail(service_name).await?;
+        let service = self.get_service_detail(service_name, ServiceScope::System).await?;
         let is_enabled = self.is_service_enabled(service_name).await?;
 
         Ok(ServiceStatusExtended {
@@ -546,30 +808,444 @@ impl SystemdClient {
         })
     }
 
-    /// Check if service is enabled in a specific scope
-    async fn check_service_enabled_in_scope(&self, service_name: &str, scope: ServiceScope) -> Result<bool> {
-        let connection = match scope {
-            ServiceScope::System => &self.connection,
-            ServiceScope::User => self.user_connection.as_ref()
-                .ok_or_else(|| SysproxError::SystemdConnection("User bus not available".to_string()))?,
-        };
-
-        let proxy = zbus::Proxy::new(
-            connection,
-            "org.freedesktop.systemd1",
-            "/org/freedesktop/systemd1",
-            "org.freedesktop.systemd1.Manager",
-        )
-        .await
-        .map_err(|e| SysproxError::SystemdConnection(e.to_string()))?;
+    /// Get the unit file state of a unit in a specific scope
+    async fn get_unit_file_state_in_scope(&self, unit_name: &str, scope: ServiceScope) -> Result<String> {
+        let proxy = manager_proxy(self.connection_for(scope)?).await?;
 
         let state: String = proxy
-            .call("GetUnitFileState", &(service_name,))
+            .call("GetUnitFileState", &(unit_name,))
             .await
             .map_err(|e| SysproxError::ServiceInfo(format!("Failed to get unit file state: {}", e)))?;
 
-        Ok(state == "enabled" || state == "enabled-runtime")
+        Ok(state)
+    }
+}
+
+/// Create a proxy for the systemd Manager interface
+async fn manager_proxy(connection: &Connection) -> Result<zbus::Proxy<'static>> {
+    let proxy = zbus::Proxy::new(
+        connection,
+        "org.freedesktop.systemd1",
+        "/org/freedesktop/systemd1",
+        "org.freedesktop.systemd1.Manager",
+    )
+    .await
+    .map_err(|e| SysproxError::SystemdConnection(e.to_string()))?;
+
+    Ok(proxy)
+}
+
+/// GetAll properties of a single interface on a unit object
+async fn get_interface_properties(
+    connection: &Connection,
+    unit_path: &str,
+    interface: &'static str,
+) -> Result<std::collections::HashMap<String, zbus::zvariant::OwnedValue>> {
+    let props_proxy = zbus::fdo::PropertiesProxy::builder(connection)
+        .destination("org.freedesktop.systemd1")?
+        .path(unit_path)?
+        .build()
+        .await
+        .map_err(|e| SysproxError::SystemdConnection(e.to_string()))?;
+
+    let interface = zbus::names::InterfaceName::from_static_str(interface)?;
+    let props = props_proxy
+        .get_all(zbus::zvariant::Optional::from(Some(interface)))
+        .await
+        .map_err(|e| SysproxError::ServiceInfo(e.to_string()))?;
+
+    Ok(props)
+}
+
+/// Build a Timer from its ListUnits row and Timer interface properties
+fn timer_from_properties(
+    scope: ServiceScope,
+    name: String,
+    description: String,
+    active_state: String,
+    sub_state: String,
+    props: &std::collections::HashMap<String, zbus::zvariant::OwnedValue>,
+) -> Timer {
+    let get_u64 = |key: &str| {
+        props
+            .get(key)
+            .and_then(|v| v.downcast_ref::<u64>().ok())
+            .unwrap_or(0)
+    };
+
+    let unit = props
+        .get("Unit")
+        .and_then(|v| v.downcast_ref::<String>().ok())
+        .unwrap_or_default();
+
+    let persistent = props
+        .get("Persistent")
+        .and_then(|v| v.downcast_ref::<bool>().ok())
+        .unwrap_or(false);
+
+    // TimersCalendar is a(sst): (base, calendar spec, next elapse)
+    let calendar: Vec<(String, String, u64)> = props
+        .get("TimersCalendar")
+        .and_then(|v| v.try_clone().ok())
+        .and_then(|v| Vec::try_from(v).ok())
+        .unwrap_or_default();
+
+    // TimersMonotonic is a(stt): (base, span in usec, next elapse)
+    let monotonic: Vec<(String, u64, u64)> = props
+        .get("TimersMonotonic")
+        .and_then(|v| v.try_clone().ok())
+        .and_then(|v| Vec::try_from(v).ok())
+        .unwrap_or_default();
+
+    let mut schedule: Vec<String> = calendar
+        .into_iter()
+        .map(|(base, spec, _)| format!("{}={}", base, spec))
+        .collect();
+    schedule.extend(monotonic.into_iter().map(|(base, usec, _)| {
+        // systemd reports "OnBootUSec"; unit files spell it "OnBootSec"
+        format!("{}={}", base.trim_end_matches("USec").to_string() + "Sec", format_usec_span(usec))
+    }));
+
+    let next_elapse = timestamp_from_usec(get_u64("NextElapseUSecRealtime"))
+        .or_else(|| monotonic_to_realtime(get_u64("NextElapseUSecMonotonic")));
+
+    Timer {
+        id: format!("{}:{}", scope.label(), name),
+        name,
+        description,
+        active_state,
+        sub_state,
+        scope,
+        unit,
+        next_elapse,
+        last_trigger: timestamp_from_usec(get_u64("LastTriggerUSec")),
+        persistent,
+        schedule,
+    }
+}
+
+/// Build a Socket from its ListUnits row plus Socket and Unit interface properties
+fn socket_from_properties(
+    scope: ServiceScope,
+    name: String,
+    description: String,
+    active_state: String,
+    sub_state: String,
+    socket_props: &std::collections::HashMap<String, zbus::zvariant::OwnedValue>,
+    unit_props: &std::collections::HashMap<String, zbus::zvariant::OwnedValue>,
+) -> Socket {
+    let get_u32 = |key: &str| {
+        socket_props
+            .get(key)
+            .and_then(|v| v.downcast_ref::<u32>().ok())
+            .unwrap_or(0)
+    };
+
+    // Listen is a(ss): (type, address)
+    let listen: Vec<(String, String)> = socket_props
+        .get("Listen")
+        .and_then(|v| v.try_clone().ok())
+        .and_then(|v| Vec::try_from(v).ok())
+        .unwrap_or_default();
+
+    let triggers: Vec<String> = unit_props
+        .get("Triggers")
+        .and_then(|v| v.try_clone().ok())
+        .and_then(|v| Vec::try_from(v).ok())
+        .unwrap_or_default();
+
+    Socket {
+        id: format!("{}:{}", scope.label(), name),
+        name,
+        description,
+        active_state,
+        sub_state,
+        scope,
+        unit: triggers.into_iter().next().unwrap_or_default(),
+        listen: listen
+            .into_iter()
+            .map(|(kind, address)| format!("{} ({})", address, kind))
+            .collect(),
+        n_accepted: get_u32("NAccepted"),
+        n_connections: get_u32("NConnections"),
+        n_refused: get_u32("NRefused"),
+    }
+}
+
+/// Approximate wall-clock time of a CLOCK_MONOTONIC timestamp (usec)
+///
+/// Uses /proc/uptime as "now" on the monotonic clock, which is accurate to
+/// within any time the machine spent suspended.
+fn monotonic_to_realtime(usec: u64) -> Option<chrono::DateTime<Utc>> {
+    if usec == 0 || usec == u64::MAX {
+        return None;
     }
+
+    let uptime_secs: f64 = std::fs::read_to_string("/proc/uptime")
+        .ok()?
+        .split_whitespace()
+        .next()?
+        .parse()
+        .ok()?;
+
+    let now_usec = (uptime_secs * 1_000_000.0) as i64;
+    Some(Utc::now() + chrono::Duration::microseconds(usec as i64 - now_usec))
+}
+
+/// Look up a unit's file state from the ListUnitFiles map
+///
+/// Instances ("getty@tty1.service") are enabled one by one, so the template's
+/// entry says nothing about them; they are left to `GetUnitFileState`.
+pub(crate) fn unit_file_state_for<'a>(
+    states: &'a std::collections::HashMap<String, String>,
+    unit_name: &str,
+) -> Option<&'a str> {
+    states.get(unit_name).map(String::as_str)
+}
+
+/// Returns true if a unit is a template instance missing from the ListUnitFiles map
+pub(crate) fn needs_unit_file_lookup(states: &std::collections::HashMap<String, String>, unit_name: &str) -> bool {
+    unit_name.contains('@') && unit_file_state_for(states, unit_name).is_none()
+}
+
+/// Match a unit name against a simple systemd glob ("*.service", "foo*")
+fn matches_unit_pattern(pattern: &str, name: &str) -> bool {
+    match pattern.split_once('*') {
+        Some((prefix, suffix)) => {
+            name.len() >= prefix.len() + suffix.len() && name.starts_with(prefix) && name.ends_with(suffix)
+        }
+        None => pattern == name,
+    }
+}
+
+/// Spawn a task that turns systemd signals into dashboard updates
+fn spawn_unit_watcher(
+    connection: Connection,
+    scope: ServiceScope,
+    mut stream: MessageStream,
+    tx: mpsc::Sender<AppEvent>,
+) {
+    tokio::spawn(async move {
+        while let Some(msg) = stream.next().await {
+            let msg = match msg {
+                Ok(msg) => msg,
+                Err(e) => {
+                    tracing::warn!("Error reading {} signal stream: {}", scope.label(), e);
+                    continue;
+                }
+            };
+
+            // Any job queue change refreshes views that show the queue
+            let member = msg.header().member().map(|m| m.to_string());
+            if matches!(member.as_deref(), Some("JobNew") | Some("JobRemoved"))
+                && tx.send(AppEvent::JobsChanged).await.is_err()
+            {
+                break;
+            }
+
+            let event = match handle_unit_signal(&connection, scope, &msg).await {
+                Ok(Some(event)) => event,
+                Ok(None) => continue,
+                Err(e) => {
+                    tracing::debug!("Ignoring {} signal: {}", scope.label(), e);
+                    continue;
+                }
+            };
+
+            if tx.send(event).await.is_err() {
+                break;
+            }
+        }
+
+        tracing::info!("Stopped watching {} manager signals", scope.label());
+        // Let the app resume tick polling (fails only if the app is gone)
+        let _ = tx.send(AppEvent::UnitWatchStopped(scope)).await;
+    });
+}
+
+/// Translate one systemd signal into an app event (if it affects the listing)
+async fn handle_unit_signal(
+    connection: &Connection,
+    scope: ServiceScope,
+    msg: &zbus::Message,
+) -> Result<Option<AppEvent>> {
+    let header = msg.header();
+    let member = header.member().map(|m| m.as_str()).unwrap_or_default();
+    let body = msg.body();
+
+    match member {
+        "UnitNew" => {
+            let (name, path): (String, zbus::zvariant::OwnedObjectPath) = body.deserialize()?;
+            if !is_listed_unit(&name) {
+                return Ok(None);
+            }
+            let service = fetch_unit_listing(connection, path.as_str(), scope).await?;
+            Ok(Some(AppEvent::ServiceUpdated(service)))
+        }
+        "UnitRemoved" => {
+            let (name, _path): (String, zbus::zvariant::OwnedObjectPath) = body.deserialize()?;
+            if !is_listed_unit(&name) {
+                return Ok(None);
+            }
+            refetch_unit(connection, scope, &name).await.map(Some)
+        }
+        "JobRemoved" => {
+            // JobRemoved carries (id, job, unit, result)
+            let (_id, _job, name, _result): (u32, zbus::zvariant::OwnedObjectPath, String, String) =
+                body.deserialize()?;
+            if !is_listed_unit(&name) {
+                return Ok(None);
+            }
+            refetch_unit(connection, scope, &name).await.map(Some)
+        }
+        "PropertiesChanged" => {
+            let Some(path) = header.path() else {
+                return Ok(None);
+            };
+            if !path.as_str().starts_with("/org/freedesktop/systemd1/unit/") {
+                return Ok(None);
+            }
+
+            // Only Unit interface changes affect the listing columns
+            let (interface, _changed, _invalidated): (
+                String,
+                std::collections::HashMap<String, zbus::zvariant::OwnedValue>,
+                Vec<String>,
+            ) = body.deserialize()?;
+            if interface != "org.freedesktop.systemd1.Unit" {
+                return Ok(None);
+            }
+
+            let service = fetch_unit_listing(connection, path.as_str(), scope).await?;
+            if !is_listed_unit(&service.name) {
+                return Ok(None);
+            }
+            Ok(Some(AppEvent::ServiceUpdated(service)))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Build a listing entry for one unit object path
+async fn fetch_unit_listing(connection: &Connection, unit_path: &str, scope: ServiceScope) -> Result<Service> {
+    let props = get_interface_properties(connection, unit_path, "org.freedesktop.systemd1.Unit").await?;
+    Ok(service_from_properties(&props, scope))
+}
+
+/// Build a listing row from a unit's Unit interface properties
+fn service_from_properties(
+    props: &std::collections::HashMap<String, zbus::zvariant::OwnedValue>,
+    scope: ServiceScope,
+) -> Service {
+    let get_string = |key: &str| {
+        props
+            .get(key)
+            .and_then(|v| v.downcast_ref::<String>().ok())
+            .unwrap_or_default()
+    };
+
+    let name = get_string("Id");
+    let unit_file_state = get_string("UnitFileState");
+    let kind = UnitKind::from_unit_name(&name).unwrap_or_default();
+
+    Service {
+        id: format!("{}:{}", scope.label(), name),
+        name,
+        description: get_string("Description"),
+        load_state: get_string("LoadState"),
+        active_state: get_string("ActiveState"),
+        sub_state: get_string("SubState"),
+        pid: 0,
+        enabled: is_enabled_state(&unit_file_state),
+        scope,
+        kind,
+        freezer_state: get_string("FreezerState"),
+        loaded_at: Utc::now(),
+    }
+}
+
+/// Re-read a unit after a signal; it may already have been garbage collected
+///
+/// A collected user unit that still has a unit file stays listed as
+/// "(not loaded)", as in `list_services_by_scope`; anything else is removed.
+async fn refetch_unit(connection: &Connection, scope: ServiceScope, name: &str) -> Result<AppEvent> {
+    let proxy = manager_proxy(connection).await?;
+
+    if let Ok(path) = proxy.call::<_, _, zbus::zvariant::OwnedObjectPath>("GetUnit", &(name,)).await {
+        let service = fetch_unit_listing(connection, path.as_str(), scope).await?;
+        return Ok(AppEvent::ServiceUpdated(service));
+    }
+
+    if scope == ServiceScope::User {
+        if let Ok(state) = proxy.call::<_, _, String>("GetUnitFileState", &(name,)).await {
+            return Ok(AppEvent::ServiceUpdated(not_loaded_service(name, &state, scope)));
+        }
+    }
+
+    Ok(AppEvent::ServiceRemoved {
+        id: format!("{}:{}", scope.label(), name),
+    })
+}
+
+/// Listing row for a unit file that systemd has not loaded
+pub(crate) fn not_loaded_service(name: &str, unit_file_state: &str, scope: ServiceScope) -> Service {
+    Service {
+        id: format!("{}:{}", scope.label(), name),
+        name: name.to_string(),
+        description: "(not loaded)".to_string(),
+        load_state: "not-loaded".to_string(),
+        active_state: "inactive".to_string(),
+        sub_state: "dead".to_string(),
+        pid: 0,
+        enabled: is_enabled_state(unit_file_state),
+        scope,
+        kind: UnitKind::from_unit_name(name).unwrap_or_default(),
+        freezer_state: String::new(),
+        loaded_at: Utc::now(),
+    }
+}
+
+/// Returns true if a unit of this name belongs in the dashboard listing
+fn is_listed_unit(name: &str) -> bool {
+    UnitKind::from_unit_name(name).is_some_and(|kind| kind.is_listed())
+}
+
+/// Render a D-Bus property value for display ("*USec" spans as "1min 30s")
+fn format_property_value(key: &str, value: &zbus::zvariant::OwnedValue) -> String {
+    if let Ok(s) = value.downcast_ref::<String>() {
+        return s;
+    }
+    if let Ok(b) = value.downcast_ref::<bool>() {
+        return if b { "yes" } else { "no" }.to_string();
+    }
+    if let Ok(n) = value.downcast_ref::<u64>() {
+        return match n {
+            u64::MAX => "infinity".to_string(),
+            _ if key.ends_with("USec") => format_usec_span(n),
+            _ => n.to_string(),
+        };
+    }
+    if let Ok(n) = value.downcast_ref::<u32>() {
+        return n.to_string();
+    }
+    if let Ok(n) = value.downcast_ref::<i32>() {
+        return n.to_string();
+    }
+
+    // Arrays of (type, value) pairs: Listen, Paths
+    if let Ok(Ok(pairs)) = value.try_clone().map(Vec::<(String, String)>::try_from) {
+        return pairs
+            .into_iter()
+            .map(|(kind, target)| format!("{}={}", kind, target))
+            .collect::<Vec<_>>()
+            .join(", ");
+    }
+    if let Ok(Ok(items)) = value.try_clone().map(Vec::<String>::try_from) {
+        return items.join(", ");
+    }
+
+    // Fall back to the GVariant text form for anything more exotic
+    format!("{}", &**value)
 }
 
 /// Helper to extract string arrays from D-Bus properties
@@ -1,22 +1,52 @@
 // Systemd service control operations
 
 use crate::error::{Result, SysproxError};
-use zbus::Connection;
+use crate::systemd::{JobResult, KillSignal, KillTarget, ResourceLimit, ServiceScope, TransientKind, TransientSpec, UnitKind};
+use futures::StreamExt;
+use std::time::Duration;
+use zbus::{Connection, MessageStream};
+
+/// How long to wait for a queued job before giving up on reporting its result
+const JOB_WAIT_TIMEOUT: Duration = Duration::from_secs(300);
 
 /// Service controller for systemd operations
 pub struct ServiceController {
     connection: Connection,
+    user_connection: Option<Connection>,
 }
 
 impl ServiceController {
-    /// Create a new service controller with D-Bus connection
+    /// Create a new service controller with D-Bus connections
     pub async fn new() -> Result<Self> {
         // Connect to system bus - polkit will handle authentication
         let connection = Connection::system()
             .await
             .map_err(|e| SysproxError::SystemdConnection(e.to_string()))?;
 
-        Ok(Self { connection })
+        // User units are managed by the per-user manager on the session bus
+        let user_connection = Connection::session().await.ok();
+
+        Ok(Self { connection, user_connection })
+    }
+
+    /// Get the bus connection for the manager that owns units of a scope
+    fn connection_for(&self, scope: ServiceScope) -> Result<&Connection> {
+        match scope {
+            ServiceScope::System => Ok(&self.connection),
+            ServiceScope::User => self.user_connection.as_ref().ok_or_else(|| {
+                SysproxError::SystemdConnection("No session bus for user units".to_string()).into()
+            }),
+        }
+    }
+
+    /// Check authorization for an operation in a scope
+    ///
+    /// The user manager never asks polkit, so only system units need an agent.
+    fn check_authorization(scope: ServiceScope) -> Result<()> {
+        match scope {
+            ServiceScope::System => Self::check_polkit_available(),
+            ServiceScope::User => Ok(()),
+        }
     }
 
     /// Check if we can perform privileged operations
@@ -52,13 +82,49 @@ impl ServiceController {
         }.into())
     }
 
-    /// Start a service unit
-    pub async fn start_service(&self, service_name: &str) -> Result<()> {
+    /// Start a unit and wait for its job to finish
+    pub async fn start_service(&self, service_name: &str, scope: ServiceScope) -> Result<JobResult> {
+        self.run_job(service_name, scope, "StartUnit", "start").await
+    }
+
+    /// Stop a unit and wait for its job to finish
+    pub async fn stop_service(&self, service_name: &str, scope: ServiceScope) -> Result<JobResult> {
+        self.run_job(service_name, scope, "StopUnit", "stop").await
+    }
+
+    /// Restart a unit and wait for its job to finish
+    pub async fn restart_service(&self, service_name: &str, scope: ServiceScope) -> Result<JobResult> {
+        self.run_job(service_name, scope, "RestartUnit", "restart").await
+    }
+
+    /// Reload a unit (if supported) and wait for its job to finish
+    pub async fn reload_service(&self, service_name: &str, scope: ServiceScope) -> Result<JobResult> {
+        self.run_job(service_name, scope, "ReloadUnit", "reload").await
+    }
+
+    /// Queue a job with a Manager method and wait for its JobRemoved signal
+    ///
+    /// The signal stream is opened before the method call so a job that
+    /// finishes immediately cannot be missed.
+    async fn run_job(&self, service_name: &str, scope: ServiceScope, method: &str, verb: &str) -> Result<JobResult> {
         self.validate_service_name(service_name)?;
-        Self::check_polkit_available()?;
+        Self::check_authorization(scope)?;
+
+        let connection = self.connection_for(scope)?;
+
+        let rule = zbus::MatchRule::builder()
+            .msg_type(zbus::message::Type::Signal)
+            .sender("org.freedesktop.systemd1")?
+            .path("/org/freedesktop/systemd1")?
+            .interface("org.freedesktop.systemd1.Manager")?
+            .member("JobRemoved")?
+            .build();
+        let mut stream = MessageStream::for_match_rule(rule, connection, Some(64))
+            .await
+            .map_err(|e| SysproxError::SystemdConnection(e.to_string()))?;
 
         let proxy = zbus::Proxy::new(
-            &self.connection,
+            connection,
             "org.freedesktop.systemd1",
             "/org/freedesktop/systemd1",
             "org.freedesktop.systemd1.Manager",
@@ -66,36 +132,81 @@ impl ServiceController {
         .await
         .map_err(|e| SysproxError::SystemdConnection(e.to_string()))?;
 
-        // StartUnit returns the job object path
+        // Signals are only emitted while some client is subscribed; a repeat
+        // subscription from this connection is refused, which is fine
+        let _ = proxy.call::<_, _, ()>("Subscribe", &()).await;
+
+        // The call returns the job object path
         // This will trigger polkit authentication dialog if privileges are needed
-        let _job_path: zbus::zvariant::OwnedObjectPath = proxy
-            .call("StartUnit", &(service_name, "replace"))
+        let job_path: zbus::zvariant::OwnedObjectPath = proxy
+            .call(method, &(service_name, "replace"))
             .await
-            .map_err(|e| {
-                let error_msg = e.to_string();
-                if error_msg.contains("Access denied") || error_msg.contains("Authentication") {
-                    SysproxError::ServiceControl {
-                        service: service_name.to_string(),
-                        message: "Access denied. Authentication required - ensure polkit agent is running or use sudo.".to_string(),
-                    }
-                } else {
-                    SysproxError::ServiceControl {
-                        service: service_name.to_string(),
-                        message: format!("Failed to start: {}", e),
-                    }
+            .map_err(|e| control_error(service_name, verb, e))?;
+
+        let wait = async {
+            while let Some(msg) = stream.next().await {
+                let Ok(msg) = msg else { continue };
+                // JobRemoved carries (id, job, unit, result)
+                let Ok((_id, job, _unit, result)) = msg
+                    .body()
+                    .deserialize::<(u32, zbus::zvariant::OwnedObjectPath, String, String)>()
+                else {
+                    continue;
+                };
+                if job == job_path {
+                    return Some(JobResult::from_result(&result));
                 }
+            }
+            None
+        };
+
+        match tokio::time::timeout(JOB_WAIT_TIMEOUT, wait).await {
+            Ok(Some(result)) => Ok(result),
+            Ok(None) => Err(SysproxError::SystemdConnection("Signal stream closed while waiting for job".to_string()).into()),
+            Err(_) => Err(SysproxError::ServiceControl {
+                service: service_name.to_string(),
+                message: format!(
+                    "{} job {} still pending after {}s",
+                    verb,
+                    job_path.as_str(),
+                    JOB_WAIT_TIMEOUT.as_secs()
+                ),
+            }
+            .into()),
+        }
+    }
+
+    /// Cancel a queued or running job by id
+    pub async fn cancel_job(&self, job_id: u32, scope: ServiceScope) -> Result<()> {
+        Self::check_authorization(scope)?;
+
+        let proxy = zbus::Proxy::new(
+            self.connection_for(scope)?,
+            "org.freedesktop.systemd1",
+            "/org/freedesktop/systemd1",
+            "org.freedesktop.systemd1.Manager",
+        )
+        .await
+        .map_err(|e| SysproxError::SystemdConnection(e.to_string()))?;
+
+        proxy
+            .call::<_, _, ()>("CancelJob", &(job_id,))
+            .await
+            .map_err(|e| SysproxError::ServiceControl {
+                service: format!("job {}", job_id),
+                message: format!("Failed to cancel job: {}", e),
             })?;
 
         Ok(())
     }
 
-    /// Stop a service unit
-    pub async fn stop_service(&self, service_name: &str) -> Result<()> {
+    /// Enable a service unit (creates symlinks)
+    pub async fn enable_service(&self, service_name: &str, scope: ServiceScope) -> Result<JobResult> {
         self.validate_service_name(service_name)?;
-        Self::check_polkit_available()?;
+        Self::check_authorization(scope)?;
 
         let proxy = zbus::Proxy::new(
-            &self.connection,
+            self.connection_for(scope)?,
             "org.freedesktop.systemd1",
             "/org/freedesktop/systemd1",
             "org.freedesktop.systemd1.Manager",
@@ -103,34 +214,26 @@ impl ServiceController {
         .await
         .map_err(|e| SysproxError::SystemdConnection(e.to_string()))?;
 
-        let _job_path: zbus::zvariant::OwnedObjectPath = proxy
-            .call("StopUnit", &(service_name, "replace"))
+        // EnableUnitFiles returns (changes, carries_install_info)
+        let (_changes, _carries_install_info): (Vec<(String, String, String)>, bool) = proxy
+            .call("EnableUnitFiles", &(&[service_name][..], false, true))
             .await
-            .map_err(|e| {
-                let error_msg = e.to_string();
-                if error_msg.contains("Access denied") || error_msg.contains("Authentication") {
-                    SysproxError::ServiceControl {
-                        service: service_name.to_string(),
-                        message: "Access denied. Authentication required - ensure polkit agent is running or use sudo.".to_string(),
-                    }
-                } else {
-                    SysproxError::ServiceControl {
-                        service: service_name.to_string(),
-                        message: format!("Failed to stop: {}", e),
-                    }
-                }
-            })?;
+            .map_err(|e| control_error(service_name, "enable", e))?;
 
-        Ok(())
+        // Reload systemd daemon to apply changes
+        self.reload_daemon(scope).await?;
+
+        // Unit file changes complete synchronously; there is no job to wait for
+        Ok(JobResult::Done)
     }
 
-    /// Restart a service unit
-    pub async fn restart_service(&self, service_name: &str) -> Result<()> {
+    /// Disable a service unit (removes symlinks)
+    pub async fn disable_service(&self, service_name: &str, scope: ServiceScope) -> Result<JobResult> {
         self.validate_service_name(service_name)?;
-        Self::check_polkit_available()?;
+        Self::check_authorization(scope)?;
 
         let proxy = zbus::Proxy::new(
-            &self.connection,
+            self.connection_for(scope)?,
             "org.freedesktop.systemd1",
             "/org/freedesktop/systemd1",
             "org.freedesktop.systemd1.Manager",
@@ -138,34 +241,26 @@ impl ServiceController {
         .await
         .map_err(|e| SysproxError::SystemdConnection(e.to_string()))?;
 
-        let _job_path: zbus::zvariant::OwnedObjectPath = proxy
-            .call("RestartUnit", &(service_name, "replace"))
+        // DisableUnitFiles returns (changes, carries_install_info)
+        let (_changes, _carries_install_info): (Vec<(String, String, String)>, bool) = proxy
+            .call("DisableUnitFiles", &(&[service_name][..], false))
             .await
-            .map_err(|e| {
-                let error_msg = e.to_string();
-                if error_msg.contains("Access denied") || error_msg.contains("Authentication") {
-                    SysproxError::ServiceControl {
-                        service: service_name.to_string(),
-                        message: "Access denied. Authentication required - ensure polkit agent is running or use sudo.".to_string(),
-                    }
-                } else {
-                    SysproxError::ServiceControl {
-                        service: service_name.to_string(),
-                        message: format!("Failed to restart: {}", e),
-                    }
-                }
-            })?;
+            .map_err(|e| control_error(service_name, "disable", e))?;
 
-        Ok(())
+        // Reload systemd daemon to apply changes
+        self.reload_daemon(scope).await?;
+
+        // Unit file changes complete synchronously; there is no job to wait for
+        Ok(JobResult::Done)
     }
 
-    /// Reload a service unit (if supported)
-    pub async fn reload_service(&self, service_name: &str) -> Result<()> {
+    /// Mask a unit (links its unit file to /dev/null so it cannot be started)
+    pub async fn mask_service(&self, service_name: &str, scope: ServiceScope) -> Result<JobResult> {
         self.validate_service_name(service_name)?;
-        Self::check_polkit_available()?;
+        Self::check_authorization(scope)?;
 
         let proxy = zbus::Proxy::new(
-            &self.connection,
+            self.connection_for(scope)?,
             "org.freedesktop.systemd1",
             "/org/freedesktop/systemd1",
             "org.freedesktop.systemd1.Manager",
@@ -173,34 +268,25 @@ impl ServiceController {
         .await
         .map_err(|e| SysproxError::SystemdConnection(e.to_string()))?;
 
-        let _job_path: zbus::zvariant::OwnedObjectPath = proxy
-            .call("ReloadUnit", &(service_name, "replace"))
+        // MaskUnitFiles(files, runtime, force) returns the symlink changes
+        let _changes: Vec<(String, String, String)> = proxy
+            .call("MaskUnitFiles", &(&[service_name][..], false, false))
             .await
-            .map_err(|e| {
-                let error_msg = e.to_string();
-                if error_msg.contains("Access denied") || error_msg.contains("Authentication") {
-                    SysproxError::ServiceControl {
-                        service: service_name.to_string(),
-                        message: "Access denied. Authentication required - ensure polkit agent is running or use sudo.".to_string(),
-                    }
-                } else {
-                    SysproxError::ServiceControl {
-                        service: service_name.to_string(),
-                        message: format!("Failed to reload: {}", e),
-                    }
-                }
-            })?;
+            .map_err(|e| control_error(service_name, "mask", e))?;
 
-        Ok(())
+        // Reload systemd daemon so the unit's load state becomes "masked"
+        self.reload_daemon(scope).await?;
+
+        Ok(JobResult::Done)
     }
 
-    /// Enable a service unit (creates symlinks)
-    pub async fn enable_service(&self, service_name: &str) -> Result<()> {
+    /// Unmask a unit (removes the /dev/null link)
+    pub async fn unmask_service(&self, service_name: &str, scope: ServiceScope) -> Result<JobResult> {
         self.validate_service_name(service_name)?;
-        Self::check_polkit_available()?;
+        Self::check_authorization(scope)?;
 
         let proxy = zbus::Proxy::new(
-            &self.connection,
+            self.connection_for(scope)?,
             "org.freedesktop.systemd1",
             "/org/freedesktop/systemd1",
             "org.freedesktop.systemd1.Manager",
@@ -208,38 +294,25 @@ impl ServiceController {
         .await
         .map_err(|e| SysproxError::SystemdConnection(e.to_string()))?;
 
-        // EnableUnitFiles returns (changes, carries_install_info)
-        let (_changes, _carries_install_info): (Vec<(String, String, String)>, bool) = proxy
-            .call("EnableUnitFiles", &(&[service_name][..], false, true))
+        // UnmaskUnitFiles(files, runtime) returns the symlink changes
+        let _changes: Vec<(String, String, String)> = proxy
+            .call("UnmaskUnitFiles", &(&[service_name][..], false))
             .await
-            .map_err(|e| {
-                let error_msg = e.to_string();
-                if error_msg.contains("Access denied") || error_msg.contains("Authentication") {
-                    SysproxError::ServiceControl {
-                        service: service_name.to_string(),
-                        message: "Access denied. Authentication required - ensure polkit agent is running or use sudo.".to_string(),
-                    }
-                } else {
-                    SysproxError::ServiceControl {
-                        service: service_name.to_string(),
-                        message: format!("Failed to enable: {}", e),
-                    }
-                }
-            })?;
+            .map_err(|e| control_error(service_name, "unmask", e))?;
 
-        // Reload systemd daemon to apply changes
-        self.reload_daemon().await?;
+        // Reload systemd daemon to pick up the real unit file again
+        self.reload_daemon(scope).await?;
 
-        Ok(())
+        Ok(JobResult::Done)
     }
 
-    /// Disable a service unit (removes symlinks)
-    pub async fn disable_service(&self, service_name: &str) -> Result<()> {
+    /// Clear a unit's failed state and restart counters
+    pub async fn reset_failed_service(&self, service_name: &str, scope: ServiceScope) -> Result<JobResult> {
         self.validate_service_name(service_name)?;
-        Self::check_polkit_available()?;
+        Self::check_authorization(scope)?;
 
         let proxy = zbus::Proxy::new(
-            &self.connection,
+            self.connection_for(scope)?,
             "org.freedesktop.systemd1",
             "/org/freedesktop/systemd1",
             "org.freedesktop.systemd1.Manager",
@@ -247,37 +320,185 @@ impl ServiceController {
         .await
         .map_err(|e| SysproxError::SystemdConnection(e.to_string()))?;
 
-        // DisableUnitFiles returns (changes, carries_install_info)
-        let (_changes, _carries_install_info): (Vec<(String, String, String)>, bool) = proxy
-            .call("DisableUnitFiles", &(&[service_name][..], false))
+        proxy
+            .call::<_, _, ()>("ResetFailedUnit", &(service_name,))
             .await
-            .map_err(|e| {
-                let error_msg = e.to_string();
-                if error_msg.contains("Access denied") || error_msg.contains("Authentication") {
-                    SysproxError::ServiceControl {
-                        service: service_name.to_string(),
-                        message: "Access denied. Authentication required - ensure polkit agent is running or use sudo.".to_string(),
-                    }
-                } else {
-                    SysproxError::ServiceControl {
-                        service: service_name.to_string(),
-                        message: format!("Failed to disable: {}", e),
-                    }
-                }
-            })?;
+            .map_err(|e| control_error(service_name, "reset failed state", e))?;
 
-        // Reload systemd daemon to apply changes
-        self.reload_daemon().await?;
+        Ok(JobResult::Done)
+    }
+
+    /// Freeze all processes of a unit with the cgroup freezer
+    pub async fn freeze_service(&self, service_name: &str, scope: ServiceScope) -> Result<JobResult> {
+        self.freezer_call(service_name, scope, "FreezeUnit", "freeze").await
+    }
+
+    /// Thaw a frozen unit so its processes run again
+    pub async fn thaw_service(&self, service_name: &str, scope: ServiceScope) -> Result<JobResult> {
+        self.freezer_call(service_name, scope, "ThawUnit", "thaw").await
+    }
+
+    /// Call FreezeUnit/ThawUnit; systemd replies once the freezer settles
+    async fn freezer_call(&self, service_name: &str, scope: ServiceScope, method: &str, verb: &str) -> Result<JobResult> {
+        self.validate_service_name(service_name)?;
+        Self::check_authorization(scope)?;
+
+        let proxy = zbus::Proxy::new(
+            self.connection_for(scope)?,
+            "org.freedesktop.systemd1",
+            "/org/freedesktop/systemd1",
+            "org.freedesktop.systemd1.Manager",
+        )
+        .await
+        .map_err(|e| SysproxError::SystemdConnection(e.to_string()))?;
+
+        proxy
+            .call::<_, _, ()>(method, &(service_name,))
+            .await
+            .map_err(|e| control_error(service_name, verb, e))?;
+
+        Ok(JobResult::Done)
+    }
+
+    /// Apply resource limits to a unit, like `systemctl set-property`
+    ///
+    /// With `runtime` the change is lost on reboot; otherwise systemd writes
+    /// a persistent drop-in.
+    pub async fn set_resource_limits(
+        &self,
+        service_name: &str,
+        scope: ServiceScope,
+        runtime: bool,
+        limits: &[(ResourceLimit, u64)],
+    ) -> Result<()> {
+        self.validate_service_name(service_name)?;
+        Self::check_authorization(scope)?;
+
+        let proxy = zbus::Proxy::new(
+            self.connection_for(scope)?,
+            "org.freedesktop.systemd1",
+            "/org/freedesktop/systemd1",
+            "org.freedesktop.systemd1.Manager",
+        )
+        .await
+        .map_err(|e| SysproxError::SystemdConnection(e.to_string()))?;
+
+        // SetUnitProperties(name, runtime, a(sv)); every limit is a u64
+        let properties: Vec<(&str, zbus::zvariant::Value)> = limits
+            .iter()
+            .map(|(limit, value)| (limit.property(), zbus::zvariant::Value::U64(*value)))
+            .collect();
+
+        proxy
+            .call::<_, _, ()>("SetUnitProperties", &(service_name, runtime, properties))
+            .await
+            .map_err(|e| control_error(service_name, "set resource limits", e))?;
+
+        Ok(())
+    }
+
+    /// Start a transient service or scope, like `systemd-run`
+    ///
+    /// Services are started by systemd; for scopes the command is spawned here
+    /// and its PID handed to systemd.
+    pub async fn start_transient_unit(&self, spec: &TransientSpec, scope: ServiceScope) -> Result<()> {
+        use zbus::zvariant::Value;
+
+        self.validate_service_name(&spec.name)?;
+        Self::check_authorization(scope)?;
+
+        let proxy = zbus::Proxy::new(
+            self.connection_for(scope)?,
+            "org.freedesktop.systemd1",
+            "/org/freedesktop/systemd1",
+            "org.freedesktop.systemd1.Manager",
+        )
+        .await
+        .map_err(|e| SysproxError::SystemdConnection(e.to_string()))?;
+
+        let mut properties: Vec<(&str, Value)> = vec![("Description", Value::from(spec.description.as_str()))];
+        if !spec.slice.is_empty() {
+            properties.push(("Slice", Value::from(spec.slice.as_str())));
+        }
+        if let Some(bytes) = spec.memory_max {
+            properties.push(("MemoryMax", Value::U64(bytes)));
+        }
+        if let Some(quota) = spec.cpu_quota {
+            properties.push(("CPUQuotaPerSecUSec", Value::U64(quota)));
+        }
+        if let Some(usec) = spec.runtime_max_usec {
+            properties.push(("RuntimeMaxUSec", Value::U64(usec)));
+        }
+
+        let mut child = None;
+        match spec.kind {
+            TransientKind::Service => {
+                // ExecStart is a(sasb): path, argv, ignore failure
+                let exec = vec![(spec.argv[0].clone(), spec.argv.clone(), false)];
+                properties.push(("ExecStart", Value::from(exec)));
+            }
+            TransientKind::Scope => {
+                let spawned = tokio::process::Command::new(&spec.argv[0])
+                    .args(&spec.argv[1..])
+                    .stdin(std::process::Stdio::null())
+                    .stdout(std::process::Stdio::null())
+                    .stderr(std::process::Stdio::null())
+                    .spawn()
+                    .map_err(|e| SysproxError::ServiceControl {
+                        service: spec.name.clone(),
+                        message: format!("Failed to run {}: {}", spec.argv[0], e),
+                    })?;
+                let pid = spawned.id().unwrap_or_default();
+                properties.push(("PIDs", Value::from(vec![pid])));
+                child = Some(spawned);
+            }
+        }
+
+        // StartTransientUnit(name, mode, properties, aux units)
+        let aux: Vec<(&str, Vec<(&str, Value)>)> = Vec::new();
+        let result = proxy
+            .call::<_, _, zbus::zvariant::OwnedObjectPath>("StartTransientUnit", &(spec.name.as_str(), "fail", properties, aux))
+            .await;
+
+        if let Err(e) = result {
+            // Do not leave an unmanaged process behind
+            if let Some(mut child) = child {
+                child.start_kill().ok();
+            }
+            return Err(control_error(&spec.name, "start transient unit", e).into());
+        }
+
+        Ok(())
+    }
+
+    /// Send a signal to a unit's main, control or all processes
+    pub async fn kill_service(&self, service_name: &str, scope: ServiceScope, target: KillTarget, signal: KillSignal) -> Result<()> {
+        self.validate_service_name(service_name)?;
+        Self::check_authorization(scope)?;
+
+        let proxy = zbus::Proxy::new(
+            self.connection_for(scope)?,
+            "org.freedesktop.systemd1",
+            "/org/freedesktop/systemd1",
+            "org.freedesktop.systemd1.Manager",
+        )
+        .await
+        .map_err(|e| SysproxError::SystemdConnection(e.to_string()))?;
+
+        proxy
+            .call::<_, _, ()>("KillUnit", &(service_name, target.as_str(), signal.number()))
+            .await
+            .map_err(|e| control_error(service_name, &format!("send {}", signal.name()), e))?;
 
         Ok(())
     }
 
     /// Check if a service unit is enabled
-    pub async fn is_service_enabled(&self, service_name: &str) -> Result<bool> {
+    pub async fn is_service_enabled(&self, service_name: &str, scope: ServiceScope) -> Result<bool> {
         self.validate_service_name(service_name)?;
         
         let proxy = zbus::Proxy::new(
-            &self.connection,
+            self.connection_for(scope)?,
             "org.freedesktop.systemd1",
             "/org/freedesktop/systemd1",
             "org.freedesktop.systemd1.Manager",
@@ -291,15 +512,15 @@ impl ServiceController {
             .await
             .map_err(|e| SysproxError::ServiceInfo(format!("Failed to get enabled state: {}", e)))?;
 
-        Ok(state == "enabled" || state == "enabled-runtime")
+        Ok(crate::systemd::models::is_enabled_state(&state))
     }
 
     /// Get the list of dependencies for a service
-    pub async fn get_dependencies(&self, service_name: &str, dependency_type: &str) -> Result<Vec<String>> {
+    pub async fn get_dependencies(&self, service_name: &str, scope: ServiceScope, dependency_type: &str) -> Result<Vec<String>> {
         self.validate_service_name(service_name)?;
         
         let proxy = zbus::Proxy::new(
-            &self.connection,
+            self.connection_for(scope)?,
             "org.freedesktop.systemd1",
             "/org/freedesktop/systemd1",
             "org.freedesktop.systemd1.Manager",
@@ -316,9 +537,9 @@ impl ServiceController {
     }
 
     /// Reload the systemd daemon configuration
-    async fn reload_daemon(&self) -> Result<()> {
+    pub async fn reload_daemon(&self, scope: ServiceScope) -> Result<()> {
         let proxy = zbus::Proxy::new(
-            &self.connection,
+            self.connection_for(scope)?,
             "org.freedesktop.systemd1",
             "/org/freedesktop/systemd1",
             "org.freedesktop.systemd1.Manager",
@@ -348,9 +569,9 @@ impl ServiceController {
             return Err(anyhow::anyhow!("Invalid service name format"));
         }
 
-        // Ensure it ends with .service for safety
-        if !service_name.ends_with(".service") {
-            return Err(anyhow::anyhow!("Service name must end with .service"));
+        // Ensure it ends with a known unit type suffix for safety
+        if UnitKind::from_unit_name(service_name).is_none() {
+            return Err(anyhow::anyhow!("Unit name must end with a unit type suffix (.service, .mount, ...)"));
         }
 
         Ok(())
@@ -362,6 +583,22 @@ impl Clone for ServiceController {
     fn clone(&self) -> Self {
         Self {
             connection: self.connection.clone(),
+            user_connection: self.user_connection.clone(),
         }
     }
 }
+
+/// Turn a failed manager call into a ServiceControl error, pointing polkit
+/// denials at the likely cause
+fn control_error(service_name: &str, action: &str, e: zbus::Error) -> SysproxError {
+    let error_msg = e.to_string();
+    let message = if error_msg.contains("Access denied") || error_msg.contains("Authentication") {
+        "Access denied. Authentication required - ensure polkit agent is running or use sudo.".to_string()
+    } else {
+        format!("Failed to {}: {}", action, e)
+    };
+    SysproxError::ServiceControl {
+        service: service_name.to_string(),
+        message,
+    }
+}
@@ -0,0 +1,220 @@
+// Drop-in override files - locate, validate and install override.conf
+
+use crate::error::{Result, SysproxError};
+use crate::systemd::ServiceScope;
+use std::io::Write;
+use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+use std::path::{Path, PathBuf};
+
+/// An override edit in progress: the editor works on `temp_path`, which is
+/// installed to `path` once the editor exits and the content validates
+#[derive(Debug)]
+pub struct DropInEdit {
+    pub unit: String,
+    pub scope: ServiceScope,
+    /// Where the override lives, e.g. /etc/systemd/system/foo.service.d/override.conf
+    pub path: PathBuf,
+    pub temp_path: PathBuf,
+    /// Private directory holding `temp_path`; removed when the edit is dropped
+    _temp_dir: tempfile::TempDir,
+    /// Content of the override before editing (empty if it did not exist)
+    pub original: String,
+    /// Content handed to the editor (the original, or a template)
+    pub initial: String,
+}
+
+impl DropInEdit {
+    /// Prepare an edit: read the current override and write the temp copy
+    pub fn prepare(unit: &str, scope: ServiceScope) -> Result<Self> {
+        let path = override_path(unit, scope)?;
+        let original = std::fs::read_to_string(&path).unwrap_or_default();
+        let initial = if original.is_empty() {
+            override_template(unit)
+        } else {
+            original.clone()
+        };
+
+        let (temp_dir, temp_path) = private_temp_file(&format!("{}-override.conf", unit), &initial)?;
+
+        Ok(Self {
+            unit: unit.to_string(),
+            scope,
+            path,
+            temp_path,
+            _temp_dir: temp_dir,
+            original,
+            initial,
+        })
+    }
+
+    /// Read back what the editor saved
+    pub fn edited_content(&self) -> Result<String> {
+        std::fs::read_to_string(&self.temp_path)
+            .map_err(|e| anyhow::anyhow!("Failed to read {}: {}", self.temp_path.display(), e))
+    }
+
+    /// Remove the temp copy
+    pub fn cleanup(&self) {
+        std::fs::remove_file(&self.temp_path).ok();
+    }
+}
+
+/// Write `content` to a new file in a fresh 0700 directory of our own
+///
+/// These files are installed as root through pkexec. A predictable name in
+/// the shared temp dir could be pre-created or swapped by another local
+/// user, so the file is created with `create_new` and mode 0600 inside a
+/// directory nobody else can enter. Keep the returned directory alive until
+/// the file has been installed; dropping it deletes both.
+pub fn private_temp_file(name: &str, content: &str) -> Result<(tempfile::TempDir, PathBuf)> {
+    let dir = tempfile::Builder::new()
+        .prefix("sysprox-")
+        .permissions(std::fs::Permissions::from_mode(0o700))
+        .tempdir()
+        .map_err(|e| anyhow::anyhow!("Failed to create temporary directory: {}", e))?;
+    let path = dir.path().join(name);
+
+    let mut file = std::fs::OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .mode(0o600)
+        .open(&path)
+        .map_err(|e| anyhow::anyhow!("Failed to create {}: {}", path.display(), e))?;
+    file.write_all(content.as_bytes())
+        .map_err(|e| anyhow::anyhow!("Failed to write {}: {}", path.display(), e))?;
+
+    Ok((dir, path))
+}
+
+/// Directory holding a unit's drop-ins for a scope
+pub fn dropin_dir(unit: &str, scope: ServiceScope) -> Result<PathBuf> {
+    Ok(unit_dir(scope)?.join(format!("{}.d", unit)))
+}
+
+/// Path of a unit's override.conf, as `systemctl edit` uses it
+pub fn override_path(unit: &str, scope: ServiceScope) -> Result<PathBuf> {
+    Ok(dropin_dir(unit, scope)?.join("override.conf"))
+}
+
+/// Administrator unit directory for a scope
+pub fn unit_dir(scope: ServiceScope) -> Result<PathBuf> {
+    match scope {
+        ServiceScope::System => Ok(PathBuf::from("/etc/systemd/system")),
+        ServiceScope::User => dirs::config_dir()
+            .map(|dir| dir.join("systemd/user"))
+            .ok_or_else(|| anyhow::anyhow!("Could not determine user config directory")),
+    }
+}
+
+/// Timer next to a service in the administrator directory, e.g. "backup.timer"
+/// for "backup.service", as the new-service form writes them
+pub fn companion_timer(unit: &str, scope: ServiceScope) -> Option<String> {
+    companion_timer_in(&unit_dir(scope).ok()?, unit)
+}
+
+/// `companion_timer` against an explicit unit directory
+pub fn companion_timer_in(dir: &Path, unit: &str) -> Option<String> {
+    let timer = format!("{}.timer", unit.strip_suffix(".service")?);
+    dir.join(&timer).is_file().then_some(timer)
+}
+
+/// Delete a unit file and its drop-in directory from the administrator directory
+pub async fn remove_unit_files(unit: &str, scope: ServiceScope) -> Result<()> {
+    let fragment = unit_dir(scope)?.join(unit);
+    match std::fs::remove_file(&fragment) {
+        Ok(()) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+        Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied && scope == ServiceScope::System => {
+            run_pkexec(&["rm", "-f", &fragment.to_string_lossy()]).await?;
+        }
+        Err(e) => return Err(anyhow::anyhow!("Failed to remove {}: {}", fragment.display(), e)),
+    }
+
+    let dir = dropin_dir(unit, scope)?;
+    match std::fs::remove_dir_all(&dir) {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied && scope == ServiceScope::System => {
+            run_pkexec(&["rm", "-rf", &dir.to_string_lossy()]).await
+        }
+        Err(e) => Err(anyhow::anyhow!("Failed to remove {}: {}", dir.display(), e)),
+    }
+}
+
+/// Starting content for a unit without an override yet
+fn override_template(unit: &str) -> String {
+    format!(
+        "# Drop-in override for {}\n\
+         # Settings here extend or replace those in the unit file. For example:\n\
+         #\n\
+         # [Service]\n\
+         # Environment=DEBUG=1\n\
+         # LimitNOFILE=65536\n",
+        unit
+    )
+}
+
+/// Returns true if the content has no settings, only comments and blank lines
+pub fn is_effectively_empty(content: &str) -> bool {
+    content
+        .lines()
+        .map(str::trim)
+        .all(|line| line.is_empty() || line.starts_with('#') || line.starts_with(';'))
+}
+
+/// Write `source` to `target`, creating parent directories
+///
+/// System unit directories are root-owned; when a direct write is refused,
+/// the copy is retried through pkexec so polkit can authorize it.
+pub async fn install_file(source: &Path, target: &Path, scope: ServiceScope) -> Result<()> {
+    let direct = target
+        .parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .and_then(|_| std::fs::copy(source, target).map(|_| ()))
+        // copy keeps the 0600 mode of the private source; match install -m 0644
+        .and_then(|_| std::fs::set_permissions(target, std::fs::Permissions::from_mode(0o644)));
+
+    match direct {
+        Ok(()) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied && scope == ServiceScope::System => {
+            run_pkexec(&["install", "-D", "-m", "0644", &source.to_string_lossy(), &target.to_string_lossy()]).await
+        }
+        Err(e) => Err(anyhow::anyhow!("Failed to write {}: {}", target.display(), e)),
+    }
+}
+
+/// Remove a file, falling back to pkexec for root-owned system paths
+pub async fn remove_file(target: &Path, scope: ServiceScope) -> Result<()> {
+    match std::fs::remove_file(target) {
+        Ok(()) => {}
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied && scope == ServiceScope::System => {
+            run_pkexec(&["rm", "-f", &target.to_string_lossy()]).await?;
+        }
+        Err(e) => return Err(anyhow::anyhow!("Failed to remove {}: {}", target.display(), e)),
+    }
+
+    // Drop the .d directory too once it is empty, like systemctl edit
+    if let Some(dir) = target.parent() {
+        std::fs::remove_dir(dir).ok();
+    }
+    Ok(())
+}
+
+/// Run a command as root through polkit
+async fn run_pkexec(args: &[&str]) -> Result<()> {
+    let status = tokio::process::Command::new("pkexec")
+        .args(args)
+        .status()
+        .await
+        .map_err(|e| anyhow::anyhow!("Failed to run pkexec: {}", e))?;
+
+    if !status.success() {
+        return Err(SysproxError::ServiceControl {
+            service: args.last().copied().unwrap_or_default().to_string(),
+            message: format!("pkexec {} failed ({})", args.first().copied().unwrap_or_default(), status),
+        }
+        .into());
+    }
+    Ok(())
+}
@@ -2,6 +2,7 @@
 
 use crate::error::{Result, SysproxError};
 use crate::events::AppEvent;
+use crate::systemd::ServiceScope;
 use std::process::Stdio;
 use tokio::io::{AsyncBufReadExt, BufReader};
 use tokio::process::{Child, Command};
@@ -26,14 +27,20 @@ impl JournalReader {
     /// Start streaming logs for a service
     pub async fn stream_logs(
         service_name: String,
+        scope: ServiceScope,
         tx: mpsc::Sender<AppEvent>,
         follow: bool,
         min_priority: Option<u8>,
         since: Option<String>,
         until: Option<String>,
     ) -> Result<Self> {
+        // User units' messages only match --user-unit
+        let unit_flag = match scope {
+            ServiceScope::System => "-u",
+            ServiceScope::User => "--user-unit",
+        };
         let mut args: Vec<String> = vec![
-            "-u".to_string(),
+            unit_flag.to_string(),
             service_name.clone(),
             "--output=short-precise".to_string(),
             "--no-pager".to_string(),
@@ -0,0 +1,175 @@
+// Runtime resource limits - parsing and formatting for SetUnitProperties
+
+use crate::error::Result;
+use crate::systemd::ServiceDetail;
+
+/// Value systemd uses for "no limit" / "use the default" in u64 properties
+pub const UNSET: u64 = u64::MAX;
+
+/// ResourceLimit is a cgroup resource control editable at runtime
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResourceLimit {
+    MemoryMax,
+    MemoryHigh,
+    CPUQuota,
+    CPUWeight,
+    TasksMax,
+    IOWeight,
+}
+
+impl ResourceLimit {
+    pub const ALL: [ResourceLimit; 6] = [
+        ResourceLimit::MemoryMax,
+        ResourceLimit::MemoryHigh,
+        ResourceLimit::CPUQuota,
+        ResourceLimit::CPUWeight,
+        ResourceLimit::TasksMax,
+        ResourceLimit::IOWeight,
+    ];
+
+    /// Name as written in unit files and `systemctl set-property`
+    pub fn label(&self) -> &'static str {
+        match self {
+            ResourceLimit::MemoryMax => "MemoryMax",
+            ResourceLimit::MemoryHigh => "MemoryHigh",
+            ResourceLimit::CPUQuota => "CPUQuota",
+            ResourceLimit::CPUWeight => "CPUWeight",
+            ResourceLimit::TasksMax => "TasksMax",
+            ResourceLimit::IOWeight => "IOWeight",
+        }
+    }
+
+    /// D-Bus property passed to SetUnitProperties (CPUQuota is set per second)
+    pub fn property(&self) -> &'static str {
+        match self {
+            ResourceLimit::CPUQuota => "CPUQuotaPerSecUSec",
+            other => other.label(),
+        }
+    }
+
+    /// Accepted input, shown next to the field
+    pub fn hint(&self) -> &'static str {
+        match self {
+            ResourceLimit::MemoryMax | ResourceLimit::MemoryHigh => "bytes, K/M/G/T suffix, or infinity",
+            ResourceLimit::CPUQuota => "percent of one CPU, e.g. 150%, or infinity",
+            ResourceLimit::CPUWeight | ResourceLimit::IOWeight => "1-10000, or default",
+            ResourceLimit::TasksMax => "number of tasks, or infinity",
+        }
+    }
+
+    /// Current value from a loaded service detail
+    pub fn current(&self, detail: &ServiceDetail) -> u64 {
+        match self {
+            ResourceLimit::MemoryMax => detail.memory_limit,
+            ResourceLimit::MemoryHigh => detail.memory_high,
+            ResourceLimit::CPUQuota => detail.cpu_quota_per_sec_usec,
+            ResourceLimit::CPUWeight => detail.cpu_weight,
+            ResourceLimit::TasksMax => detail.tasks_max,
+            ResourceLimit::IOWeight => detail.io_weight,
+        }
+    }
+
+    /// Parse user input into the property's D-Bus value
+    pub fn parse(&self, input: &str) -> Result<u64> {
+        let input = input.trim();
+        match self {
+            ResourceLimit::MemoryMax | ResourceLimit::MemoryHigh | ResourceLimit::TasksMax
+                if input.eq_ignore_ascii_case("infinity") =>
+            {
+                Ok(UNSET)
+            }
+            ResourceLimit::MemoryMax | ResourceLimit::MemoryHigh => parse_bytes(input),
+            ResourceLimit::TasksMax => input
+                .parse::<u64>()
+                .map_err(|_| anyhow::anyhow!("TasksMax must be a number or infinity")),
+            ResourceLimit::CPUQuota => {
+                if input.eq_ignore_ascii_case("infinity") {
+                    return Ok(UNSET);
+                }
+                // 100% is one full CPU: 1s of CPU time per second
+                input
+                    .strip_suffix('%')
+                    .and_then(|p| parse_quota_usec(p.trim()))
+                    .filter(|usec| *usec > 0)
+                    .ok_or_else(|| anyhow::anyhow!("CPUQuota must be a percentage like 50%, 12.5% or 200%"))
+            }
+            ResourceLimit::CPUWeight | ResourceLimit::IOWeight => {
+                if input.eq_ignore_ascii_case("default") {
+                    return Ok(UNSET);
+                }
+                input
+                    .parse::<u64>()
+                    .ok()
+                    .filter(|w| (1..=10_000).contains(w))
+                    .ok_or_else(|| anyhow::anyhow!("{} must be between 1 and 10000", self.label()))
+            }
+        }
+    }
+
+    /// Render a D-Bus value the way `parse` accepts it
+    pub fn format(&self, value: u64) -> String {
+        match self {
+            ResourceLimit::MemoryMax | ResourceLimit::MemoryHigh | ResourceLimit::TasksMax | ResourceLimit::CPUQuota
+                if value == UNSET =>
+            {
+                "infinity".to_string()
+            }
+            ResourceLimit::CPUWeight | ResourceLimit::IOWeight if value == UNSET => "default".to_string(),
+            ResourceLimit::MemoryMax | ResourceLimit::MemoryHigh => format_bytes(value),
+            ResourceLimit::CPUQuota => format_quota(value),
+            ResourceLimit::CPUWeight | ResourceLimit::IOWeight | ResourceLimit::TasksMax => value.to_string(),
+        }
+    }
+}
+
+/// Parse "512M", "2G", "1048576" (1024-based suffixes, like systemd)
+fn parse_bytes(input: &str) -> Result<u64> {
+    let (number, multiplier) = match input.chars().last().map(|c| c.to_ascii_uppercase()) {
+        Some('K') => (&input[..input.len() - 1], 1u64 << 10),
+        Some('M') => (&input[..input.len() - 1], 1u64 << 20),
+        Some('G') => (&input[..input.len() - 1], 1u64 << 30),
+        Some('T') => (&input[..input.len() - 1], 1u64 << 40),
+        _ => (input, 1),
+    };
+
+    number
+        .trim()
+        .parse::<u64>()
+        .ok()
+        .and_then(|n| n.checked_mul(multiplier))
+        .ok_or_else(|| anyhow::anyhow!("Invalid size '{}', use bytes or a K/M/G/T suffix", input))
+}
+
+/// Parse a percentage like "25" or "12.5" into µs of CPU time per second
+///
+/// One percent is 10000µs, so up to four decimals are exact.
+fn parse_quota_usec(percent: &str) -> Option<u64> {
+    let (whole, fraction) = percent.split_once('.').unwrap_or((percent, ""));
+    if fraction.len() > 4 || !fraction.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    let fraction = format!("{:0<4}", fraction).parse::<u64>().ok()?;
+    whole.parse::<u64>().ok()?.checked_mul(10_000)?.checked_add(fraction)
+}
+
+/// Format µs per second as a percentage, keeping only the decimals needed
+fn format_quota(usec: u64) -> String {
+    let fraction = usec % 10_000;
+    if fraction == 0 {
+        format!("{}%", usec / 10_000)
+    } else {
+        let decimals = format!("{:04}", fraction);
+        format!("{}.{}%", usec / 10_000, decimals.trim_end_matches('0'))
+    }
+}
+
+/// Format bytes with the largest suffix that divides them exactly
+fn format_bytes(bytes: u64) -> String {
+    for (suffix, shift) in [("T", 40), ("G", 30), ("M", 20), ("K", 10)] {
+        let unit = 1u64 << shift;
+        if bytes >= unit && bytes % unit == 0 {
+            return format!("{}{}", bytes / unit, suffix);
+        }
+    }
+    bytes.to_string()
+}
@@ -0,0 +1,487 @@
+// Unit file linter - offline checks run before sysprox writes a unit or drop-in
+
+use crate::systemd::unit_file::{parse_line, UnitLine};
+use crate::systemd::UnitKind;
+use std::fmt;
+use std::path::Path;
+
+const UNIT_KEYS: &[&str] = &[
+    "Description", "Documentation", "Wants", "Requires", "Requisite", "BindsTo", "PartOf", "Upholds",
+    "Conflicts", "Before", "After", "OnFailure", "OnSuccess", "PropagatesReloadTo", "ReloadPropagatedFrom",
+    "PropagatesStopTo", "StopPropagatedFrom", "JoinsNamespaceOf", "RequiresMountsFor", "OnFailureJobMode",
+    "IgnoreOnIsolate", "StopWhenUnneeded", "RefuseManualStart", "RefuseManualStop", "AllowIsolate",
+    "DefaultDependencies", "CollectMode", "FailureAction", "SuccessAction", "FailureActionExitStatus",
+    "SuccessActionExitStatus", "JobTimeoutSec", "JobRunningTimeoutSec", "JobTimeoutAction",
+    "JobTimeoutRebootArgument", "StartLimitIntervalSec", "StartLimitBurst", "StartLimitAction",
+    "RebootArgument", "SourcePath",
+];
+
+const INSTALL_KEYS: &[&str] = &["Alias", "WantedBy", "RequiredBy", "UpheldBy", "Also", "DefaultInstance"];
+
+/// Settings shared by every unit type that runs processes (systemd.exec)
+const EXEC_KEYS: &[&str] = &[
+    "WorkingDirectory", "RootDirectory", "RootImage", "User", "Group", "DynamicUser", "SupplementaryGroups",
+    "PAMName", "UMask", "Nice", "OOMScoreAdjust", "IOSchedulingClass", "IOSchedulingPriority",
+    "CPUSchedulingPolicy", "CPUSchedulingPriority", "CPUSchedulingResetOnFork", "CPUAffinity", "NUMAPolicy",
+    "NUMAMask", "Environment", "EnvironmentFile", "PassEnvironment", "UnsetEnvironment", "StandardInput",
+    "StandardOutput", "StandardError", "StandardInputText", "StandardInputData", "SyslogIdentifier",
+    "SyslogFacility", "SyslogLevel", "SyslogLevelPrefix", "LogLevelMax", "LogExtraFields",
+    "LogRateLimitIntervalSec", "LogRateLimitBurst", "LogNamespace", "TTYPath", "TTYReset", "TTYVHangup",
+    "TTYVTDisallocate", "LimitCPU", "LimitFSIZE", "LimitDATA", "LimitSTACK", "LimitCORE", "LimitRSS",
+    "LimitNOFILE", "LimitAS", "LimitNPROC", "LimitMEMLOCK", "LimitLOCKS", "LimitSIGPENDING", "LimitMSGQUEUE",
+    "LimitNICE", "LimitRTPRIO", "LimitRTTIME", "CapabilityBoundingSet", "AmbientCapabilities",
+    "NoNewPrivileges", "SecureBits", "ProtectSystem", "ProtectHome", "ProtectKernelTunables",
+    "ProtectKernelModules", "ProtectKernelLogs", "ProtectControlGroups", "ProtectClock", "ProtectHostname",
+    "ProtectProc", "ProcSubset", "PrivateTmp", "PrivateDevices", "PrivateNetwork", "PrivateUsers",
+    "PrivateIPC", "PrivateMounts", "ReadWritePaths", "ReadOnlyPaths", "InaccessiblePaths", "ExecPaths",
+    "NoExecPaths", "BindPaths", "BindReadOnlyPaths", "TemporaryFileSystem", "MountFlags", "RuntimeDirectory",
+    "RuntimeDirectoryMode", "RuntimeDirectoryPreserve", "StateDirectory", "StateDirectoryMode",
+    "CacheDirectory", "CacheDirectoryMode", "LogsDirectory", "LogsDirectoryMode", "ConfigurationDirectory",
+    "ConfigurationDirectoryMode", "RestrictAddressFamilies", "RestrictNamespaces", "RestrictRealtime",
+    "RestrictSUIDSGID", "RestrictFileSystems", "LockPersonality", "MemoryDenyWriteExecute", "SystemCallFilter",
+    "SystemCallArchitectures", "SystemCallErrorNumber", "SystemCallLog", "RemoveIPC", "KeyringMode",
+    "LoadCredential", "LoadCredentialEncrypted", "SetCredential", "SetCredentialEncrypted", "Personality",
+    "IgnoreSIGPIPE", "TimerSlackNSec", "UtmpIdentifier", "UtmpMode", "SELinuxContext", "AppArmorProfile",
+    "SmackProcessLabel",
+];
+
+/// Cgroup resource controls (systemd.resource-control)
+const RESOURCE_KEYS: &[&str] = &[
+    "CPUAccounting", "CPUWeight", "StartupCPUWeight", "CPUQuota", "CPUQuotaPeriodSec", "AllowedCPUs",
+    "StartupAllowedCPUs", "AllowedMemoryNodes", "MemoryAccounting", "MemoryMin", "MemoryLow", "MemoryHigh",
+    "MemoryMax", "MemorySwapMax", "MemoryZSwapMax", "MemoryLimit", "TasksAccounting", "TasksMax",
+    "IOAccounting", "IOWeight", "StartupIOWeight", "IODeviceWeight", "IOReadBandwidthMax",
+    "IOWriteBandwidthMax", "IOReadIOPSMax", "IOWriteIOPSMax", "IODeviceLatencyTargetSec", "IPAccounting",
+    "IPAddressAllow", "IPAddressDeny", "IPIngressFilterPath", "IPEgressFilterPath", "DeviceAllow",
+    "DevicePolicy", "Slice", "Delegate", "DisableControllers", "ManagedOOMSwap", "ManagedOOMMemoryPressure",
+    "ManagedOOMMemoryPressureLimit", "ManagedOOMPreference", "CPUShares", "StartupCPUShares",
+    "BlockIOAccounting", "BlockIOWeight",
+];
+
+/// Process killing settings (systemd.kill)
+const KILL_KEYS: &[&str] = &[
+    "KillMode", "KillSignal", "RestartKillSignal", "SendSIGHUP", "SendSIGKILL", "FinalKillSignal",
+    "WatchdogSignal",
+];
+
+const SERVICE_KEYS: &[&str] = &[
+    "Type", "ExitType", "RemainAfterExit", "GuessMainPID", "PIDFile", "BusName", "ExecStart", "ExecStartPre",
+    "ExecStartPost", "ExecCondition", "ExecReload", "ExecStop", "ExecStopPost", "RestartSec", "RestartSteps",
+    "RestartMaxDelaySec", "TimeoutStartSec", "TimeoutStopSec", "TimeoutAbortSec", "TimeoutSec",
+    "TimeoutStartFailureMode", "TimeoutStopFailureMode", "RuntimeMaxSec", "RuntimeRandomizedExtraSec",
+    "WatchdogSec", "Restart", "RestartMode", "SuccessExitStatus", "RestartPreventExitStatus",
+    "RestartForceExitStatus", "RootDirectoryStartOnly", "NonBlocking", "NotifyAccess", "Sockets",
+    "FileDescriptorStoreMax", "FileDescriptorStorePreserve", "USBFunctionDescriptors", "USBFunctionStrings",
+    "OOMPolicy", "OpenFile", "ReloadSignal", "PermissionsStartOnly",
+];
+
+const TIMER_KEYS: &[&str] = &[
+    "OnActiveSec", "OnBootSec", "OnStartupSec", "OnUnitActiveSec", "OnUnitInactiveSec", "OnCalendar",
+    "AccuracySec", "RandomizedDelaySec", "FixedRandomDelay", "OnClockChange", "OnTimezoneChange", "Unit",
+    "Persistent", "WakeSystem", "RemainAfterElapse",
+];
+
+/// Valid values of Restart=
+pub const RESTART_VALUES: &[&str] = &[
+    "no", "on-success", "on-failure", "on-abnormal", "on-watchdog", "on-abort", "always",
+];
+
+/// Valid values of Type= in [Service]
+pub const SERVICE_TYPES: &[&str] = &["simple", "exec", "forking", "oneshot", "dbus", "notify", "notify-reload", "idle"];
+
+/// Settings whose value is a command line
+const EXEC_COMMANDS: &[&str] = &[
+    "ExecStart", "ExecStartPre", "ExecStartPost", "ExecCondition", "ExecReload", "ExecStop", "ExecStopPost",
+];
+
+/// Where systemd looks up commands given without a path
+const EXEC_SEARCH_PATH: &[&str] = &["/usr/local/sbin", "/usr/local/bin", "/usr/sbin", "/usr/bin"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    /// The unit would fail to load or start; sysprox refuses to write it
+    Error,
+    /// Suspicious but accepted by systemd
+    Warning,
+}
+
+/// One finding of the linter
+#[derive(Debug, Clone, PartialEq)]
+pub struct LintIssue {
+    pub severity: Severity,
+    /// 1-based line number, 0 for issues about the file as a whole
+    pub line: usize,
+    /// Setting the issue is about, if any
+    pub key: Option<String>,
+    pub message: String,
+}
+
+impl LintIssue {
+    fn error(line: usize, key: Option<&str>, message: String) -> Self {
+        Self {
+            severity: Severity::Error,
+            line,
+            key: key.map(str::to_string),
+            message,
+        }
+    }
+
+    fn warning(line: usize, key: Option<&str>, message: String) -> Self {
+        Self {
+            severity: Severity::Warning,
+            line,
+            key: key.map(str::to_string),
+            message,
+        }
+    }
+
+    pub fn is_error(&self) -> bool {
+        self.severity == Severity::Error
+    }
+}
+
+impl fmt::Display for LintIssue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.line > 0 {
+            write!(f, "Line {}: {}", self.line, self.message)
+        } else {
+            write!(f, "{}", self.message)
+        }
+    }
+}
+
+/// Returns true if any issue would stop the unit from working
+pub fn has_errors(issues: &[LintIssue]) -> bool {
+    issues.iter().any(LintIssue::is_error)
+}
+
+/// Lint a unit file or drop-in
+///
+/// `kind` selects the type-specific section and keys; with None any known
+/// section is accepted. Drop-ins may reset settings and need no ExecStart=.
+pub fn lint_unit(content: &str, kind: Option<UnitKind>, drop_in: bool) -> Vec<LintIssue> {
+    let mut issues = Vec::new();
+    let mut section: Option<String> = None;
+    let mut exec_starts = Vec::new();
+    let mut service_type = String::new();
+    let mut timer_triggers = 0;
+
+    let mut lines = content.lines().enumerate();
+    while let Some((i, raw)) = lines.next() {
+        let line_no = i + 1;
+
+        let (key, value) = match parse_line(raw) {
+            UnitLine::Blank | UnitLine::Comment(_) => continue,
+            UnitLine::Section(name) => {
+                if !section_allowed(name, kind) {
+                    issues.push(LintIssue::error(line_no, None, format!("unknown section [{}]", name)));
+                }
+                section = Some(name.to_string());
+                continue;
+            }
+            UnitLine::Invalid(text) if text.starts_with('[') => {
+                issues.push(LintIssue::error(line_no, None, "unterminated section header".to_string()));
+                continue;
+            }
+            UnitLine::Invalid(text) => {
+                issues.push(LintIssue::error(line_no, None, format!("expected Key=Value, got '{}'", text)));
+                continue;
+            }
+            UnitLine::Setting { key, value } => (key, value),
+        };
+
+        // Join backslash continuations so values are checked whole
+        let mut value = value.to_string();
+        while value.ends_with('\\') {
+            value.pop();
+            match lines.next() {
+                Some((_, next)) => {
+                    value.push(' ');
+                    value.push_str(next.trim());
+                }
+                None => break,
+            }
+        }
+        let value = value.trim();
+
+        let Some(section) = section.as_deref() else {
+            issues.push(LintIssue::error(line_no, Some(key), format!("'{}' is outside any [Section]", key)));
+            continue;
+        };
+        if key.is_empty() || !key.chars().all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-') {
+            issues.push(LintIssue::error(line_no, Some(key), format!("invalid setting name '{}'", key)));
+            continue;
+        }
+        if !key_known(section, key) {
+            issues.push(LintIssue::warning(line_no, Some(key), format!("unknown setting {}= in [{}]", key, section)));
+        }
+
+        // An empty value resets the setting, which is always valid
+        if value.is_empty() {
+            if key == "ExecStart" {
+                exec_starts.clear();
+            }
+            continue;
+        }
+
+        match key {
+            "Restart" if !RESTART_VALUES.contains(&value) => issues.push(LintIssue::error(
+                line_no,
+                Some(key),
+                format!("invalid Restart={}, expected one of {}", value, RESTART_VALUES.join(", ")),
+            )),
+            "Type" if section == "Service" => {
+                if !SERVICE_TYPES.contains(&value) {
+                    issues.push(LintIssue::error(
+                        line_no,
+                        Some(key),
+                        format!("invalid Type={}, expected one of {}", value, SERVICE_TYPES.join(", ")),
+                    ));
+                }
+                service_type = value.to_string();
+            }
+            "RestartSec" | "TimeoutSec" | "TimeoutStartSec" | "TimeoutStopSec" | "RuntimeMaxSec" | "WatchdogSec"
+                if !is_time_span(value) =>
+            {
+                issues.push(LintIssue::error(line_no, Some(key), format!("invalid time span {}={}, e.g. 5s or 1min 30s", key, value)));
+            }
+            "Environment" => {
+                if let Err(message) = parse_environment(value) {
+                    issues.push(LintIssue::error(line_no, Some(key), message));
+                }
+            }
+            "WorkingDirectory" => {
+                let dir = value.trim_start_matches('-');
+                if !dir.starts_with('/') && !dir.starts_with('~') && !dir.starts_with('%') {
+                    issues.push(LintIssue::error(line_no, Some(key), format!("WorkingDirectory must be absolute, got '{}'", value)));
+                }
+            }
+            _ if EXEC_COMMANDS.contains(&key) => {
+                if key == "ExecStart" {
+                    exec_starts.push(line_no);
+                }
+                issues.extend(check_command(line_no, key, value));
+            }
+            _ if section == "Timer" && key.starts_with("On") && key != "OnClockChange" && key != "OnTimezoneChange" => {
+                timer_triggers += 1;
+            }
+            _ => {}
+        }
+    }
+
+    // Whole-unit checks only make sense for complete unit files
+    if !drop_in {
+        match kind {
+            Some(UnitKind::Service) if service_type != "oneshot" => match exec_starts.len() {
+                0 => issues.push(LintIssue::error(0, Some("ExecStart"), "missing ExecStart=".to_string())),
+                1 => {}
+                _ => issues.push(LintIssue::error(
+                    exec_starts[1],
+                    Some("ExecStart"),
+                    "only Type=oneshot services may have more than one ExecStart=".to_string(),
+                )),
+            },
+            Some(UnitKind::Timer) if timer_triggers == 0 => issues.push(LintIssue::error(
+                0,
+                Some("OnCalendar"),
+                "timer has no OnCalendar= or On*Sec= trigger".to_string(),
+            )),
+            _ => {}
+        }
+    }
+
+    issues
+}
+
+fn section_allowed(name: &str, kind: Option<UnitKind>) -> bool {
+    if name == "Unit" || name == "Install" || name.starts_with("X-") {
+        return true;
+    }
+    match kind {
+        Some(kind) => kind.section() == Some(name),
+        None => UnitKind::ALL.iter().any(|k| k.section() == Some(name)),
+    }
+}
+
+/// Keys are checked in the sections sysprox writes; other sections pass
+fn key_known(section: &str, key: &str) -> bool {
+    if key.starts_with("X-") {
+        return true;
+    }
+    match section {
+        "Unit" => UNIT_KEYS.contains(&key) || key.starts_with("Condition") || key.starts_with("Assert"),
+        "Install" => INSTALL_KEYS.contains(&key),
+        "Service" => [SERVICE_KEYS, EXEC_KEYS, RESOURCE_KEYS, KILL_KEYS].iter().any(|keys| keys.contains(&key)),
+        "Timer" => TIMER_KEYS.contains(&key),
+        _ => true,
+    }
+}
+
+/// Check the program of an Exec*= command line
+fn check_command(line: usize, key: &str, value: &str) -> Vec<LintIssue> {
+    // Strip the special prefixes: @ - : + ! !!
+    let command = value.trim_start_matches(['@', '-', ':', '+', '!']);
+    let program = command
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .trim_matches(|c| c == '"' || c == '\'');
+
+    // Specifiers and variables are only known when systemd runs the unit
+    if program.is_empty() || program.contains('%') || program.contains('$') {
+        return Vec::new();
+    }
+
+    if program.starts_with('/') {
+        let path = Path::new(program);
+        if !path.is_file() {
+            return vec![LintIssue::warning(line, Some(key), format!("executable {} not found", program))];
+        }
+        if !is_executable(path) {
+            return vec![LintIssue::warning(line, Some(key), format!("{} is not executable", program))];
+        }
+        return Vec::new();
+    }
+
+    if program.contains('/') {
+        return vec![LintIssue::error(
+            line,
+            Some(key),
+            format!("relative path '{}' in {}=, use an absolute path", program, key),
+        )];
+    }
+
+    // Bare names are looked up in a fixed path, not the user's $PATH
+    let found = EXEC_SEARCH_PATH.iter().any(|dir| Path::new(dir).join(program).is_file());
+    if found {
+        Vec::new()
+    } else {
+        vec![LintIssue::warning(
+            line,
+            Some(key),
+            format!("'{}' not found in {}, use an absolute path", program, EXEC_SEARCH_PATH.join(":")),
+        )]
+    }
+}
+
+/// Check a time span like "5", "500ms", "1min 30s" or "infinity"
+pub fn is_time_span(value: &str) -> bool {
+    parse_time_span(value).is_some()
+}
+
+/// Parse a systemd time span into microseconds; bare numbers are seconds
+pub fn parse_time_span(value: &str) -> Option<u64> {
+    const UNITS: &[(&[&str], f64)] = &[
+        (&["us", "usec"], 1.0),
+        (&["ms", "msec"], 1e3),
+        (&["", "s", "sec", "second", "seconds"], 1e6),
+        (&["m", "min", "minute", "minutes"], 60e6),
+        (&["h", "hr", "hour", "hours"], 3600e6),
+        (&["d", "day", "days"], 86400e6),
+        (&["w", "week", "weeks"], 604800e6),
+    ];
+    if value.trim() == "infinity" {
+        return Some(u64::MAX);
+    }
+
+    // Numbers and units may be separated by spaces: "1 min 30 s"
+    let compact: String = value.split_whitespace().collect::<Vec<_>>().join(" ");
+    let mut rest = compact.as_str();
+    let mut total = 0.0;
+    let mut parts = 0;
+    while !rest.is_empty() {
+        let digits = rest.find(|c: char| !c.is_ascii_digit() && c != '.').unwrap_or(rest.len());
+        let number: f64 = rest[..digits].parse().ok()?;
+        rest = rest[digits..].trim_start();
+        let unit_len = rest.find(|c: char| !c.is_ascii_alphabetic()).unwrap_or(rest.len());
+        let (_, scale) = UNITS.iter().find(|(names, _)| names.contains(&&rest[..unit_len]))?;
+        total += number * scale;
+        rest = rest[unit_len..].trim_start();
+        parts += 1;
+    }
+    (parts > 0).then_some(total as u64)
+}
+
+fn is_executable(path: &Path) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    std::fs::metadata(path).is_ok_and(|m| m.permissions().mode() & 0o111 != 0)
+}
+
+/// Split an Environment= value into VAR=value assignments, honoring quotes
+pub fn parse_environment(value: &str) -> Result<Vec<String>, String> {
+    let assignments = split_words(value).map_err(|e| format!("{} in Environment=", e))?;
+
+    for assignment in &assignments {
+        let name = assignment
+            .split_once('=')
+            .map(|(name, _)| name)
+            .ok_or_else(|| format!("'{}' in Environment= is not VAR=value", assignment))?;
+        let valid = !name.is_empty()
+            && !name.starts_with(|c: char| c.is_ascii_digit())
+            && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '_');
+        if !valid {
+            return Err(format!("invalid variable name '{}' in Environment=", name));
+        }
+    }
+
+    Ok(assignments)
+}
+
+/// Split a value into words, honoring single and double quotes like systemd
+pub fn split_words(value: &str) -> Result<Vec<String>, String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut quote: Option<char> = None;
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        match (c, quote) {
+            ('\\', Some(_)) => {
+                if let Some(next) = chars.next() {
+                    current.push(next);
+                }
+            }
+            (q, None) if q == '"' || q == '\'' => quote = Some(q),
+            (q, Some(open)) if q == open => quote = None,
+            (c, None) if c.is_whitespace() => {
+                if !current.is_empty() {
+                    words.push(std::mem::take(&mut current));
+                }
+            }
+            (c, _) => current.push(c),
+        }
+    }
+    if quote.is_some() {
+        return Err("unterminated quote".to_string());
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    Ok(words)
+}
+
+/// Check a full unit name ("foo.service", "getty@tty1.service") against systemd's rules
+pub fn validate_unit_name(name: &str) -> Result<(), String> {
+    if name.len() > 255 {
+        return Err("Unit name must be at most 255 characters".to_string());
+    }
+
+    let Some((prefix, suffix)) = name.rsplit_once('.') else {
+        return Err(format!("Unit name '{}' needs a type suffix like .service", name));
+    };
+    if UnitKind::ALL.iter().all(|kind| kind.label() != suffix) {
+        return Err(format!("'.{}' is not a unit type", suffix));
+    }
+    if prefix.is_empty() {
+        return Err("Unit name is empty".to_string());
+    }
+    if let Some(c) = prefix.chars().find(|c| !(c.is_ascii_alphanumeric() || ":-_.\\@".contains(*c))) {
+        return Err(format!("Unit name cannot contain '{}'", c));
+    }
+    match prefix.matches('@').count() {
+        0 => Ok(()),
+        1 if !prefix.starts_with('@') => Ok(()),
+        1 => Err("Template unit names need a prefix before '@'".to_string()),
+        _ => Err("Unit name can contain at most one '@'".to_string()),
+    }
+}
@@ -1,21 +1,57 @@
 // Metrics collection and calculation
 
 use crate::error::{Result, SysproxError};
-use crate::systemd::Metrics;
+use crate::events::AppEvent;
+use crate::systemd::cgroup::read_unit_cgroup;
+use crate::systemd::client::MAX_CONCURRENT_CALLS;
+use crate::systemd::models::timestamp_from_usec;
+use crate::systemd::{Metrics, ServiceScope, UnitKind};
+use futures::StreamExt;
+use std::collections::HashMap;
+use std::time::Duration;
+use tokio::sync::{mpsc, watch};
+use zbus::names::InterfaceName;
+use zbus::zvariant::Optional;
 use zbus::Connection;
 
 /// Service metrics collector with history tracking
 pub struct MetricsCollector {
     connection: Connection,
+    /// Session bus for user units; None outside a user session
+    user_connection: Option<Connection>,
     history: std::collections::HashMap<String, Vec<MetricsSnapshot>>,
     max_history: usize,
+    /// Online CPUs, for whole-machine percentages
+    cpus: usize,
 }
 
 #[derive(Debug, Clone)]
 pub struct MetricsSnapshot {
     pub timestamp: chrono::DateTime<chrono::Utc>,
     pub metrics: Metrics,
+    /// Percent of one core since the previous sample, like top (250% = 2.5 cores)
     pub cpu_percent: f64,
+    /// Percent of the whole machine since the previous sample
+    pub cpu_percent_machine: f64,
+}
+
+/// CPU usage over one sampling interval
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct CpuUsage {
+    pub per_core: f64,
+    pub machine: f64,
+}
+
+/// Latest resource usage of one unit, for the dashboard columns
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct UnitResources {
+    /// Percent of one core; None until a second sample arrives
+    pub cpu_percent: Option<f64>,
+    /// None when accounting is off
+    pub memory_current: Option<u64>,
+    pub tasks_current: Option<u64>,
+    /// ActiveEnterTimestamp, for uptime
+    pub active_since: Option<chrono::DateTime<chrono::Utc>>,
 }
 
 impl MetricsCollector {
@@ -27,44 +63,146 @@ impl MetricsCollector {
 
         Ok(Self {
             connection,
+            user_connection: Connection::session().await.ok(),
             history: std::collections::HashMap::new(),
             max_history: 300, // Keep 5 minutes of data (assuming 1s intervals)
+            cpus: online_cpus(),
         })
     }
 
-    /// Get current metrics for a service
+    /// Get current metrics for a system service
     pub async fn get_service_metrics(&mut self, service_name: &str) -> Result<MetricsSnapshot> {
-        let service_path = self.get_service_path(service_name).await?;
-        let props = self.get_service_properties(&service_path).await?;
-
-        let current_metrics = Metrics {
-            cpu_usage_nsec: props.get("CPUUsageNSec")
-                .and_then(|v| v.downcast_ref::<u64>().ok())
-                .unwrap_or(0),
-            memory_current: props.get("MemoryCurrent")
-                .and_then(|v| v.downcast_ref::<u64>().ok())
-                .unwrap_or(0),
-            tasks_current: props.get("TasksCurrent")
-                .and_then(|v| v.downcast_ref::<u64>().ok())
-                .unwrap_or(0),
-            n_restarts: props.get("NRestarts")
-                .and_then(|v| v.downcast_ref::<u32>().ok())
-                .unwrap_or(0),
-        };
+        self.get_unit_metrics(service_name, ServiceScope::System).await
+    }
+
+    /// Get current metrics for a unit of either manager
+    pub async fn get_unit_metrics(&mut self, service_name: &str, scope: ServiceScope) -> Result<MetricsSnapshot> {
+        let (snapshot, _) = self.sample_unit(service_name, service_name, scope).await?;
+        Ok(snapshot)
+    }
+
+    /// Sample many units at once, keyed by Service id ("system:nginx.service")
+    ///
+    /// Units that fail to answer are left out; history of units no longer
+    /// asked for is dropped so the map tracks the current list.
+    pub async fn get_units_resources(&mut self, units: &[(String, ServiceScope)]) -> HashMap<String, UnitResources> {
+        let this = &*self;
+        let samples: Vec<_> = futures::stream::iter(units.iter().cloned())
+            .map(|(name, scope)| async move {
+                let sample = match this.connection_for(scope) {
+                    Ok(connection) => Self::fetch_unit_resources(connection, &name).await,
+                    Err(e) => Err(e),
+                };
+                (name, scope, sample)
+            })
+            .buffer_unordered(MAX_CONCURRENT_CALLS)
+            .collect()
+            .await;
+
+        let mut resources = HashMap::new();
+        for (name, scope, sample) in samples {
+            let (metrics, active_since, timestamp) = match sample {
+                Ok(sample) => sample,
+                Err(e) => {
+                    tracing::debug!("Metrics sample for {} failed: {}", name, e);
+                    continue;
+                }
+            };
+            let id = format!("{}:{}", scope.label(), name);
+            // A first sample, or one after accounting was off, has no CPU delta yet
+            let previous_cpu = self.history.get(&id).and_then(|h| h.last()).map(|s| s.metrics.cpu_usage_nsec);
+            let cpu_measurable = previous_cpu.is_some_and(|nsec| nsec != u64::MAX) && metrics.cpu_usage_nsec != u64::MAX;
+            let cpu = self.calculate_cpu_percentage(&id, &metrics, timestamp);
+
+            let available = |value: u64| (value != u64::MAX).then_some(value);
+            resources.insert(
+                id.clone(),
+                UnitResources {
+                    cpu_percent: cpu_measurable.then_some(cpu.per_core),
+                    memory_current: available(metrics.memory_current),
+                    tasks_current: available(metrics.tasks_current),
+                    active_since,
+                },
+            );
+            self.update_history(
+                id,
+                MetricsSnapshot { timestamp, metrics, cpu_percent: cpu.per_core, cpu_percent_machine: cpu.machine },
+            );
+        }
+
+        self.history.retain(|id, _| resources.contains_key(id));
+        resources
+    }
 
+    /// Read only what the dashboard columns need: the type interface's
+    /// counters and the Unit interface's ActiveEnterTimestamp
+    async fn fetch_unit_resources(
+        connection: &Connection,
+        service_name: &str,
+    ) -> Result<(Metrics, Option<chrono::DateTime<chrono::Utc>>, chrono::DateTime<chrono::Utc>)> {
+        let interface = UnitKind::from_unit_name(service_name)
+            .and_then(|kind| kind.interface())
+            .ok_or_else(|| SysproxError::ServiceInfo(format!("{} has no resource counters", service_name)))?;
+        let service_path = Self::get_service_path(connection, service_name).await?;
+        let props_proxy = Self::properties_proxy(connection, &service_path).await?;
+
+        let props = props_proxy
+            .get_all(Optional::from(Some(InterfaceName::from_static_str_unchecked(interface))))
+            .await
+            .map_err(|e| SysproxError::ServiceInfo(format!("Failed to get properties: {}", e)))?;
         let timestamp = chrono::Utc::now();
-        let cpu_percent = self.calculate_cpu_percentage(service_name, &current_metrics);
+
+        let active_since = props_proxy
+            .get(
+                InterfaceName::from_static_str_unchecked("org.freedesktop.systemd1.Unit"),
+                "ActiveEnterTimestamp",
+            )
+            .await
+            .ok()
+            .and_then(|v| v.downcast_ref::<u64>().ok())
+            .and_then(timestamp_from_usec);
+
+        Ok((metrics_from_properties(&props), active_since, timestamp))
+    }
+
+    /// Bus that manages units of `scope`
+    fn connection_for(&self, scope: ServiceScope) -> Result<&Connection> {
+        match scope {
+            ServiceScope::System => Ok(&self.connection),
+            ServiceScope::User => self
+                .user_connection
+                .as_ref()
+                .ok_or_else(|| SysproxError::SystemdConnection("No user session bus".to_string()).into()),
+        }
+    }
+
+    /// Fetch a unit's counters and record them in history under `key`
+    async fn sample_unit(
+        &mut self,
+        key: &str,
+        service_name: &str,
+        scope: ServiceScope,
+    ) -> Result<(MetricsSnapshot, HashMap<String, zbus::zvariant::OwnedValue>)> {
+        let connection = self.connection_for(scope)?;
+        let service_path = Self::get_service_path(connection, service_name).await?;
+        let props = Self::get_service_properties(connection, &service_path, service_name).await?;
+
+        let current_metrics = metrics_from_properties(&props);
+
+        let timestamp = chrono::Utc::now();
+        let cpu = self.calculate_cpu_percentage(key, &current_metrics, timestamp);
 
         let snapshot = MetricsSnapshot {
             timestamp,
             metrics: current_metrics,
-            cpu_percent,
+            cpu_percent: cpu.per_core,
+            cpu_percent_machine: cpu.machine,
         };
 
         // Store in history
-        self.update_history(service_name.to_string(), snapshot.clone());
+        self.update_history(key.to_string(), snapshot.clone());
 
-        Ok(snapshot)
+        Ok((snapshot, props))
     }
 
     /// Get historical metrics for a service
@@ -119,10 +257,106 @@ impl MetricsCollector {
         self.history.remove(service_name);
     }
 
+    /// Poll a unit's metrics in the background, sending its history after each sample
+    ///
+    /// The target is whatever unit the detail view shows; None pauses polling.
+    /// Its cgroup's pressure and memory files are read alongside.
+    pub fn spawn_poller(
+        mut self,
+        interval: Duration,
+        mut target: watch::Receiver<Option<(String, ServiceScope)>>,
+        tx: mpsc::Sender<AppEvent>,
+    ) {
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            loop {
+                tokio::select! {
+                    _ = ticker.tick() => {}
+                    // Sample a newly opened unit right away, with fresh history
+                    changed = target.changed() => {
+                        if changed.is_err() {
+                            break;
+                        }
+                        self.history.clear();
+                        ticker.reset();
+                    }
+                }
+
+                let Some((service, scope)) = target.borrow().clone() else {
+                    continue;
+                };
+                // Keyed like Service::id, so a same-named unit of the other scope never mixes in
+                let key = format!("{}:{}", scope.label(), service);
+                match self.sample_unit(&key, &service, scope).await {
+                    Ok((_, props)) => {
+                        let control_group = props
+                            .get("ControlGroup")
+                            .and_then(|v| v.downcast_ref::<String>().ok())
+                            .unwrap_or_default();
+                        let stats = read_unit_cgroup(&control_group)
+                            .map_err(|e| tracing::debug!("cgroup read for {} failed: {}", service, e))
+                            .ok();
+
+                        let history = self.get_metrics_history(&key).to_vec();
+                        if tx.send(AppEvent::MetricsUpdated { service: service.clone(), scope, history }).await.is_err() {
+                            break;
+                        }
+                        if tx.send(AppEvent::CgroupUpdated { service, scope, stats }).await.is_err() {
+                            break;
+                        }
+                    }
+                    Err(e) => tracing::debug!("Metrics poll for {} failed: {}", service, e),
+                }
+            }
+        });
+    }
+
+    /// Poll resource usage of a list of units, e.g. the dashboard's active units
+    ///
+    /// Only the previous sample per unit is kept; an empty list pauses polling.
+    pub fn spawn_batch_poller(
+        mut self,
+        interval: Duration,
+        mut units: watch::Receiver<Vec<(String, ServiceScope)>>,
+        tx: mpsc::Sender<AppEvent>,
+    ) {
+        self.max_history = 1;
+        tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            // A slow round should not be followed by a burst of catch-up rounds
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+            loop {
+                tokio::select! {
+                    _ = ticker.tick() => {}
+                    changed = units.changed() => {
+                        if changed.is_err() {
+                            break;
+                        }
+                        // Only sample right away when the columns were just turned on
+                        if !self.history.is_empty() {
+                            continue;
+                        }
+                        ticker.reset();
+                    }
+                }
+
+                let targets = units.borrow().clone();
+                if targets.is_empty() {
+                    self.history.clear();
+                    continue;
+                }
+                let resources = self.get_units_resources(&targets).await;
+                if tx.send(AppEvent::ResourcesUpdated(resources)).await.is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
     /// Get service object path from systemd
-    async fn get_service_path(&self, service_name: &str) -> Result<zbus::zvariant::OwnedObjectPath> {
+    async fn get_service_path(connection: &Connection, service_name: &str) -> Result<zbus::zvariant::OwnedObjectPath> {
         let proxy = zbus::Proxy::new(
-            &self.connection,
+            connection,
             "org.freedesktop.systemd1",
             "/org/freedesktop/systemd1",
             "org.freedesktop.systemd1.Manager",
@@ -139,43 +373,60 @@ impl MetricsCollector {
     }
 
     /// Get service properties from D-Bus
+    ///
+    /// Reads the Unit interface plus the unit type's own one (counters,
+    /// ControlGroup), not every interface the object implements.
     async fn get_service_properties(
-        &self,
+        connection: &Connection,
         service_path: &zbus::zvariant::OwnedObjectPath,
+        service_name: &str,
     ) -> Result<std::collections::HashMap<String, zbus::zvariant::OwnedValue>> {
-        let props_proxy = zbus::fdo::PropertiesProxy::builder(&self.connection)
+        let props_proxy = Self::properties_proxy(connection, service_path).await?;
+        let interfaces = std::iter::once("org.freedesktop.systemd1.Unit")
+            .chain(UnitKind::from_unit_name(service_name).and_then(|kind| kind.interface()));
+
+        let mut props = HashMap::new();
+        for interface in interfaces {
+            props.extend(
+                props_proxy
+                    .get_all(Optional::from(Some(InterfaceName::from_static_str_unchecked(interface))))
+                    .await
+                    .map_err(|e| SysproxError::ServiceInfo(format!("Failed to get properties: {}", e)))?,
+            );
+        }
+
+        Ok(props)
+    }
+
+    /// Properties proxy for a unit object
+    async fn properties_proxy<'a>(
+        connection: &Connection,
+        service_path: &'a zbus::zvariant::OwnedObjectPath,
+    ) -> Result<zbus::fdo::PropertiesProxy<'a>> {
+        zbus::fdo::PropertiesProxy::builder(connection)
             .destination("org.freedesktop.systemd1")?
             .path(service_path.as_str())?
             .build()
             .await
-            .map_err(|e| SysproxError::SystemdConnection(e.to_string()))?;
-
-        use zbus::zvariant::Optional;
-        let props = props_proxy
-            .get_all(Optional::default())
-            .await
-            .map_err(|e| SysproxError::ServiceInfo(format!("Failed to get properties: {}", e)))?;
-
-        Ok(props)
+            .map_err(|e| SysproxError::SystemdConnection(e.to_string()).into())
     }
 
-    /// Calculate CPU percentage based on historical data
-    fn calculate_cpu_percentage(&mut self, service_name: &str, current: &Metrics) -> f64 {
-        if let Some(snapshots) = self.history.get(service_name) {
-            if let Some(prev_snapshot) = snapshots.iter().last() {
-                let prev = &prev_snapshot.metrics;
-                if current.cpu_usage_nsec > prev.cpu_usage_nsec {
-                    let cpu_delta_ns = current.cpu_usage_nsec - prev.cpu_usage_nsec;
-                    let time_delta_secs = (chrono::Utc::now() - prev_snapshot.timestamp).num_seconds() as f64;
-                    
-                    if time_delta_secs > 0.0 {
-                        let cpu_delta_secs = cpu_delta_ns as f64 / 1_000_000_000.0;
-                        return (cpu_delta_secs / time_delta_secs) * 100.0;
-                    }
-                }
-            }
-        }
-        0.0
+    /// Calculate CPU percentage against the previous sample, if any
+    fn calculate_cpu_percentage(
+        &self,
+        service_name: &str,
+        current: &Metrics,
+        timestamp: chrono::DateTime<chrono::Utc>,
+    ) -> CpuUsage {
+        let Some(prev_snapshot) = self.history.get(service_name).and_then(|snapshots| snapshots.last()) else {
+            return CpuUsage::default();
+        };
+        let elapsed_nsec = (timestamp - prev_snapshot.timestamp)
+            .num_nanoseconds()
+            .and_then(|ns| u64::try_from(ns).ok())
+            .unwrap_or(0);
+
+        cpu_usage(prev_snapshot.metrics.cpu_usage_nsec, current.cpu_usage_nsec, elapsed_nsec, self.cpus).unwrap_or_default()
     }
 
     /// Update history with new snapshot
@@ -267,17 +518,137 @@ struct MemoryInfo {
     swap_used: u64,
 }
 
+/// Read the accounting counters out of a unit's D-Bus properties
+///
+/// Counters systemd does not report (e.g. MemoryPeak before v255) read as
+/// u64::MAX, the same as accounting being off.
+pub fn metrics_from_properties(props: &HashMap<String, zbus::zvariant::OwnedValue>) -> Metrics {
+    let get_u64 = |key: &str, missing: u64| {
+        props
+            .get(key)
+            .and_then(|v| v.downcast_ref::<u64>().ok())
+            .unwrap_or(missing)
+    };
+
+    Metrics {
+        cpu_usage_nsec: get_u64("CPUUsageNSec", 0),
+        memory_current: get_u64("MemoryCurrent", 0),
+        tasks_current: get_u64("TasksCurrent", 0),
+        n_restarts: props
+            .get("NRestarts")
+            .and_then(|v| v.downcast_ref::<u32>().ok())
+            .unwrap_or(0),
+        io_read_bytes: get_u64("IOReadBytes", u64::MAX),
+        io_write_bytes: get_u64("IOWriteBytes", u64::MAX),
+        ip_ingress_bytes: get_u64("IPIngressBytes", u64::MAX),
+        ip_egress_bytes: get_u64("IPEgressBytes", u64::MAX),
+        memory_peak: get_u64("MemoryPeak", u64::MAX),
+        memory_swap_current: get_u64("MemorySwapCurrent", u64::MAX),
+    }
+}
+
+/// Per-second rate of a byte counter between two samples
+///
+/// None when either sample is unaccounted (u64::MAX) or no time passed. As
+/// with CPU time, a counter that went down was reset by a restart.
+pub fn counter_rate(prev: u64, curr: u64, elapsed_secs: f64) -> Option<f64> {
+    if prev == u64::MAX || curr == u64::MAX || elapsed_secs <= 0.0 {
+        return None;
+    }
+    let delta = if curr >= prev { curr - prev } else { curr };
+    Some(delta as f64 / elapsed_secs)
+}
+
+/// Disk and network throughput between the last two samples, in bytes per second
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct IoRates {
+    pub io_read: Option<f64>,
+    pub io_write: Option<f64>,
+    pub ip_ingress: Option<f64>,
+    pub ip_egress: Option<f64>,
+}
+
+impl IoRates {
+    /// Rates from the newest two snapshots; all None with fewer than two
+    pub fn from_history(history: &[MetricsSnapshot]) -> Self {
+        let [.., prev, curr] = history else {
+            return Self::default();
+        };
+        let elapsed_secs = (curr.timestamp - prev.timestamp)
+            .num_nanoseconds()
+            .map(|ns| ns as f64 / 1_000_000_000.0)
+            .unwrap_or(0.0);
+        let rate = |counter: fn(&Metrics) -> u64| counter_rate(counter(&prev.metrics), counter(&curr.metrics), elapsed_secs);
+
+        Self {
+            io_read: rate(|m| m.io_read_bytes),
+            io_write: rate(|m| m.io_write_bytes),
+            ip_ingress: rate(|m| m.ip_ingress_bytes),
+            ip_egress: rate(|m| m.ip_egress_bytes),
+        }
+    }
+}
+
 /// Calculate CPU percentage from two metric snapshots (legacy function)
 pub fn calculate_cpu_percent(prev: &Metrics, curr: &Metrics, interval_secs: f64) -> f64 {
-    if interval_secs <= 0.0 || curr.cpu_usage_nsec <= prev.cpu_usage_nsec {
+    if interval_secs <= 0.0 {
         return 0.0;
     }
+    let elapsed_nsec = (interval_secs * 1_000_000_000.0) as u64;
+    cpu_usage(prev.cpu_usage_nsec, curr.cpu_usage_nsec, elapsed_nsec, 1)
+        .map(|usage| usage.per_core)
+        .unwrap_or(0.0)
+}
+
+/// CPU usage from two CPUUsageNSec readings taken `elapsed_nsec` apart
+///
+/// A reading below the previous one means the unit restarted with a fresh
+/// cgroup during the interval, so all of its current usage falls inside it.
+/// u64::MAX is systemd's "not available" (accounting off or unit inactive);
+/// either reading being unavailable gives no rate, like `counter_rate`.
+pub fn cpu_usage(prev_nsec: u64, curr_nsec: u64, elapsed_nsec: u64, cpus: usize) -> Option<CpuUsage> {
+    if elapsed_nsec == 0 || prev_nsec == u64::MAX || curr_nsec == u64::MAX {
+        return None;
+    }
 
-    let cpu_delta_ns = curr.cpu_usage_nsec - prev.cpu_usage_nsec;
-    let cpu_delta_secs = cpu_delta_ns as f64 / 1_000_000_000.0;
+    let delta = if curr_nsec >= prev_nsec {
+        curr_nsec - prev_nsec
+    } else {
+        curr_nsec
+    };
+    let per_core = delta as f64 / elapsed_nsec as f64 * 100.0;
+
+    Some(CpuUsage {
+        per_core,
+        machine: per_core / cpus.max(1) as f64,
+    })
+}
 
-    // Percentage based on interval
-    (cpu_delta_secs / interval_secs) * 100.0
+/// Number of online CPUs, from sysfs with a fallback to the scheduler's view
+pub fn online_cpus() -> usize {
+    std::fs::read_to_string("/sys/devices/system/cpu/online")
+        .ok()
+        .and_then(|list| parse_cpu_list(&list))
+        .or_else(|| std::thread::available_parallelism().ok().map(|n| n.get()))
+        .unwrap_or(1)
+}
+
+/// Count the CPUs in a kernel CPU list like "0-3,5,7-8"
+pub fn parse_cpu_list(list: &str) -> Option<usize> {
+    let mut count = 0;
+    for part in list.trim().split(',').filter(|p| !p.is_empty()) {
+        count += match part.split_once('-') {
+            Some((start, end)) => {
+                let (start, end) = (start.parse::<usize>().ok()?, end.parse::<usize>().ok()?);
+                end.checked_sub(start)? + 1
+            }
+            None => {
+                part.parse::<usize>().ok()?;
+                1
+            }
+        };
+    }
+    (count > 0).then_some(count)
 }
 
 /// Get service metrics from systemd (legacy function - use MetricsCollector instead)
@@ -310,8 +681,10 @@ impl Clone for MetricsCollector {
     fn clone(&self) -> Self {
         Self {
             connection: self.connection.clone(),
+            user_connection: self.user_connection.clone(),
             history: std::collections::HashMap::new(),
             max_history: self.max_history,
+            cpus: self.cpus,
         }
     }
 }
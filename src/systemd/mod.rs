@@ -1,21 +1,32 @@
 // Systemd integration module
 
+pub mod cgroup;
 pub mod client;
 pub mod control;
+pub mod dropin;
 pub mod journal;
+pub mod limits;
+pub mod lint;
 pub mod metrics;
 pub mod models;
 pub mod resilience;
+pub mod transient;
+pub mod unit_file;
 
 #[cfg(test)]
 mod tests;
 
+pub use cgroup::CgroupStats;
 pub use client::SystemdClient;
 pub use control::ServiceController;
+pub use dropin::DropInEdit;
 pub use journal::{JournalReader, LogLine};
-pub use metrics::{MetricsCollector, MetricsSnapshot, ServiceMetricsCollection, SystemMetrics};
-pub use models::{Metrics, Service, ServiceDetail, ServiceScope, ServiceStatus, ServiceStatusExtended};
+pub use limits::ResourceLimit;
+pub use metrics::{CpuUsage, IoRates, MetricsCollector, MetricsSnapshot, ServiceMetricsCollection, SystemMetrics, UnitResources};
+pub use models::{Job, JobResult, KillSignal, KillTarget, Metrics, Service, ServiceDetail, ServiceScope, ServiceStatus, ServiceStatusExtended, Socket, Timer, UnitKind};
 pub use resilience::{ConnectionManager, RecoveryStrategy, SystemdHealthChecker};
+pub use transient::{TransientKind, TransientSpec};
+pub use unit_file::UnitFile;
 
 // Re-export for tests
 #[cfg(test)]
@@ -31,7 +31,319 @@ impl ServiceScope {
     }
 }
 
-/// Service represents a systemd service unit
+/// UnitKind is the systemd unit type, taken from the unit name suffix
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+pub enum UnitKind {
+    #[default]
+    Service,
+    Socket,
+    Target,
+    Device,
+    Mount,
+    Automount,
+    Swap,
+    Timer,
+    Path,
+    Slice,
+    Scope,
+}
+
+impl UnitKind {
+    /// Every unit type systemd knows about
+    pub const ALL: [UnitKind; 11] = [
+        UnitKind::Service,
+        UnitKind::Socket,
+        UnitKind::Target,
+        UnitKind::Device,
+        UnitKind::Mount,
+        UnitKind::Automount,
+        UnitKind::Swap,
+        UnitKind::Timer,
+        UnitKind::Path,
+        UnitKind::Slice,
+        UnitKind::Scope,
+    ];
+
+    /// Unit types shown in the dashboard (devices are too numerous to be useful)
+    pub const LISTED: [UnitKind; 10] = [
+        UnitKind::Service,
+        UnitKind::Socket,
+        UnitKind::Target,
+        UnitKind::Mount,
+        UnitKind::Automount,
+        UnitKind::Swap,
+        UnitKind::Timer,
+        UnitKind::Path,
+        UnitKind::Slice,
+        UnitKind::Scope,
+    ];
+
+    /// Determine the unit type from a unit name ("home.mount" -> Mount)
+    pub fn from_unit_name(name: &str) -> Option<Self> {
+        let (_, suffix) = name.rsplit_once('.')?;
+        Self::ALL.into_iter().find(|kind| kind.label() == suffix)
+    }
+
+    /// Returns true if units of this type appear in the dashboard
+    pub fn is_listed(&self) -> bool {
+        Self::LISTED.contains(self)
+    }
+
+    /// Returns true if units of this type run processes in their own cgroup
+    pub fn has_cgroup(&self) -> bool {
+        matches!(
+            self,
+            UnitKind::Service | UnitKind::Socket | UnitKind::Mount | UnitKind::Swap | UnitKind::Slice | UnitKind::Scope
+        )
+    }
+
+    /// Returns true if systemd can freeze units of this type (cgroup freezer)
+    pub fn can_freeze(&self) -> bool {
+        matches!(self, UnitKind::Service | UnitKind::Scope | UnitKind::Slice)
+    }
+
+    /// Type-specific unit file section, e.g. [Service]; targets and devices have none
+    pub fn section(&self) -> Option<&'static str> {
+        match self {
+            UnitKind::Service => Some("Service"),
+            UnitKind::Socket => Some("Socket"),
+            UnitKind::Mount => Some("Mount"),
+            UnitKind::Automount => Some("Automount"),
+            UnitKind::Swap => Some("Swap"),
+            UnitKind::Timer => Some("Timer"),
+            UnitKind::Path => Some("Path"),
+            UnitKind::Slice => Some("Slice"),
+            UnitKind::Scope => Some("Scope"),
+            UnitKind::Target | UnitKind::Device => None,
+        }
+    }
+
+    /// Get display label, which is also the unit name suffix
+    pub fn label(&self) -> &'static str {
+        match self {
+            UnitKind::Service => "service",
+            UnitKind::Socket => "socket",
+            UnitKind::Target => "target",
+            UnitKind::Device => "device",
+            UnitKind::Mount => "mount",
+            UnitKind::Automount => "automount",
+            UnitKind::Swap => "swap",
+            UnitKind::Timer => "timer",
+            UnitKind::Path => "path",
+            UnitKind::Slice => "slice",
+            UnitKind::Scope => "scope",
+        }
+    }
+
+    /// Get the D-Bus interface carrying this unit type's own properties
+    pub fn interface(&self) -> Option<&'static str> {
+        match self {
+            UnitKind::Service => Some("org.freedesktop.systemd1.Service"),
+            UnitKind::Socket => Some("org.freedesktop.systemd1.Socket"),
+            UnitKind::Device => Some("org.freedesktop.systemd1.Device"),
+            UnitKind::Mount => Some("org.freedesktop.systemd1.Mount"),
+            UnitKind::Automount => Some("org.freedesktop.systemd1.Automount"),
+            UnitKind::Swap => Some("org.freedesktop.systemd1.Swap"),
+            UnitKind::Timer => Some("org.freedesktop.systemd1.Timer"),
+            UnitKind::Path => Some("org.freedesktop.systemd1.Path"),
+            UnitKind::Slice => Some("org.freedesktop.systemd1.Slice"),
+            UnitKind::Scope => Some("org.freedesktop.systemd1.Scope"),
+            UnitKind::Target => None,
+        }
+    }
+
+    /// Type-specific properties shown in the detail view
+    pub fn detail_properties(&self) -> &'static [&'static str] {
+        match self {
+            // Services have their own configuration section
+            UnitKind::Service | UnitKind::Target => &[],
+            UnitKind::Socket => &["Listen", "Accept", "NAccepted", "NConnections", "NRefused", "Result"],
+            UnitKind::Device => &["SysFSPath"],
+            UnitKind::Mount => &["What", "Where", "Type", "Options", "TimeoutUSec", "Result"],
+            UnitKind::Automount => &["Where", "DirectoryMode", "TimeoutIdleUSec", "Result"],
+            UnitKind::Swap => &["What", "Priority", "Options", "TimeoutUSec", "Result"],
+            UnitKind::Timer => &["TimersCalendar", "TimersMonotonic", "Persistent", "AccuracyUSec", "RandomizedDelayUSec", "Result"],
+            UnitKind::Path => &["Paths", "MakeDirectory", "Result"],
+            UnitKind::Slice => &["ControlGroup", "MemoryCurrent", "TasksCurrent", "CPUWeight"],
+            UnitKind::Scope => &["ControlGroup", "Controller", "RuntimeMaxUSec", "Result"],
+        }
+    }
+}
+
+/// Job represents an entry in a systemd manager's job queue
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Job {
+    pub id: u32,
+    pub unit: String,
+    /// Job type, e.g. "start", "stop", "restart"
+    pub job_type: String,
+    /// "waiting" (blocked on other jobs) or "running"
+    pub state: String,
+    pub scope: ServiceScope,
+}
+
+impl Job {
+    /// Returns true if the job is blocked behind other jobs
+    pub fn is_waiting(&self) -> bool {
+        self.state == "waiting"
+    }
+}
+
+/// KillSignal is a signal the kill dialog can send to a unit's processes
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum KillSignal {
+    Hup,
+    Term,
+    Kill,
+    Usr1,
+    Usr2,
+}
+
+impl KillSignal {
+    pub const ALL: [KillSignal; 5] = [
+        KillSignal::Hup,
+        KillSignal::Term,
+        KillSignal::Kill,
+        KillSignal::Usr1,
+        KillSignal::Usr2,
+    ];
+
+    /// Signal number on Linux
+    pub fn number(&self) -> i32 {
+        match self {
+            KillSignal::Hup => 1,
+            KillSignal::Kill => 9,
+            KillSignal::Usr1 => 10,
+            KillSignal::Usr2 => 12,
+            KillSignal::Term => 15,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            KillSignal::Hup => "SIGHUP",
+            KillSignal::Term => "SIGTERM",
+            KillSignal::Kill => "SIGKILL",
+            KillSignal::Usr1 => "SIGUSR1",
+            KillSignal::Usr2 => "SIGUSR2",
+        }
+    }
+
+    /// What daemons conventionally do on receipt
+    pub fn hint(&self) -> &'static str {
+        match self {
+            KillSignal::Hup => "reload config / reopen logs",
+            KillSignal::Term => "graceful termination",
+            KillSignal::Kill => "immediate kill, cannot be caught",
+            KillSignal::Usr1 | KillSignal::Usr2 => "application defined",
+        }
+    }
+}
+
+/// KillTarget selects which of a unit's processes receive a signal
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum KillTarget {
+    #[default]
+    Main,
+    Control,
+    All,
+}
+
+impl KillTarget {
+    pub const ALL: [KillTarget; 3] = [KillTarget::Main, KillTarget::Control, KillTarget::All];
+
+    /// The "whom" argument of KillUnit
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KillTarget::Main => "main",
+            KillTarget::Control => "control",
+            KillTarget::All => "all",
+        }
+    }
+
+    pub fn description(&self) -> &'static str {
+        match self {
+            KillTarget::Main => "the main process",
+            KillTarget::Control => "the control process",
+            KillTarget::All => "all processes",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        let idx = Self::ALL.iter().position(|t| t == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+
+    pub fn prev(&self) -> Self {
+        let idx = Self::ALL.iter().position(|t| t == self).unwrap_or(0);
+        Self::ALL[(idx + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// JobResult is the outcome systemd reports in JobRemoved for a finished job
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JobResult {
+    Done,
+    Failed,
+    Timeout,
+    Dependency,
+    Canceled,
+    Skipped,
+    /// Less common results ("invalid", "assert", "unsupported", ...)
+    Other(String),
+}
+
+impl JobResult {
+    /// Parse the result string from the JobRemoved signal
+    pub fn from_result(result: &str) -> Self {
+        match result {
+            "done" => JobResult::Done,
+            "failed" => JobResult::Failed,
+            "timeout" => JobResult::Timeout,
+            "dependency" => JobResult::Dependency,
+            "canceled" => JobResult::Canceled,
+            "skipped" => JobResult::Skipped,
+            other => JobResult::Other(other.to_string()),
+        }
+    }
+
+    /// Returns true if the job did what was asked
+    pub fn is_success(&self) -> bool {
+        *self == JobResult::Done
+    }
+
+    /// Returns true if the job failed (skipped jobs are neither)
+    pub fn is_failure(&self) -> bool {
+        !matches!(self, JobResult::Done | JobResult::Skipped)
+    }
+
+    /// Human-readable explanation for status messages
+    pub fn description(&self) -> String {
+        match self {
+            JobResult::Done => "job completed".to_string(),
+            JobResult::Failed => "job failed, check the unit's logs".to_string(),
+            JobResult::Timeout => "job timed out".to_string(),
+            JobResult::Dependency => "a required dependency failed".to_string(),
+            JobResult::Canceled => "job was canceled".to_string(),
+            JobResult::Skipped => "job skipped, not applicable in the unit's current state".to_string(),
+            JobResult::Other(result) => format!("job finished with result '{}'", result),
+        }
+    }
+}
+
+/// Returns true if a unit file state means the unit starts automatically
+pub fn is_enabled_state(state: &str) -> bool {
+    state == "enabled" || state == "enabled-runtime"
+}
+
+/// Returns true if a unit file or drop-in lives in an administrator directory
+/// (/etc/systemd or ~/.config/systemd), not under /usr/lib or /run
+pub fn is_admin_path(path: &str) -> bool {
+    let path = std::path::Path::new(path);
+    path.starts_with("/etc/systemd") || dirs::config_dir().is_some_and(|dir| path.starts_with(dir.join("systemd")))
+}
+
+/// Service represents a systemd unit in the dashboard listing (any UnitKind)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Service {
     pub id: String,
@@ -43,6 +355,12 @@ pub struct Service {
     pub pid: u32,
     pub enabled: bool,
     pub scope: ServiceScope,
+    #[serde(default)]
+    pub kind: UnitKind,
+    /// FreezerState: "running", "freezing", "frozen" or "thawing"
+    /// (empty when not fetched, e.g. for units that cannot be frozen)
+    #[serde(default)]
+    pub freezer_state: String,
     #[serde(with = "chrono::serde::ts_seconds")]
     pub loaded_at: DateTime<Utc>,
 }
@@ -58,6 +376,16 @@ impl Service {
         self.active_state == "failed"
     }
 
+    /// Returns true if the unit's processes are frozen (or being frozen)
+    pub fn is_frozen(&self) -> bool {
+        self.freezer_state == "frozen" || self.freezer_state == "freezing"
+    }
+
+    /// Returns true if the unit file is masked (linked to /dev/null)
+    pub fn is_masked(&self) -> bool {
+        self.load_state == "masked"
+    }
+
     /// Returns true if the service is inactive/stopped
     pub fn is_inactive(&self) -> bool {
         self.active_state == "inactive"
@@ -81,6 +409,58 @@ impl Service {
     }
 }
 
+/// Timer represents a systemd timer unit and the unit it triggers
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Timer {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub active_state: String,
+    pub sub_state: String,
+    pub scope: ServiceScope,
+    /// Unit activated when the timer elapses (usually a .service)
+    pub unit: String,
+    #[serde(with = "chrono::serde::ts_seconds_option")]
+    pub next_elapse: Option<DateTime<Utc>>,
+    #[serde(with = "chrono::serde::ts_seconds_option")]
+    pub last_trigger: Option<DateTime<Utc>>,
+    pub persistent: bool,
+    /// Calendar and monotonic specs, e.g. "OnCalendar=daily", "OnBootSec=15min"
+    pub schedule: Vec<String>,
+}
+
+impl Timer {
+    /// Returns true if the timer is currently armed
+    pub fn is_active(&self) -> bool {
+        self.active_state == "active"
+    }
+}
+
+/// Socket represents a systemd socket unit and the unit it activates
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Socket {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub active_state: String,
+    pub sub_state: String,
+    pub scope: ServiceScope,
+    /// Unit activated by incoming connections (usually a .service)
+    pub unit: String,
+    /// Listen addresses with their type, e.g. "[::]:22 (Stream)"
+    pub listen: Vec<String>,
+    pub n_accepted: u32,
+    pub n_connections: u32,
+    pub n_refused: u32,
+}
+
+impl Socket {
+    /// Returns true if the socket is currently listening
+    pub fn is_active(&self) -> bool {
+        self.active_state == "active"
+    }
+}
+
 /// Extended service status with enable/disable information
 #[derive(Debug, Clone)]
 pub struct ServiceStatusExtended {
@@ -132,6 +512,12 @@ pub struct ServiceDetail {
     pub main_pid: u32,
     pub control_pid: u32,
     pub load_path: String,
+    /// Drop-in files applied on top of load_path, in the order systemd reads them
+    pub drop_in_paths: Vec<String>,
+    /// UnitFileState, e.g. "enabled", "linked" or "masked"
+    pub unit_file_state: String,
+    /// Vendor copy under /usr/lib/systemd hidden by a local load_path (empty if none)
+    pub vendor_path: String,
     pub exec_main_start: String,
     pub exec_main_status: String,
     pub memory_current: u64,
@@ -139,6 +525,11 @@ pub struct ServiceDetail {
     pub cpu_usage_nsec: u64,
     pub tasks_current: u64,
     pub tasks_max: u64,
+    // Resource controls (u64::MAX means unset / infinity)
+    pub memory_high: u64,
+    pub cpu_quota_per_sec_usec: u64,
+    pub cpu_weight: u64,
+    pub io_weight: u64,
     pub n_restarts: u32,
     #[serde(with = "chrono::serde::ts_seconds")]
     pub active_enter_time: DateTime<Utc>,
@@ -161,6 +552,9 @@ pub struct ServiceDetail {
     pub group: String,
     pub working_directory: String,
     pub environment: Vec<String>,
+
+    /// Type-specific properties for non-service units, e.g. "Where" for mounts
+    pub type_properties: Vec<(String, String)>,
 }
 
 impl ServiceDetail {
@@ -186,6 +580,43 @@ impl ServiceDetail {
         }
     }
 
+    /// Returns true if a local unit file hides the vendor-supplied one
+    pub fn shadows_vendor(&self) -> bool {
+        !self.vendor_path.is_empty()
+    }
+
+    /// Returns true if the unit file lives in the administrator directory,
+    /// i.e. it was created locally and can be deleted without breaking a package
+    ///
+    /// A full local copy of a packaged unit does not count: removing it would
+    /// stop and disable the packaged service.
+    pub fn is_removable(&self) -> bool {
+        !self.load_path.is_empty()
+            && !self.shadows_vendor()
+            && crate::systemd::dropin::unit_dir(self.service.scope)
+                .is_ok_and(|dir| std::path::Path::new(&self.load_path).parent() == Some(dir.as_path()))
+    }
+
+    /// Local customizations worth checking after an upgrade, like `systemd-delta`
+    pub fn local_changes(&self) -> Vec<String> {
+        let mut changes = Vec::new();
+        if self.shadows_vendor() {
+            changes.push("overrides vendor file".to_string());
+        }
+        match self.drop_in_paths.iter().filter(|path| is_admin_path(path)).count() {
+            0 => {}
+            1 => changes.push("1 drop-in".to_string()),
+            n => changes.push(format!("{} drop-ins", n)),
+        }
+        if self.unit_file_state.starts_with("linked") {
+            changes.push("linked".to_string());
+        }
+        if self.unit_file_state.starts_with("masked") {
+            changes.push("masked".to_string());
+        }
+        changes
+    }
+
     /// Format memory usage as human-readable string
     pub fn memory_usage_formatted(&self) -> String {
         format_bytes(self.memory_current)
@@ -202,12 +633,33 @@ impl ServiceDetail {
 }
 
 /// Metrics snapshot for a service
+///
+/// The IO, IP and memory peak/swap counters are u64::MAX when systemd
+/// does not account them for the unit, as it reports them.
 #[derive(Debug, Clone, Copy, Default)]
 pub struct Metrics {
     pub cpu_usage_nsec: u64,
     pub memory_current: u64,
     pub tasks_current: u64,
     pub n_restarts: u32,
+    pub io_read_bytes: u64,
+    pub io_write_bytes: u64,
+    pub ip_ingress_bytes: u64,
+    pub ip_egress_bytes: u64,
+    pub memory_peak: u64,
+    pub memory_swap_current: u64,
+}
+
+impl Metrics {
+    /// True when IOAccounting= is on and the io controller is available
+    pub fn io_accounted(&self) -> bool {
+        self.io_read_bytes != u64::MAX && self.io_write_bytes != u64::MAX
+    }
+
+    /// True when IPAccounting= is on
+    pub fn ip_accounted(&self) -> bool {
+        self.ip_ingress_bytes != u64::MAX && self.ip_egress_bytes != u64::MAX
+    }
 }
 
 
@@ -233,6 +685,47 @@ mod serde_duration {
     }
 }
 
+/// Convert systemd microseconds since epoch to a timestamp (0 means "never")
+pub fn timestamp_from_usec(usec: u64) -> Option<DateTime<Utc>> {
+    if usec == 0 || usec == u64::MAX {
+        return None;
+    }
+
+    DateTime::from_timestamp((usec / 1_000_000) as i64, ((usec % 1_000_000) * 1000) as u32)
+}
+
+/// Format a systemd microsecond time span the way systemctl does ("1h 30min")
+pub fn format_usec_span(usec: u64) -> String {
+    if usec == 0 {
+        return "0".to_string();
+    }
+
+    let units: [(u64, &str); 7] = [
+        (365 * 24 * 3600 * 1_000_000, "y"),
+        (7 * 24 * 3600 * 1_000_000, "w"),
+        (24 * 3600 * 1_000_000, "d"),
+        (3600 * 1_000_000, "h"),
+        (60 * 1_000_000, "min"),
+        (1_000_000, "s"),
+        (1_000, "ms"),
+    ];
+
+    let mut remaining = usec;
+    let mut parts = Vec::new();
+    for (size, label) in units {
+        if remaining >= size {
+            parts.push(format!("{}{}", remaining / size, label));
+            remaining %= size;
+        }
+    }
+
+    if parts.is_empty() {
+        format!("{}us", usec)
+    } else {
+        parts.join(" ")
+    }
+}
+
 /// Format bytes as human-readable string (e.g., "45.2 MB")
 fn format_bytes(bytes: u64) -> String {
     use byte_unit::{Byte, UnitType};
@@ -1,6 +1,7 @@
 // Resilient D-Bus connection handling with retry logic
 
 use crate::error::{Result, SysproxError};
+use crate::systemd::client::ListedJob;
 use std::time::Duration;
 use tokio::time::sleep;
 use zbus::Connection;
@@ -192,8 +193,8 @@ impl SystemdHealthChecker {
 
         match proxy {
             Ok(proxy) => {
-                // Try a simple call
-                proxy.call::<_, _, ()>("ListJobs", &()).await.is_ok()
+                // Try a simple call (the reply must be typed or it never parses)
+                proxy.call::<_, _, Vec<ListedJob>>("ListJobs", &()).await.is_ok()
             }
             Err(_) => false,
         }
@@ -218,7 +219,7 @@ impl SystemdHealthChecker {
                         ).await;
 
                         if let Ok(proxy) = proxy {
-                            if proxy.call::<_, _, ()>("ListJobs", &()).await.is_err() {
+                            if proxy.call::<_, _, Vec<ListedJob>>("ListJobs", &()).await.is_err() {
                                 tracing::warn!("Systemd connection health check failed");
                             }
                         }
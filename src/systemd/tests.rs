@@ -4,7 +4,7 @@ mod tests {
     use crate::systemd::{
         ConnectionManager, RecoveryStrategy, ServiceController,
         Service, ServiceDetail, MetricsCollector, Metrics,
-        ServiceStatusExtended, SystemMetrics, calculate_cpu_percent, ServiceScope
+        ServiceStatusExtended, SystemMetrics, calculate_cpu_percent, ServiceScope, UnitKind
     };
 
     #[tokio::test]
@@ -102,6 +102,8 @@ mod tests {
             pid: 1234,
             enabled: true,
             scope: ServiceScope::System,
+            kind: UnitKind::Service,
+            freezer_state: String::new(),
             loaded_at: chrono::Utc::now(),
         };
 
@@ -110,6 +112,9 @@ mod tests {
             main_pid: 1234,
             control_pid: 0,
             load_path: "/test/path".to_string(),
+            drop_in_paths: Vec::new(),
+            unit_file_state: String::new(),
+            vendor_path: String::new(),
             exec_main_start: String::new(),
             exec_main_status: String::new(),
             memory_current: 1024 * 1024,
@@ -117,6 +122,10 @@ mod tests {
             cpu_usage_nsec: 1000000000,
             tasks_current: 5,
             tasks_max: 100,
+            memory_high: u64::MAX,
+            cpu_quota_per_sec_usec: u64::MAX,
+            cpu_weight: u64::MAX,
+            io_weight: u64::MAX,
             n_restarts: 2,
             active_enter_time: chrono::Utc::now(),
             active_exit_time: chrono::Utc::now(),
@@ -133,6 +142,7 @@ mod tests {
             group: "root".to_string(),
             working_directory: "/".to_string(),
             environment: vec![],
+            type_properties: Vec::new(),
         };
 
         let status = ServiceStatusExtended {
@@ -151,6 +161,7 @@ mod tests {
             memory_current: 1024,
             tasks_current: 5,
             n_restarts: 1,
+            ..Default::default()
         };
 
         let curr = Metrics {
@@ -158,12 +169,513 @@ mod tests {
             memory_current: 2048,
             tasks_current: 10,
             n_restarts: 1,
+            ..Default::default()
         };
 
         let cpu_percent = calculate_cpu_percent(&prev, &curr, 1.0);
         assert!((cpu_percent - 0.1).abs() < 0.01); // Should be ~0.1% for 1s interval
     }
 
+    #[test]
+    fn test_cpu_usage_precision() {
+        use crate::systemd::metrics::{cpu_usage, parse_cpu_list};
+
+        // 50ms of CPU in 100ms is half a core, a quarter of a 2-CPU machine
+        let usage = cpu_usage(1_000_000_000, 1_050_000_000, 100_000_000, 2).unwrap();
+        assert!((usage.per_core - 50.0).abs() < 1e-9);
+        assert!((usage.machine - 25.0).abs() < 1e-9);
+
+        // Several busy cores go past 100% per core but not per machine
+        let usage = cpu_usage(0, 3_000_000_000, 1_000_000_000, 4).unwrap();
+        assert!((usage.per_core - 300.0).abs() < 1e-9);
+        assert!((usage.machine - 75.0).abs() < 1e-9);
+
+        // Counter reset after a restart: the new counter is all within the interval
+        let usage = cpu_usage(5_000_000_000, 200_000_000, 1_000_000_000, 1).unwrap();
+        assert!((usage.per_core - 20.0).abs() < 1e-9);
+        // Coming from "not available" there is no interval to measure; the
+        // counter holds the unit's whole lifetime, not one interval
+        assert!(cpu_usage(u64::MAX, 100_000_000, 1_000_000_000, 1).is_none());
+
+        assert!(cpu_usage(0, u64::MAX, 1_000_000_000, 1).is_none());
+        assert!(cpu_usage(0, 1_000, 0, 1).is_none());
+        // A zero CPU count is treated as one
+        assert_eq!(cpu_usage(0, 500_000_000, 1_000_000_000, 0).unwrap().machine, 50.0);
+
+        // Sub-second intervals in the legacy helper
+        let prev = Metrics { cpu_usage_nsec: 0, ..Default::default() };
+        let curr = Metrics { cpu_usage_nsec: 25_000_000, ..Default::default() };
+        assert!((calculate_cpu_percent(&prev, &curr, 0.25) - 10.0).abs() < 1e-6);
+        assert!((calculate_cpu_percent(&curr, &prev, 1.0) - 0.0).abs() < 1e-9);
+
+        assert_eq!(parse_cpu_list("0-7\n"), Some(8));
+        assert_eq!(parse_cpu_list("0-3,5,7-8"), Some(7));
+        assert_eq!(parse_cpu_list("0"), Some(1));
+        assert_eq!(parse_cpu_list(""), None);
+        assert_eq!(parse_cpu_list("3-1"), None);
+        assert_eq!(parse_cpu_list("a-b"), None);
+    }
+
+    #[test]
+    fn test_io_accounting_rates() {
+        use crate::systemd::metrics::{counter_rate, metrics_from_properties};
+        use crate::systemd::{IoRates, MetricsSnapshot};
+        use zbus::zvariant::OwnedValue;
+
+        assert_eq!(counter_rate(1000, 3000, 2.0), Some(1000.0));
+        assert_eq!(counter_rate(1000, 3000, 0.5), Some(4000.0));
+        // Counter reset by a restart
+        assert_eq!(counter_rate(5000, 1000, 1.0), Some(1000.0));
+        assert_eq!(counter_rate(u64::MAX, 1000, 1.0), None);
+        assert_eq!(counter_rate(0, u64::MAX, 1.0), None);
+        assert_eq!(counter_rate(0, 1000, 0.0), None);
+
+        let props: std::collections::HashMap<String, OwnedValue> = [
+            ("CPUUsageNSec", OwnedValue::from(5_000u64)),
+            ("MemoryCurrent", OwnedValue::from(4096u64)),
+            ("NRestarts", OwnedValue::from(2u32)),
+            ("IOReadBytes", OwnedValue::from(100u64)),
+            ("IOWriteBytes", OwnedValue::from(200u64)),
+            ("IPIngressBytes", OwnedValue::from(u64::MAX)),
+            ("IPEgressBytes", OwnedValue::from(u64::MAX)),
+            ("MemorySwapCurrent", OwnedValue::from(0u64)),
+        ]
+        .into_iter()
+        .map(|(key, value)| (key.to_string(), value))
+        .collect();
+        let metrics = metrics_from_properties(&props);
+        assert_eq!(metrics.cpu_usage_nsec, 5_000);
+        assert_eq!(metrics.n_restarts, 2);
+        assert_eq!(metrics.tasks_current, 0);
+        assert!(metrics.io_accounted());
+        assert!(!metrics.ip_accounted());
+        // Missing on older systemd
+        assert_eq!(metrics.memory_peak, u64::MAX);
+        assert_eq!(metrics.memory_swap_current, 0);
+
+        let start = chrono::Utc::now();
+        let snapshot = |millis: i64, read: u64| MetricsSnapshot {
+            timestamp: start + chrono::Duration::milliseconds(millis),
+            metrics: Metrics { io_read_bytes: read, ..metrics },
+            cpu_percent: 0.0,
+            cpu_percent_machine: 0.0,
+        };
+        assert_eq!(IoRates::from_history(&[snapshot(0, 100)]), IoRates::default());
+
+        // Only the newest two samples count; sub-second gaps are exact
+        let rates = IoRates::from_history(&[snapshot(0, 0), snapshot(1000, 100), snapshot(1250, 1100)]);
+        assert_eq!(rates.io_read, Some(4000.0));
+        assert_eq!(rates.io_write, Some(0.0));
+        assert_eq!(rates.ip_ingress, None);
+        assert_eq!(rates.ip_egress, None);
+    }
+
+    #[test]
+    fn test_cgroup_reader() {
+        use crate::systemd::cgroup::{parse_memory_stat, parse_pressure, read_cgroup, MemoryBreakdown, MemoryEvents};
+
+        // Fake /sys/fs/cgroup with one unit
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path();
+        let dir = root.join("system.slice/nginx.service");
+        std::fs::create_dir_all(&dir).unwrap();
+        let write = |file: &str, content: &str| std::fs::write(dir.join(file), content).unwrap();
+        write("cgroup.controllers", "cpu io memory pids\n");
+        write("cpu.pressure", "some avg10=1.50 avg60=0.75 avg300=0.10 total=123456\nfull avg10=0.00 avg60=0.00 avg300=0.00 total=0\n");
+        write("memory.pressure", "some avg10=12.00 avg60=4.00 avg300=1.00 total=999\nfull avg10=8.25 avg60=2.00 avg300=0.50 total=500\n");
+        write(
+            "memory.stat",
+            "anon 10485760\nfile 5242880\nkernel 1048576\nkernel_stack 16384\nslab 500000\nsock 4096\nshmem 0\n",
+        );
+        write("memory.events", "low 0\nhigh 3\nmax 1\noom 1\noom_kill 1\noom_group_kill 0\n");
+
+        let stats = read_cgroup(root, "/system.slice/nginx.service").unwrap();
+        let cpu = stats.cpu_pressure.unwrap();
+        assert_eq!(cpu.some.avg10, 1.5);
+        assert_eq!(cpu.some.avg60, 0.75);
+        assert_eq!(cpu.some.total_usec, 123456);
+        assert_eq!(cpu.full.unwrap().avg10, 0.0);
+        assert_eq!(stats.memory_pressure.unwrap().full.unwrap().avg10, 8.25);
+        // PSI disabled (psi=0) leaves the file out
+        assert_eq!(stats.io_pressure, None);
+        assert_eq!(
+            stats.memory_stat,
+            Some(MemoryBreakdown { anon: 10 << 20, file: 5 << 20, kernel: 1 << 20, sock: 4096 })
+        );
+        assert_eq!(stats.memory_events, Some(MemoryEvents { oom: 1, oom_kill: 1, high: 3, max: 1 }));
+
+        // Stopped unit or cgroup v1: no directory
+        assert!(read_cgroup(root, "/system.slice/gone.service").is_err());
+        assert!(read_cgroup(root, "").is_err());
+
+        // Older kernels: kernel total summed from its parts
+        let stat = parse_memory_stat("anon 1\nkernel_stack 100\npagetables 20\npercpu 3\nslab 400\n");
+        assert_eq!(stat.kernel, 523);
+
+        // cpu.pressure before 5.13 has no "full" line
+        let pressure = parse_pressure("some avg10=0.00 avg60=0.00 avg300=0.00 total=42\n").unwrap();
+        assert_eq!(pressure.full, None);
+        assert_eq!(pressure.some.total_usec, 42);
+        assert_eq!(parse_pressure(""), None);
+        assert_eq!(parse_pressure("some avg10=x\n"), None);
+    }
+
+    #[test]
+    fn test_unit_file_state_lookup() {
+        use crate::systemd::client::{needs_unit_file_lookup, unit_file_state_for};
+        use crate::systemd::models::is_enabled_state;
+
+        let states: std::collections::HashMap<String, String> = [
+            ("nginx.service", "enabled"),
+            ("getty@.service", "enabled"),
+            ("cups.service", "disabled"),
+            ("dbus.service", "static"),
+        ]
+        .into_iter()
+        .map(|(name, state)| (name.to_string(), state.to_string()))
+        .collect();
+
+        assert_eq!(unit_file_state_for(&states, "nginx.service"), Some("enabled"));
+        assert_eq!(unit_file_state_for(&states, "cups.service"), Some("disabled"));
+        // Instances are not resolved through their template
+        assert_eq!(unit_file_state_for(&states, "getty@tty1.service"), None);
+        // Transient units have no unit file
+        assert_eq!(unit_file_state_for(&states, "run-u42.service"), None);
+
+        // Only instances get a per-unit GetUnitFileState call
+        assert!(needs_unit_file_lookup(&states, "getty@tty1.service"));
+        assert!(!needs_unit_file_lookup(&states, "run-u42.service"));
+        assert!(!needs_unit_file_lookup(&states, "session-3.scope"));
+        assert!(!needs_unit_file_lookup(&states, "nginx.service"));
+
+        assert!(is_enabled_state("enabled"));
+        assert!(is_enabled_state("enabled-runtime"));
+        assert!(!is_enabled_state("static"));
+        assert!(!is_enabled_state("masked"));
+        assert!(!is_enabled_state(""));
+    }
+
+    #[test]
+    fn test_not_loaded_service_row() {
+        use crate::systemd::client::not_loaded_service;
+
+        let service = not_loaded_service("backup.timer", "enabled", ServiceScope::User);
+        assert_eq!(service.id, "user:backup.timer");
+        assert_eq!(service.kind, UnitKind::Timer);
+        assert_eq!(service.description, "(not loaded)");
+        assert_eq!(service.load_state, "not-loaded");
+        assert!(!service.is_active());
+        assert!(service.enabled);
+    }
+
+    #[test]
+    fn test_unit_kind_from_name() {
+        assert_eq!(UnitKind::from_unit_name("nginx.service"), Some(UnitKind::Service));
+        assert_eq!(UnitKind::from_unit_name("home.mount"), Some(UnitKind::Mount));
+        assert_eq!(UnitKind::from_unit_name("proc-sys-fs-binfmt_misc.automount"), Some(UnitKind::Automount));
+        assert_eq!(UnitKind::from_unit_name("user-1000.slice"), Some(UnitKind::Slice));
+        assert_eq!(UnitKind::from_unit_name("getty@tty1.service"), Some(UnitKind::Service));
+        assert_eq!(UnitKind::from_unit_name("sys-devices-foo.device"), Some(UnitKind::Device));
+        assert_eq!(UnitKind::from_unit_name("noextension"), None);
+        assert_eq!(UnitKind::from_unit_name("archive.tar"), None);
+
+        assert!(UnitKind::Target.is_listed());
+        assert!(!UnitKind::Device.is_listed());
+        assert!(UnitKind::Service.detail_properties().is_empty());
+        assert!(UnitKind::Mount.detail_properties().contains(&"Where"));
+
+        // Only cgroup-backed units can be frozen
+        assert!(UnitKind::Service.can_freeze());
+        assert!(UnitKind::Slice.can_freeze());
+        assert!(!UnitKind::Timer.can_freeze());
+    }
+
+    #[test]
+    fn test_resource_limit_parsing() {
+        use crate::systemd::ResourceLimit;
+
+        assert_eq!(ResourceLimit::MemoryMax.parse("512M").unwrap(), 512 * 1024 * 1024);
+        assert_eq!(ResourceLimit::MemoryHigh.parse("2g").unwrap(), 2 * 1024 * 1024 * 1024);
+        assert_eq!(ResourceLimit::MemoryMax.parse("infinity").unwrap(), u64::MAX);
+        assert!(ResourceLimit::MemoryMax.parse("lots").is_err());
+
+        // 100% is one second of CPU time per second
+        assert_eq!(ResourceLimit::CPUQuota.parse("150%").unwrap(), 1_500_000);
+        assert_eq!(ResourceLimit::CPUQuota.property(), "CPUQuotaPerSecUSec");
+        assert!(ResourceLimit::CPUQuota.parse("150").is_err());
+        assert_eq!(ResourceLimit::CPUQuota.parse("12.5%").unwrap(), 125_000);
+        assert!(ResourceLimit::CPUQuota.parse("0.00001%").is_err());
+
+        assert_eq!(ResourceLimit::CPUWeight.parse("default").unwrap(), u64::MAX);
+        assert!(ResourceLimit::IOWeight.parse("0").is_err());
+        assert!(ResourceLimit::IOWeight.parse("10001").is_err());
+        assert_eq!(ResourceLimit::TasksMax.parse("4096").unwrap(), 4096);
+
+        // Formatting round-trips through parse
+        for (limit, value) in [
+            (ResourceLimit::MemoryMax, 768 * 1024 * 1024),
+            (ResourceLimit::MemoryHigh, 1_000_000),
+            (ResourceLimit::CPUQuota, 500_000),
+            (ResourceLimit::CPUQuota, 255_000),
+            (ResourceLimit::CPUQuota, 1_234),
+            (ResourceLimit::CPUWeight, u64::MAX),
+            (ResourceLimit::TasksMax, u64::MAX),
+        ] {
+            assert_eq!(limit.parse(&limit.format(value)).unwrap(), value);
+        }
+        assert_eq!(ResourceLimit::MemoryMax.format(768 * 1024 * 1024), "768M");
+        assert_eq!(ResourceLimit::CPUQuota.format(255_000), "25.5%");
+    }
+
+    #[test]
+    fn test_dropin_validation() {
+        use crate::diff::{diff_lines, diff_stats, DiffLine};
+        use crate::systemd::dropin::{is_effectively_empty, override_path};
+        use crate::systemd::lint::lint_unit;
+
+        // Drop-ins are checked by the unit linter; only errors block a save
+        let errors = |content: &str| lint_unit(content, None, true).into_iter().filter(|i| i.is_error()).collect::<Vec<_>>();
+        assert!(errors("[Service]\nEnvironment=DEBUG=1\n# comment\n\nLimitNOFILE=65536\n").is_empty());
+        assert!(errors("[Unit]\nX-Custom-Key=1\n").is_empty());
+        assert!(!errors("Environment=DEBUG=1\n").is_empty());
+        assert!(!errors("[Servce]\nType=simple\n").is_empty());
+        assert!(!errors("[Service\n").is_empty());
+        let issues = errors("[Service]\nType simple\n");
+        assert!(issues[0].to_string().starts_with("Line 2:"));
+
+        assert!(is_effectively_empty("# nothing here\n\n; still nothing\n"));
+        assert!(!is_effectively_empty("[Service]\n"));
+
+        assert_eq!(
+            override_path("nginx.service", ServiceScope::System).unwrap(),
+            std::path::PathBuf::from("/etc/systemd/system/nginx.service.d/override.conf")
+        );
+
+        // Files later installed as root live in a 0700 directory, created 0600
+        use std::os::unix::fs::PermissionsExt;
+        let (dir, path) = crate::systemd::dropin::private_temp_file("nginx.service-override.conf", "[Service]\n").unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "[Service]\n");
+        assert_eq!(std::fs::metadata(dir.path()).unwrap().permissions().mode() & 0o777, 0o700);
+        assert_eq!(std::fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+        let dir_path = dir.path().to_path_buf();
+        drop(dir);
+        assert!(!dir_path.exists());
+
+        let lines = diff_lines("[Service]\nNice=5\n", "[Service]\nNice=10\nUser=www\n");
+        assert_eq!(lines[0], DiffLine::Same("[Service]".to_string()));
+        assert!(lines.contains(&DiffLine::Removed("Nice=5".to_string())));
+        assert!(lines.contains(&DiffLine::Added("User=www".to_string())));
+        assert_eq!(diff_stats(&lines), (2, 1));
+        assert!(diff_lines("a\nb\n", "a\nb\n").iter().all(|l| !l.is_change()));
+    }
+
+    #[tokio::test]
+    async fn test_install_file_mode() {
+        use crate::systemd::dropin::{install_file, private_temp_file};
+        use std::os::unix::fs::PermissionsExt;
+
+        // An installed unit is world-readable even though its source was 0600
+        let (_source_dir, source) = private_temp_file("backup.service", "[Service]\n").unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let target = dir.path().join("user").join("backup.service");
+        install_file(&source, &target, ServiceScope::User).await.unwrap();
+        assert_eq!(std::fs::read_to_string(&target).unwrap(), "[Service]\n");
+        assert_eq!(std::fs::metadata(&target).unwrap().permissions().mode() & 0o777, 0o644);
+    }
+
+    #[test]
+    fn test_remove_unit_companion_timer() {
+        use crate::systemd::dropin::companion_timer_in;
+
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("backup.service"), "[Service]\n").unwrap();
+        assert_eq!(companion_timer_in(dir.path(), "backup.service"), None);
+
+        std::fs::write(dir.path().join("backup.timer"), "[Timer]\n").unwrap();
+        assert_eq!(companion_timer_in(dir.path(), "backup.service"), Some("backup.timer".to_string()));
+        // Only services have a companion timer
+        assert_eq!(companion_timer_in(dir.path(), "backup.timer"), None);
+    }
+
+    #[test]
+    fn test_vendor_diff() {
+        use crate::diff::{diff_lines, unified, DiffLine};
+        use crate::systemd::unit_file::shadowed_vendor_copy;
+
+        // Two changes far apart make two hunks with one line of context
+        let old = "a\nb\nc\nd\ne\nf\ng\nh\n";
+        let new = "a\nB\nc\nd\ne\nf\ng\nh\ni\n";
+        let hunks = unified(&diff_lines(old, new), 1);
+        let headers: Vec<&str> = hunks
+            .iter()
+            .filter(|l| matches!(l, DiffLine::Hunk(_)))
+            .map(|l| l.text())
+            .collect();
+        assert_eq!(headers, vec!["@@ -1,3 +1,3 @@", "@@ -8,1 +8,2 @@"]);
+        assert!(!hunks.contains(&DiffLine::Same("e".to_string())));
+        assert!(unified(&diff_lines(old, old), 3).is_empty());
+
+        // Only fragments in the administrator directory can shadow a vendor copy
+        assert_eq!(shadowed_vendor_copy("/usr/lib/systemd/system/ssh.service", ServiceScope::System), None);
+        assert_eq!(shadowed_vendor_copy("/etc/systemd/system/sysprox-none.service", ServiceScope::System), None);
+    }
+
+    #[test]
+    fn test_unit_file_effective_config() {
+        use crate::systemd::unit_file::{effective_config, overridden_lines, UnitFile};
+
+        let file = |path: &str, content: &str| UnitFile {
+            path: path.to_string(),
+            content: content.to_string(),
+            error: None,
+        };
+        let files = vec![
+            file(
+                "/usr/lib/systemd/system/web.service",
+                "[Unit]\nAfter=network.target\n\n[Service]\nExecStart=/usr/bin/web \\\n  --port 80\nUser=web\nNice=5\n",
+            ),
+            file(
+                "/etc/systemd/system/web.service.d/override.conf",
+                "[Unit]\nAfter=db.service\n[Service]\nExecStart=\nExecStart=/usr/bin/web --port 8080\nUser=www\nNice=\n",
+            ),
+        ];
+        assert!(!files[0].is_drop_in());
+        assert!(files[1].is_drop_in());
+        assert_eq!(files[1].short_name(), "web.service.d/override.conf");
+
+        let settings = effective_config(&files);
+        let get = |key: &str| settings.iter().find(|s| s.key == key).unwrap();
+        let values = |key: &str| get(key).values.iter().map(|a| a.value.clone()).collect::<Vec<_>>();
+
+        // Lists accumulate, single values are replaced, `Key=` resets
+        assert_eq!(values("After"), vec!["network.target", "db.service"]);
+        assert_eq!(values("ExecStart"), vec!["/usr/bin/web --port 8080"]);
+        assert_eq!(get("ExecStart").overridden[0].value, "/usr/bin/web --port 80");
+        assert_eq!(values("User"), vec!["www"]);
+        assert!(values("Nice").is_empty());
+
+        // Overridden fragment lines point at the drop-in
+        let overridden = overridden_lines(&settings);
+        assert_eq!(overridden.get(&(0, 4)), Some(&1)); // ExecStart
+        assert_eq!(overridden.get(&(0, 6)), Some(&1)); // User
+        assert_eq!(overridden.get(&(0, 1)), None); // After still applies
+
+        assert_eq!(UnitFile::read_all("", &[]), Vec::new());
+        assert!(UnitFile::read("/nonexistent/unit.service").error.is_some());
+    }
+
+    #[test]
+    fn test_unit_linter() {
+        use crate::systemd::lint::{has_errors, lint_unit, parse_environment, validate_unit_name, Severity};
+
+        let good = "[Unit]\nDescription=Web\nConditionPathExists=/etc\n\n[Service]\nType=notify\nExecStart=/bin/sh -c 'exec web'\nRestart=on-failure\nEnvironment=\"A=1 2\" B=3\n\n[Install]\nWantedBy=default.target\n";
+        assert_eq!(lint_unit(good, Some(UnitKind::Service), false), Vec::new());
+
+        let bad = "[Service]\nType=daemon\nRestart=sometimes\nExecStart=bin/web\nEnvironment=\"A=1\nWorkingDirectory=srv\nFrobnicate=yes\n[Timer]\n";
+        let issues = lint_unit(bad, Some(UnitKind::Service), false);
+        let messages: Vec<String> = issues.iter().map(|i| i.to_string()).collect();
+        assert!(messages[0].starts_with("Line 2: invalid Type=daemon"));
+        assert!(messages[1].starts_with("Line 3: invalid Restart=sometimes"));
+        assert_eq!(messages[2], "Line 4: relative path 'bin/web' in ExecStart=, use an absolute path");
+        assert_eq!(messages[3], "Line 5: unterminated quote in Environment=");
+        assert!(messages[4].starts_with("Line 6: WorkingDirectory must be absolute"));
+        assert_eq!(issues[5].severity, Severity::Warning);
+        assert_eq!(messages[5], "Line 7: unknown setting Frobnicate= in [Service]");
+        assert_eq!(messages[6], "Line 8: unknown section [Timer]");
+        assert!(has_errors(&issues));
+
+        // Missing executables warn; a unit without ExecStart= is an error
+        let issues = lint_unit("[Service]\nExecStart=/nonexistent/web\n", Some(UnitKind::Service), false);
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].severity, Severity::Warning);
+        assert!(has_errors(&lint_unit("[Service]\nType=simple\n", Some(UnitKind::Service), false)));
+        assert!(lint_unit("[Service]\nExecStart=\nNice=5\n", Some(UnitKind::Service), true).is_empty());
+        assert!(has_errors(&lint_unit("[Timer]\nPersistent=true\n", Some(UnitKind::Timer), false)));
+
+        assert_eq!(parse_environment("A=1 'B=two words' C=\"x\\\"y\"").unwrap(), vec!["A=1", "B=two words", "C=x\"y"]);
+        assert!(parse_environment("1A=x").is_err());
+        assert!(parse_environment("NOVALUE").is_err());
+
+        assert!(validate_unit_name("web.service").is_ok());
+        assert!(validate_unit_name("getty@tty1.service").is_ok());
+        assert!(validate_unit_name("getty@.service").is_ok());
+        assert!(validate_unit_name("web").is_err());
+        assert!(validate_unit_name("web.daemon").is_err());
+        assert!(validate_unit_name("we b.service").is_err());
+        assert!(validate_unit_name("@x.service").is_err());
+        assert!(validate_unit_name("a@b@c.service").is_err());
+        assert!(validate_unit_name(&format!("{}.service", "a".repeat(250))).is_err());
+
+        use crate::systemd::lint::is_time_span;
+        for span in ["5", "500ms", "1min 30s", "2 h", "infinity"] {
+            assert!(is_time_span(span), "{}", span);
+        }
+        for span in ["", "soon", "5 parsecs", "min"] {
+            assert!(!is_time_span(span), "{}", span);
+        }
+    }
+
+    #[test]
+    fn test_transient_spec_helpers() {
+        use crate::systemd::lint::{parse_time_span, split_words};
+        use crate::systemd::{TransientKind, TransientSpec};
+
+        assert_eq!(parse_time_span("90"), Some(90_000_000));
+        assert_eq!(parse_time_span("1min 30s"), Some(90_000_000));
+        assert_eq!(parse_time_span("500ms"), Some(500_000));
+        assert_eq!(parse_time_span("infinity"), Some(u64::MAX));
+
+        assert_eq!(split_words("echo 'a b' \"c\\\"d\"").unwrap(), vec!["echo", "a b", "c\"d"]);
+        assert!(split_words("echo 'open").is_err());
+
+        let argv = TransientSpec::command("sh -c 'sleep 1'").unwrap();
+        assert!(argv[0].starts_with('/') && argv[0].ends_with("/sh"));
+        assert_eq!(&argv[1..], ["-c", "sleep 1"]);
+        assert!(TransientSpec::command("./local-script").is_err());
+        assert!(TransientSpec::command("no-such-sysprox-command").is_err());
+        assert!(TransientSpec::command("   ").is_err());
+
+        assert_eq!(TransientSpec::unit_name("backup", TransientKind::Service).unwrap(), "backup.service");
+        assert_eq!(TransientSpec::unit_name("backup.scope", TransientKind::Scope).unwrap(), "backup.scope");
+        assert!(TransientSpec::unit_name("", TransientKind::Scope).unwrap().starts_with("run-sysprox-"));
+        assert!(TransientSpec::unit_name("bad name", TransientKind::Service).is_err());
+    }
+
+    #[test]
+    fn test_job_result_parsing() {
+        use crate::systemd::JobResult;
+
+        assert_eq!(JobResult::from_result("done"), JobResult::Done);
+        assert_eq!(JobResult::from_result("failed"), JobResult::Failed);
+        assert_eq!(JobResult::from_result("timeout"), JobResult::Timeout);
+        assert_eq!(JobResult::from_result("dependency"), JobResult::Dependency);
+        assert_eq!(JobResult::from_result("canceled"), JobResult::Canceled);
+        assert_eq!(JobResult::from_result("skipped"), JobResult::Skipped);
+        assert_eq!(JobResult::from_result("assert"), JobResult::Other("assert".to_string()));
+
+        assert!(JobResult::Done.is_success());
+        assert!(!JobResult::Skipped.is_success());
+        assert!(!JobResult::Skipped.is_failure());
+        assert!(JobResult::Timeout.is_failure());
+        assert!(JobResult::Other("invalid".to_string()).is_failure());
+    }
+
+    #[test]
+    fn test_usec_helpers() {
+        use crate::systemd::models::{format_usec_span, timestamp_from_usec};
+
+        assert_eq!(timestamp_from_usec(0), None);
+        assert_eq!(timestamp_from_usec(u64::MAX), None);
+        let ts = timestamp_from_usec(1_700_000_000_500_000).unwrap();
+        assert_eq!(ts.timestamp(), 1_700_000_000);
+        assert_eq!(ts.timestamp_subsec_millis(), 500);
+
+        assert_eq!(format_usec_span(0), "0");
+        assert_eq!(format_usec_span(15 * 60 * 1_000_000), "15min");
+        assert_eq!(format_usec_span((24 * 3600 + 90) * 1_000_000), "1d 1min 30s");
+        assert_eq!(format_usec_span(250), "250us");
+    }
+
     #[test]
     fn test_service_helper_methods() {
         let service = Service {
@@ -176,6 +688,8 @@ mod tests {
             pid: 1234,
             enabled: true,
             scope: ServiceScope::System,
+            kind: UnitKind::Service,
+            freezer_state: String::new(),
             loaded_at: chrono::Utc::now(),
         };
 
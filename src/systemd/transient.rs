@@ -0,0 +1,84 @@
+// Transient units - ad-hoc services and scopes started like `systemd-run`
+
+use crate::error::Result;
+use crate::systemd::lint::{split_words, validate_unit_name};
+use std::path::Path;
+
+/// What kind of transient unit to create
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransientKind {
+    /// systemd starts the command; output goes to the journal
+    Service,
+    /// sysprox starts the command and systemd adopts the process
+    Scope,
+}
+
+impl TransientKind {
+    pub fn suffix(&self) -> &'static str {
+        match self {
+            TransientKind::Service => "service",
+            TransientKind::Scope => "scope",
+        }
+    }
+}
+
+/// Everything needed for one StartTransientUnit call
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransientSpec {
+    /// Full unit name, e.g. "run-sysprox-1700000000.service"
+    pub name: String,
+    pub kind: TransientKind,
+    /// Command with an absolute executable path first
+    pub argv: Vec<String>,
+    pub description: String,
+    /// Empty means the manager's default slice
+    pub slice: String,
+    pub memory_max: Option<u64>,
+    /// CPUQuotaPerSecUSec
+    pub cpu_quota: Option<u64>,
+    pub runtime_max_usec: Option<u64>,
+}
+
+impl TransientSpec {
+    /// Full unit name from the given one, adding the suffix or generating a name
+    pub fn unit_name(given: &str, kind: TransientKind) -> Result<String> {
+        let name = match given.trim() {
+            "" => default_unit_name(kind),
+            given if given.ends_with(&format!(".{}", kind.suffix())) => given.to_string(),
+            given => format!("{}.{}", given, kind.suffix()),
+        };
+        validate_unit_name(&name).map_err(|e| anyhow::anyhow!(e))?;
+        Ok(name)
+    }
+
+    /// Split a command line and resolve its executable
+    pub fn command(line: &str) -> Result<Vec<String>> {
+        let mut argv = split_words(line).map_err(|e| anyhow::anyhow!("Command: {}", e))?;
+        let program = argv.first().ok_or_else(|| anyhow::anyhow!("Command is required"))?;
+        argv[0] = resolve_executable(program)?;
+        Ok(argv)
+    }
+}
+
+/// Unit name used when none is given, unique per second like systemd-run's
+pub fn default_unit_name(kind: TransientKind) -> String {
+    format!("run-sysprox-{}.{}", chrono::Utc::now().timestamp(), kind.suffix())
+}
+
+/// Find a command in $PATH; ExecStart= over D-Bus needs an absolute path
+pub fn resolve_executable(program: &str) -> Result<String> {
+    if program.contains('/') {
+        let path = Path::new(program);
+        if !path.is_absolute() {
+            return Err(anyhow::anyhow!("Use an absolute path or a command in $PATH, not '{}'", program));
+        }
+        return Ok(program.to_string());
+    }
+
+    let search = std::env::var("PATH").unwrap_or_else(|_| "/usr/local/bin:/usr/bin:/bin".to_string());
+    std::env::split_paths(&search)
+        .map(|dir| dir.join(program))
+        .find(|candidate| candidate.is_file())
+        .map(|found| found.to_string_lossy().into_owned())
+        .ok_or_else(|| anyhow::anyhow!("Command '{}' not found in $PATH", program))
+}
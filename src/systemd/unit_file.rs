@@ -0,0 +1,238 @@
+// Unit files - read a unit's fragment and drop-ins and merge them like systemd
+
+use crate::systemd::{dropin, ServiceScope};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Settings that build up a list instead of replacing the previous value;
+/// an empty assignment (`Key=`) clears the list
+const ACCUMULATING_KEYS: &[&str] = &[
+    "After", "Before", "Wants", "Requires", "Requisite", "BindsTo", "PartOf", "Upholds", "Conflicts",
+    "OnFailure", "OnSuccess", "WantedBy", "RequiredBy", "UpheldBy", "Also", "Alias",
+    "ExecStartPre", "ExecStart", "ExecStartPost", "ExecCondition", "ExecReload", "ExecStop", "ExecStopPost",
+    "Environment", "EnvironmentFile", "PassEnvironment", "UnsetEnvironment",
+    "ReadWritePaths", "ReadOnlyPaths", "InaccessiblePaths", "BindPaths", "BindReadOnlyPaths",
+    "ListenStream", "ListenDatagram", "ListenSequentialPacket", "ListenFIFO",
+    "OnCalendar", "OnActiveSec", "OnBootSec", "OnStartupSec", "OnUnitActiveSec", "OnUnitInactiveSec",
+    "PathExists", "PathExistsGlob", "PathChanged", "PathModified", "DirectoryNotEmpty",
+    "ConditionPathExists", "AssertPathExists", "SupplementaryGroups", "DeviceAllow",
+];
+
+/// Directories packages install unit files into, in lookup order
+pub fn vendor_dirs(scope: ServiceScope) -> &'static [&'static str] {
+    match scope {
+        ServiceScope::System => &["/usr/lib/systemd/system", "/lib/systemd/system"],
+        ServiceScope::User => &["/usr/lib/systemd/user"],
+    }
+}
+
+/// Vendor copy hidden by a fragment in the administrator directory
+///
+/// e.g. /etc/systemd/system/foo.service shadows /usr/lib/systemd/system/foo.service.
+pub fn shadowed_vendor_copy(fragment: &str, scope: ServiceScope) -> Option<String> {
+    let fragment = Path::new(fragment);
+    if !fragment.starts_with(dropin::unit_dir(scope).ok()?) {
+        return None;
+    }
+    let name = fragment.file_name()?;
+
+    vendor_dirs(scope)
+        .iter()
+        .map(|dir| Path::new(dir).join(name))
+        .find(|path| path.is_file())
+        .map(|path| path.to_string_lossy().into_owned())
+}
+
+/// One file making up a unit: the fragment or one of its drop-ins
+#[derive(Debug, Clone, PartialEq)]
+pub struct UnitFile {
+    pub path: String,
+    pub content: String,
+    /// Set when the file could not be read (content is then empty)
+    pub error: Option<String>,
+}
+
+impl UnitFile {
+    pub fn read(path: &str) -> Self {
+        match std::fs::read_to_string(path) {
+            Ok(content) => Self {
+                path: path.to_string(),
+                content,
+                error: None,
+            },
+            Err(e) => Self {
+                path: path.to_string(),
+                content: String::new(),
+                error: Some(e.to_string()),
+            },
+        }
+    }
+
+    /// Read the fragment followed by its drop-ins, skipping empty paths
+    pub fn read_all(fragment: &str, drop_ins: &[String]) -> Vec<Self> {
+        std::iter::once(fragment)
+            .chain(drop_ins.iter().map(String::as_str))
+            .filter(|path| !path.is_empty())
+            .map(Self::read)
+            .collect()
+    }
+
+    pub fn is_drop_in(&self) -> bool {
+        self.path.ends_with(".conf")
+            && Path::new(&self.path)
+                .parent()
+                .and_then(|dir| dir.extension())
+                .is_some_and(|ext| ext == "d")
+    }
+
+    /// Short name: "foo.service", or "foo.service.d/override.conf" for drop-ins
+    pub fn short_name(&self) -> String {
+        let path = Path::new(&self.path);
+        let file = path.file_name().map(|f| f.to_string_lossy().into_owned()).unwrap_or_default();
+        if self.is_drop_in() {
+            let dir = path
+                .parent()
+                .and_then(|dir| dir.file_name())
+                .map(|d| d.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            format!("{}/{}", dir, file)
+        } else {
+            file
+        }
+    }
+}
+
+/// Classification of a single unit file line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitLine<'a> {
+    Blank,
+    Comment(&'a str),
+    Section(&'a str),
+    Setting { key: &'a str, value: &'a str },
+    Invalid(&'a str),
+}
+
+/// Classify one line of a unit file
+pub fn parse_line(line: &str) -> UnitLine<'_> {
+    let trimmed = line.trim();
+    if trimmed.is_empty() {
+        UnitLine::Blank
+    } else if trimmed.starts_with('#') || trimmed.starts_with(';') {
+        UnitLine::Comment(trimmed)
+    } else if let Some(name) = trimmed.strip_prefix('[').and_then(|rest| rest.strip_suffix(']')) {
+        UnitLine::Section(name)
+    } else if let Some((key, value)) = trimmed.split_once('=') {
+        UnitLine::Setting {
+            key: key.trim(),
+            value: value.trim(),
+        }
+    } else {
+        UnitLine::Invalid(trimmed)
+    }
+}
+
+/// One assignment of a setting: which file and line it came from
+#[derive(Debug, Clone, PartialEq)]
+pub struct Assignment {
+    /// Index into the unit's files
+    pub file: usize,
+    /// Zero-based line number within the file
+    pub line: usize,
+    pub value: String,
+    /// File whose later assignment replaced or reset this one
+    pub overridden_by: Option<usize>,
+}
+
+/// A setting after all files have been applied
+#[derive(Debug, Clone, PartialEq)]
+pub struct EffectiveSetting {
+    pub section: String,
+    pub key: String,
+    /// Assignments in effect; empty if the setting was reset to its default
+    pub values: Vec<Assignment>,
+    /// Earlier assignments replaced by a later file
+    pub overridden: Vec<Assignment>,
+}
+
+impl EffectiveSetting {
+    pub fn accumulates(&self) -> bool {
+        ACCUMULATING_KEYS.contains(&self.key.as_str())
+    }
+}
+
+/// Merge the files in order, as systemd does when loading a unit
+///
+/// Settings keep the order in which they first appear.
+pub fn effective_config(files: &[UnitFile]) -> Vec<EffectiveSetting> {
+    let mut settings: Vec<EffectiveSetting> = Vec::new();
+    let mut index: HashMap<(String, String), usize> = HashMap::new();
+
+    for (file_idx, file) in files.iter().enumerate() {
+        let mut section = String::new();
+        let mut lines = file.content.lines().enumerate();
+
+        while let Some((line_no, line)) = lines.next() {
+            let (key, value) = match parse_line(line) {
+                UnitLine::Section(name) => {
+                    section = name.to_string();
+                    continue;
+                }
+                UnitLine::Setting { key, value } => (key, value),
+                _ => continue,
+            };
+
+            // Backslash continues the value on the next line
+            let mut value = value.to_string();
+            while value.ends_with('\\') {
+                value.pop();
+                value.truncate(value.trim_end().len());
+                match lines.next() {
+                    Some((_, next)) => {
+                        value.push(' ');
+                        value.push_str(next.trim());
+                    }
+                    None => break,
+                }
+            }
+            let value = value.trim().to_string();
+
+            let idx = *index.entry((section.clone(), key.to_string())).or_insert_with(|| {
+                settings.push(EffectiveSetting {
+                    section: section.clone(),
+                    key: key.to_string(),
+                    values: Vec::new(),
+                    overridden: Vec::new(),
+                });
+                settings.len() - 1
+            });
+            let setting = &mut settings[idx];
+
+            // Single-valued settings are replaced; lists are only cleared by `Key=`
+            if value.is_empty() || !setting.accumulates() {
+                for mut previous in setting.values.drain(..) {
+                    previous.overridden_by = Some(file_idx);
+                    setting.overridden.push(previous);
+                }
+            }
+            if !value.is_empty() {
+                setting.values.push(Assignment {
+                    file: file_idx,
+                    line: line_no,
+                    value,
+                    overridden_by: None,
+                });
+            }
+        }
+    }
+
+    settings
+}
+
+/// Map (file, line) of every overridden assignment to the file that overrode it
+pub fn overridden_lines(settings: &[EffectiveSetting]) -> HashMap<(usize, usize), usize> {
+    settings
+        .iter()
+        .flat_map(|setting| setting.overridden.iter())
+        .filter_map(|a| a.overridden_by.map(|by| ((a.file, a.line), by)))
+        .collect()
+}
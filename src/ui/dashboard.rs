@@ -1,14 +1,18 @@
 // Dashboard view - service list
 
 use crate::events::{Action, FilterAction};
-use crate::systemd::{Service, ServiceScope};
-use crate::ui::{state_color, status_emoji};
+use crate::systemd::{Service, ServiceScope, UnitKind, UnitResources};
+use crate::ui::run::RunDialog;
+use crate::ui::tabs::{tab_title, ListTab};
+use crate::ui::{freezer_color, unit_emoji, unit_state_color, FROZEN_EMOJI};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Modifier, Style},
     widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
     Frame,
 };
+use std::cmp::Ordering;
+use std::collections::HashMap;
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum FilterType {
@@ -54,14 +58,100 @@ impl ScopeFilter {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum UnitKindFilter {
+    All,
+    Kind(UnitKind),
+}
+
+impl UnitKindFilter {
+    pub fn label(&self) -> &'static str {
+        match self {
+            UnitKindFilter::All => "All",
+            UnitKindFilter::Kind(kind) => kind.label(),
+        }
+    }
+
+    /// Cycle service -> all -> every other listed kind -> service
+    pub fn next(&self) -> Self {
+        match self {
+            UnitKindFilter::All => UnitKindFilter::Kind(UnitKind::LISTED[1]),
+            UnitKindFilter::Kind(kind) => {
+                let idx = UnitKind::LISTED.iter().position(|k| k == kind).unwrap_or(0);
+                if idx == 0 {
+                    return UnitKindFilter::All;
+                }
+                UnitKindFilter::Kind(*UnitKind::LISTED.get(idx + 1).unwrap_or(&UnitKind::LISTED[0]))
+            }
+        }
+    }
+}
+
+/// Column the service table is sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortField {
+    Name,
+    Cpu,
+    Memory,
+    Tasks,
+    Uptime,
+}
+
+impl SortField {
+    pub fn label(&self) -> &'static str {
+        match self {
+            SortField::Name => "Name",
+            SortField::Cpu => "CPU",
+            SortField::Memory => "Memory",
+            SortField::Tasks => "Tasks",
+            SortField::Uptime => "Uptime",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            SortField::Name => SortField::Cpu,
+            SortField::Cpu => SortField::Memory,
+            SortField::Memory => SortField::Tasks,
+            SortField::Tasks => SortField::Uptime,
+            SortField::Uptime => SortField::Name,
+        }
+    }
+}
+
+/// Resource columns and sort order; carried over when the dashboard is rebuilt
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ColumnSettings {
+    /// Show the CPU, memory, tasks and uptime columns
+    pub show_resources: bool,
+    pub sort: SortField,
+    pub sort_descending: bool,
+}
+
+impl Default for ColumnSettings {
+    fn default() -> Self {
+        Self {
+            show_resources: false,
+            sort: SortField::Name,
+            sort_descending: false,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct DashboardState {
     pub services: Vec<Service>,
     pub filter: FilterType,
     pub scope_filter: ScopeFilter,
+    pub kind_filter: UnitKindFilter,
     pub search_term: String,
     pub table_state: TableState,
     pub searching: bool,
+    /// Transient unit dialog; takes raw key input while open
+    pub run_dialog: Option<RunDialog>,
+    pub columns: ColumnSettings,
+    /// Latest resource usage of active units, keyed by Service id
+    pub resources: HashMap<String, UnitResources>,
 }
 
 impl Default for DashboardState {
@@ -79,21 +169,84 @@ impl DashboardState {
             services: Vec::new(),
             filter: FilterType::All,
             scope_filter: ScopeFilter::All,
+            kind_filter: UnitKindFilter::Kind(UnitKind::Service),
             search_term: String::new(),
             table_state,
             searching: false,
+            run_dialog: None,
+            columns: ColumnSettings::default(),
+            resources: HashMap::new(),
         }
     }
 
     pub fn set_services(&mut self, mut services: Vec<Service>) {
         // Sort services alphabetically by name
         services.sort_by(|a, b| a.name.cmp(&b.name));
+
+        // The listing has no FreezerState; keep what the unit watcher last reported
+        for service in services.iter_mut().filter(|s| s.freezer_state.is_empty() && s.is_active()) {
+            if let Some(previous) = self.services.iter().find(|p| p.id == service.id) {
+                service.freezer_state = previous.freezer_state.clone();
+            }
+        }
+
         self.services = services;
 
         // Smart selection: maintain current selection if possible, otherwise select first
         self.smart_select();
     }
 
+    /// Insert or replace a single service in place, keeping sort order
+    pub fn upsert_service(&mut self, service: Service) {
+        match self.services.iter().position(|s| s.id == service.id) {
+            Some(idx) => self.services[idx] = service,
+            None => {
+                let idx = self
+                    .services
+                    .partition_point(|s| s.name < service.name);
+                self.services.insert(idx, service);
+            }
+        }
+
+        self.smart_select();
+    }
+
+    /// Remove a single service by id
+    pub fn remove_service(&mut self, id: &str) {
+        self.services.retain(|s| s.id != id);
+        self.smart_select();
+    }
+
+    /// Replace the resource samples, keeping the selected unit selected
+    pub fn set_resources(&mut self, resources: HashMap<String, UnitResources>) {
+        self.keep_selection(|state| state.resources = resources);
+    }
+
+    /// Units whose resources the columns need: active units in the current list
+    pub fn resource_targets(&self) -> Vec<(String, ServiceScope)> {
+        if !self.columns.show_resources {
+            return Vec::new();
+        }
+        self.filtered_services()
+            .into_iter()
+            .filter(|s| s.is_active() && s.kind.has_cgroup())
+            .map(|s| (s.name.clone(), s.scope))
+            .collect()
+    }
+
+    /// Apply a change that may reorder the list, following the selected unit
+    fn keep_selection(&mut self, change: impl FnOnce(&mut Self)) {
+        let selected_id = self.get_selected_service().map(|s| s.id.clone());
+        change(self);
+        if let Some(id) = selected_id {
+            if let Some(idx) = self.filtered_services().iter().position(|s| s.id == id) {
+                self.table_state.select(Some(idx));
+                return;
+            }
+        }
+        self.smart_select();
+    }
+
     pub fn smart_select(&mut self) {
         if self.services.is_empty() {
             return;
@@ -117,7 +270,7 @@ impl DashboardState {
         self.table_state.select(Some(0));
     }
 
-    pub fn handle_action(&mut self, action: Action) -> Option<String> {
+    pub fn handle_action(&mut self, action: Action) -> Option<(String, ServiceScope)> {
         match action {
             Action::MoveUp => {
                 self.move_selection(-1);
@@ -153,6 +306,39 @@ impl DashboardState {
                 self.smart_select();
                 None
             }
+            Action::CycleUnitKind => {
+                self.kind_filter = self.kind_filter.next();
+                self.smart_select();
+                None
+            }
+            Action::ToggleResourceColumns => {
+                self.keep_selection(|state| {
+                    if state.columns.show_resources {
+                        // Samples stop with the columns; don't sort by stale ones
+                        state.columns = ColumnSettings::default();
+                        state.resources.clear();
+                    } else {
+                        state.columns.show_resources = true;
+                    }
+                });
+                None
+            }
+            Action::CycleSort => {
+                self.keep_selection(|state| {
+                    let columns = &mut state.columns;
+                    columns.sort = columns.sort.next();
+                    // Biggest consumers first, like top; names A-Z
+                    columns.sort_descending = columns.sort != SortField::Name;
+                    if columns.sort != SortField::Name {
+                        columns.show_resources = true;
+                    }
+                });
+                None
+            }
+            Action::ReverseSort => {
+                self.keep_selection(|state| state.columns.sort_descending = !state.columns.sort_descending);
+                None
+            }
             Action::Search(_) => {
                 // Enter search mode
                 self.searching = true;
@@ -167,8 +353,17 @@ impl DashboardState {
                 None
             }
             Action::Select => {
-                // Return selected service name for detail view
-                self.get_selected_service().map(|s| s.name.clone())
+                // Return selected service for detail view
+                self.get_selected_service().map(|s| (s.name.clone(), s.scope))
+            }
+            Action::RunTransient => {
+                // Start in the manager being looked at; user units need no polkit
+                let scope = match self.scope_filter {
+                    ScopeFilter::System => ServiceScope::System,
+                    _ => ServiceScope::User,
+                };
+                self.run_dialog = Some(RunDialog::new(scope));
+                None
             }
             Action::CreateService => {
                 // TODO: Implement service creation form
@@ -215,12 +410,56 @@ impl DashboardState {
     }
 
     pub fn filtered_services(&self) -> Vec<&Service> {
-        self.services
+        let mut units: Vec<&Service> = self
+            .services
             .iter()
             .filter(|s| self.matches_filter(s))
             .filter(|s| self.matches_scope(s))
+            .filter(|s| self.matches_kind(s))
             .filter(|s| self.matches_search(s))
-            .collect()
+            .collect();
+
+        if self.columns.sort == SortField::Name {
+            if self.columns.sort_descending {
+                units.reverse();
+            }
+        } else {
+            // Stable sort keeps ties in name order; units without a sample go last
+            units.sort_by(|a, b| match (self.sort_key(a), self.sort_key(b)) {
+                (Some(x), Some(y)) => {
+                    let order = x.partial_cmp(&y).unwrap_or(Ordering::Equal);
+                    if self.columns.sort_descending { order.reverse() } else { order }
+                }
+                (Some(_), None) => Ordering::Less,
+                (None, Some(_)) => Ordering::Greater,
+                (None, None) => Ordering::Equal,
+            });
+        }
+        units
+    }
+
+    /// Value of the sort column for a unit, if sampled
+    fn sort_key(&self, service: &Service) -> Option<f64> {
+        let resources = self.resources.get(&service.id)?;
+        match self.columns.sort {
+            SortField::Name => None,
+            SortField::Cpu => resources.cpu_percent,
+            SortField::Memory => resources.memory_current.map(|bytes| bytes as f64),
+            SortField::Tasks => resources.tasks_current.map(|tasks| tasks as f64),
+            // Earlier start means longer uptime
+            SortField::Uptime => resources.active_since.map(|since| -(since.timestamp_millis() as f64)),
+        }
+    }
+
+    /// Sort indicator for a column header, or "" when not sorted by it
+    fn sort_arrow(&self, field: SortField) -> &'static str {
+        if self.columns.sort != field || (field == SortField::Name && !self.columns.sort_descending) {
+            ""
+        } else if self.columns.sort_descending {
+            " ▼"
+        } else {
+            " ▲"
+        }
     }
 
     fn matches_filter(&self, service: &Service) -> bool {
@@ -240,6 +479,13 @@ impl DashboardState {
         }
     }
 
+    fn matches_kind(&self, service: &Service) -> bool {
+        match self.kind_filter {
+            UnitKindFilter::All => true,
+            UnitKindFilter::Kind(kind) => service.kind == kind,
+        }
+    }
+
     fn matches_search(&self, service: &Service) -> bool {
         if self.search_term.is_empty() {
             return true;
@@ -291,6 +537,10 @@ impl DashboardState {
         if show_footer {
             self.render_help(frame, chunks[3]);
         }
+
+        if let Some(dialog) = &self.run_dialog {
+            dialog.render(frame, area);
+        }
     }
 
     fn render_help(&self, frame: &mut Frame, area: Rect) {
@@ -303,7 +553,7 @@ impl DashboardState {
             } else {
                 ""
             };
-            format!("[Enter] Details | [↑↓/jk] Navigate | [a/r/s/f] Filter | [m] Scope{} | [/] Search | [q] Quit", new_service)
+            format!("[Enter] Details | [↑↓/jk] Navigate | [a/r/s/f] Filter | [m] Scope | [u] Type{} | [/] Search | [v] Resources | [o/O] Sort | [!] Run | [q] Quit", new_service)
         };
         let help = Paragraph::new(help_text)
             .style(Style::default().fg(ratatui::style::Color::DarkGray))
@@ -324,7 +574,7 @@ impl DashboardState {
         let title_para = Paragraph::new(title)
             .style(Style::default().fg(ratatui::style::Color::Cyan).add_modifier(Modifier::BOLD))
             .alignment(ratatui::layout::Alignment::Center)
-            .block(Block::default().borders(Borders::ALL));
+            .block(Block::default().borders(Borders::ALL).title(tab_title(ListTab::Services)));
 
         // Render title first
         frame.render_widget(title_para, area);
@@ -359,9 +609,15 @@ impl DashboardState {
             String::new()
         };
 
+        let sort_info = if self.columns.sort != SortField::Name || self.columns.sort_descending {
+            format!(" | Sort: {}{}", self.columns.sort.label(), if self.columns.sort_descending { " ▼" } else { " ▲" })
+        } else {
+            String::new()
+        };
+
         let stats_text = format!(
-            "Total: {} | Active: {} | Inactive: {} | Failed: {} | Showing: {} | Filter: {} | Scope: {}{}",
-            total, active, inactive, failed, filtered.len(), self.filter.label(), self.scope_filter.label(), search_info
+            "Total: {} | Active: {} | Inactive: {} | Failed: {} | Showing: {} | Filter: {} | Scope: {} | Type: {}{}{}",
+            total, active, inactive, failed, filtered.len(), self.filter.label(), self.scope_filter.label(), self.kind_filter.label(), sort_info, search_info
         );
 
         let stats = Paragraph::new(stats_text)
@@ -379,11 +635,15 @@ impl DashboardState {
         let rows: Vec<Row> = filtered
             .iter()
             .map(|service| {
-                let icon = status_emoji(&service.active_state);
+                let icon = if service.is_frozen() {
+                    FROZEN_EMOJI
+                } else {
+                    unit_emoji(&service.active_state, &service.load_state)
+                };
                 let enabled = if service.enabled { "✓" } else { "" };
                 let scope_label = service.scope.label();
 
-                Row::new(vec![
+                let mut cells = vec![
                     Cell::from(format!("{} {}", icon, service.name)),
                     Cell::from(scope_label)
                         .style(Style::default().fg(
@@ -393,26 +653,62 @@ impl DashboardState {
                                 ratatui::style::Color::Gray
                             }
                         )),
-                    Cell::from(service.active_state.clone())
-                        .style(Style::default().fg(state_color(&service.active_state))),
+                    // A frozen unit stays "active"; show the freezer state instead
+                    match freezer_color(&service.freezer_state) {
+                        Some(color) => Cell::from(service.freezer_state.clone())
+                            .style(Style::default().fg(color)),
+                        None => Cell::from(service.active_state.clone())
+                            .style(Style::default().fg(unit_state_color(&service.active_state, &service.load_state))),
+                    },
                     Cell::from(service.sub_state.clone()),
                     Cell::from(enabled),
-                ])
+                ];
+                if self.columns.show_resources {
+                    cells.extend(self.resource_cells(service));
+                }
+                Row::new(cells)
             })
             .collect();
 
         // Create table
-        let widths = [
-            Constraint::Percentage(40),
-            Constraint::Percentage(10),
-            Constraint::Percentage(18),
-            Constraint::Percentage(18),
-            Constraint::Percentage(14),
+        let widths = if self.columns.show_resources {
+            vec![
+                Constraint::Percentage(28),
+                Constraint::Percentage(8),
+                Constraint::Percentage(11),
+                Constraint::Percentage(11),
+                Constraint::Percentage(7),
+                Constraint::Percentage(8),
+                Constraint::Percentage(9),
+                Constraint::Percentage(7),
+                Constraint::Percentage(11),
+            ]
+        } else {
+            vec![
+                Constraint::Percentage(40),
+                Constraint::Percentage(10),
+                Constraint::Percentage(18),
+                Constraint::Percentage(18),
+                Constraint::Percentage(14),
+            ]
+        };
+
+        let mut header = vec![
+            format!("Unit{}", self.sort_arrow(SortField::Name)),
+            "Scope".to_string(),
+            "State".to_string(),
+            "Sub-State".to_string(),
+            "Enabled".to_string(),
         ];
+        if self.columns.show_resources {
+            for field in [SortField::Cpu, SortField::Memory, SortField::Tasks, SortField::Uptime] {
+                header.push(format!("{}{}", field.label(), self.sort_arrow(field)));
+            }
+        }
 
         let table = Table::new(rows, widths)
             .header(
-                Row::new(vec!["Service", "Scope", "State", "Sub-State", "Enabled"])
+                Row::new(header)
                     .style(
                         Style::default()
                             .bg(ratatui::style::Color::DarkGray)
@@ -423,7 +719,7 @@ impl DashboardState {
             )
             .block(
                 Block::default()
-                    .title(" Services ")
+                    .title(format!(" Units: {} ", self.kind_filter.label()))
                     .borders(Borders::ALL),
             )
             .highlight_style(
@@ -436,12 +732,64 @@ impl DashboardState {
         frame.render_stateful_widget(table, area, &mut self.table_state);
     }
 
+    /// CPU, memory, tasks and uptime cells; "-" where there is no sample
+    fn resource_cells(&self, service: &Service) -> Vec<Cell<'static>> {
+        let resources = self.resources.get(&service.id).copied().unwrap_or_default();
+        let or_dash = |value: Option<String>| value.unwrap_or_else(|| "-".to_string());
+
+        let cpu_color = match resources.cpu_percent {
+            Some(p) if p >= 90.0 => ratatui::style::Color::Red,
+            Some(p) if p >= 50.0 => ratatui::style::Color::Yellow,
+            _ => ratatui::style::Color::Reset,
+        };
+        let uptime = resources
+            .active_since
+            .filter(|_| service.is_active())
+            .map(|since| format_uptime((chrono::Utc::now() - since).num_seconds()));
+
+        vec![
+            Cell::from(or_dash(resources.cpu_percent.map(|p| format!("{:.1}%", p)))).style(Style::default().fg(cpu_color)),
+            Cell::from(or_dash(resources.memory_current.map(format_memory))),
+            Cell::from(or_dash(resources.tasks_current.map(|t| t.to_string()))),
+            Cell::from(or_dash(uptime)),
+        ]
+    }
+
     pub fn get_stats(&self) -> (usize, usize, usize, usize) {
-        let total = self.services.len();
-        let active = self.services.iter().filter(|s| s.is_active()).count();
-        let inactive = self.services.iter().filter(|s| s.is_inactive()).count();
-        let failed = self.services.iter().filter(|s| s.is_failed()).count();
+        // Stats cover the selected unit type only
+        let units: Vec<&Service> = self.services.iter().filter(|s| self.matches_kind(s)).collect();
+        let total = units.len();
+        let active = units.iter().filter(|s| s.is_active()).count();
+        let inactive = units.iter().filter(|s| s.is_inactive()).count();
+        let failed = units.iter().filter(|s| s.is_failed()).count();
 
         (total, active, inactive, failed)
     }
 }
+
+/// Compact binary size for a table column, like top ("512K", "1.5G")
+pub fn format_memory(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "K", "M", "G", "T"];
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{}B", bytes)
+    } else {
+        format!("{:.1}{}", value, UNITS[unit])
+    }
+}
+
+/// Compact uptime with the two largest units ("45s", "12m", "3h 5m", "2d 4h")
+pub fn format_uptime(secs: i64) -> String {
+    let secs = secs.max(0);
+    match secs {
+        s if s < 60 => format!("{}s", s),
+        s if s < 3600 => format!("{}m", s / 60),
+        s if s < 86400 => format!("{}h {}m", s / 3600, s % 3600 / 60),
+        s => format!("{}d {}h", s / 86400, s % 86400 / 3600),
+    }
+}
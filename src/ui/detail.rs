@@ -1,8 +1,10 @@
 // Detail view - service details
 
 use crate::events::Action;
-use crate::systemd::ServiceDetail;
-use crate::ui::{state_color, status_emoji, load_state_color, result_color, sub_state_color};
+use crate::systemd::cgroup::{Pressure, PressureLine};
+use crate::systemd::{CgroupStats, IoRates, KillSignal, KillTarget, Metrics, MetricsSnapshot, Service, ServiceDetail, ServiceScope, UnitKind};
+use crate::ui::limits::LimitsEditor;
+use crate::ui::{freezer_color, unit_emoji, unit_state_color, state_color, load_state_color, FROZEN, FROZEN_EMOJI, MASKED, result_color, sub_state_color};
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -15,16 +17,246 @@ pub struct DetailState {
     pub detail: Option<ServiceDetail>,
     pub loading: bool,
     pub confirmation_dialog: Option<ConfirmationDialog>,
+    pub kill_dialog: Option<KillDialog>,
+    /// Resource limit editor; takes raw key input while open
+    pub limits_editor: Option<LimitsEditor>,
+    /// Typed-name confirmation for deleting the unit; takes raw key input while open
+    pub remove_dialog: Option<RemoveDialog>,
+    /// Signal and target awaiting confirmation from the kill dialog
+    pending_kill: Option<(KillTarget, KillSignal)>,
+    /// Samples from the metrics poller, oldest first
+    pub metrics_history: Vec<MetricsSnapshot>,
+    /// Latest read of the unit's cgroup; None until read or when unavailable
+    pub cgroup_stats: Option<CgroupStats>,
 }
 
 #[derive(Debug)]
 pub struct ConfirmationDialog {
     pub service: String,
     pub operation: String,
+    pub scope: ServiceScope,
     pub message: String,
     pub confirmed: bool,
 }
 
+impl ConfirmationDialog {
+    /// Render the dialog centered over a darkened area
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        // Create dialog area (centered)
+        let dialog_width = 60.min(area.width - 4);
+        let dialog_height = 8.min(area.height - 4);
+        let dialog_x = (area.width - dialog_width) / 2;
+        let dialog_y = (area.height - dialog_height) / 2;
+
+        let dialog_area = Rect {
+            x: area.x + dialog_x,
+            y: area.y + dialog_y,
+            width: dialog_width,
+            height: dialog_height,
+        };
+
+        // Darken background
+        let background = Block::default()
+            .style(Style::default().bg(ratatui::style::Color::DarkGray).fg(ratatui::style::Color::Reset));
+        frame.render_widget(background, area);
+
+        // Dialog box, naming the manager the operation goes to
+        let scope_line = match self.scope {
+            ServiceScope::System => "Scope: system (may require polkit authentication)",
+            ServiceScope::User => "Scope: user (session manager)",
+        };
+        let dialog = Paragraph::new(format!(
+            "{}\n{}\n\n[y] Yes  [n] No  [Esc] Cancel",
+            self.message, scope_line
+        ))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" Confirm {} ", self.operation))
+                .border_style(Style::default().fg(ratatui::style::Color::Yellow))
+                .style(
+                    Style::default()
+                        .bg(ratatui::style::Color::Black)
+                        .fg(ratatui::style::Color::White)
+                )
+        )
+        .alignment(ratatui::layout::Alignment::Center)
+        .wrap(Wrap { trim: false });
+
+        frame.render_widget(dialog, dialog_area);
+    }
+}
+
+/// Signal picker shown before the kill confirmation
+#[derive(Debug)]
+pub struct KillDialog {
+    pub service: String,
+    pub scope: ServiceScope,
+    pub selected: usize,
+    pub target: KillTarget,
+}
+
+impl KillDialog {
+    pub fn new(service: String, scope: ServiceScope) -> Self {
+        Self {
+            service,
+            scope,
+            selected: 0,
+            target: KillTarget::default(),
+        }
+    }
+
+    pub fn signal(&self) -> KillSignal {
+        KillSignal::ALL[self.selected]
+    }
+
+    /// Render the signal list and target centered over a darkened area
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        use ratatui::text::{Line, Span};
+
+        let dialog_width = 60.min(area.width - 4);
+        let dialog_height = (KillSignal::ALL.len() as u16 + 7).min(area.height - 4);
+        let dialog_area = Rect {
+            x: area.x + (area.width - dialog_width) / 2,
+            y: area.y + (area.height - dialog_height) / 2,
+            width: dialog_width,
+            height: dialog_height,
+        };
+
+        let background = Block::default()
+            .style(Style::default().bg(Color::DarkGray).fg(Color::Reset));
+        frame.render_widget(background, area);
+
+        let mut lines = vec![Line::from(format!("Send a signal to '{}'", self.service)), Line::from("")];
+        for (i, signal) in KillSignal::ALL.iter().enumerate() {
+            let style = if i == self.selected {
+                Style::default().fg(Color::Black).bg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            lines.push(Line::from(Span::styled(
+                format!(" {:<8} {:>2}  {:<34}", signal.name(), signal.number(), signal.hint()),
+                style,
+            )));
+        }
+        lines.push(Line::from(""));
+        lines.push(Line::from(vec![
+            Span::styled("Target: ", Style::default().fg(Color::Cyan)),
+            Span::styled(self.target.description(), Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+        ]));
+        lines.push(Line::from(Span::styled(
+            "[↑↓] Signal  [Tab] Target  [Enter] Send  [Esc] Cancel",
+            Style::default().fg(Color::DarkGray),
+        )));
+
+        let dialog = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Kill ")
+                    .border_style(Style::default().fg(Color::Red))
+                    .style(Style::default().bg(Color::Black).fg(Color::White)),
+            )
+            .alignment(ratatui::layout::Alignment::Center);
+
+        frame.render_widget(dialog, dialog_area);
+    }
+}
+
+/// Confirmation for deleting a unit: the full unit name must be typed
+#[derive(Debug)]
+pub struct RemoveDialog {
+    pub service: String,
+    pub scope: ServiceScope,
+    /// Unit file that will be deleted
+    pub path: String,
+    /// Timer activating the service, removed along with it
+    pub timer: Option<String>,
+    pub typed: String,
+}
+
+impl RemoveDialog {
+    pub fn new(service: String, scope: ServiceScope, path: String) -> Self {
+        let timer = crate::systemd::dropin::companion_timer(&service, scope);
+        Self {
+            service,
+            scope,
+            path,
+            timer,
+            typed: String::new(),
+        }
+    }
+
+    pub fn handle_char(&mut self, c: char) {
+        self.typed.push(c);
+    }
+
+    pub fn backspace(&mut self) {
+        self.typed.pop();
+    }
+
+    /// Whether the typed text matches the unit name
+    pub fn confirmed(&self) -> bool {
+        self.typed == self.service
+    }
+
+    /// Render the prompt centered over a darkened area
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        use ratatui::text::{Line, Span};
+
+        let dialog_width = 70.min(area.width - 4);
+        let dialog_height = 11.min(area.height - 4);
+        let dialog_area = Rect {
+            x: area.x + (area.width - dialog_width) / 2,
+            y: area.y + (area.height - dialog_height) / 2,
+            width: dialog_width,
+            height: dialog_height,
+        };
+
+        let background = Block::default()
+            .style(Style::default().bg(Color::DarkGray).fg(Color::Reset));
+        frame.render_widget(background, area);
+
+        let input_color = if self.confirmed() { Color::Green } else { Color::Yellow };
+        let mut lines = vec![
+            Line::from(format!("Stop, disable and delete '{}'?", self.service)),
+            Line::from(Span::styled(
+                format!("Removes {} and its .d drop-ins", self.path),
+                Style::default().fg(Color::Gray),
+            )),
+        ];
+        if let Some(timer) = &self.timer {
+            lines.push(Line::from(Span::styled(
+                format!("Also stops, disables and deletes {}", timer),
+                Style::default().fg(Color::Gray),
+            )));
+        }
+        lines.extend([
+            Line::from(""),
+            Line::from("Type the unit name to confirm:"),
+            Line::from(vec![
+                Span::styled(self.typed.clone(), Style::default().fg(input_color).add_modifier(Modifier::BOLD)),
+                Span::styled("█", Style::default().fg(input_color)),
+            ]),
+            Line::from(""),
+            Line::from(Span::styled("[Enter] Delete  [Esc] Cancel", Style::default().fg(Color::DarkGray))),
+        ]);
+
+        let dialog = Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .title(" Remove unit ")
+                    .border_style(Style::default().fg(Color::Red))
+                    .style(Style::default().bg(Color::Black).fg(Color::White)),
+            )
+            .alignment(ratatui::layout::Alignment::Center)
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(dialog, dialog_area);
+    }
+}
+
 impl Default for DetailState {
     fn default() -> Self {
         Self::new()
@@ -37,6 +269,12 @@ impl DetailState {
             detail: None,
             loading: true,
             confirmation_dialog: None,
+            kill_dialog: None,
+            limits_editor: None,
+            remove_dialog: None,
+            pending_kill: None,
+            metrics_history: Vec::new(),
+            cgroup_stats: None,
         }
     }
 
@@ -45,10 +283,34 @@ impl DetailState {
         self.loading = false;
     }
 
-    pub fn show_confirmation(&mut self, service: String, operation: String, message: String) {
+    /// Take the poller's history if it belongs to the unit shown
+    pub fn set_metrics_history(&mut self, service: &str, scope: ServiceScope, history: Vec<MetricsSnapshot>) {
+        if self.detail.as_ref().is_some_and(|d| d.service.name == service && d.service.scope == scope) {
+            self.metrics_history = history;
+        }
+    }
+
+    /// Take the poller's cgroup read if it belongs to the unit shown
+    pub fn set_cgroup_stats(&mut self, service: &str, scope: ServiceScope, stats: Option<CgroupStats>) {
+        if self.detail.as_ref().is_some_and(|d| d.service.name == service && d.service.scope == scope) {
+            self.cgroup_stats = stats;
+        }
+    }
+
+    /// Patch the listing part of the loaded detail with fresh state
+    pub fn update_service(&mut self, service: Service) {
+        if let Some(detail) = &mut self.detail {
+            if detail.service.id == service.id {
+                detail.service = service;
+            }
+        }
+    }
+
+    pub fn show_confirmation(&mut self, service: String, operation: String, scope: ServiceScope, message: String) {
         self.confirmation_dialog = Some(ConfirmationDialog {
             service,
             operation,
+            scope,
             message,
             confirmed: false,
         });
@@ -56,6 +318,7 @@ impl DetailState {
 
     pub fn hide_confirmation(&mut self) {
         self.confirmation_dialog = None;
+        self.pending_kill = None;
     }
 
     pub fn confirm_action(&mut self) -> bool {
@@ -76,8 +339,13 @@ impl DetailState {
                         // Extract dialog info before clearing
                         let service = self.confirmation_dialog.as_ref().unwrap().service.clone();
                         let operation = self.confirmation_dialog.as_ref().unwrap().operation.clone();
+                        let scope = self.confirmation_dialog.as_ref().unwrap().scope;
+                        let kill = self.pending_kill.take();
                         self.hide_confirmation();
-                        return DetailAction::ExecuteServiceControl { service, operation };
+                        if let Some((target, signal)) = kill {
+                            return DetailAction::KillUnit { service, scope, target, signal };
+                        }
+                        return DetailAction::ExecuteServiceControl { service, operation, scope };
                     }
                     DetailAction::None
                 }
@@ -87,13 +355,38 @@ impl DetailState {
                 }
                 _ => DetailAction::None,
             }
+        } else if let Some(dialog) = &mut self.kill_dialog {
+            // Signal picker: choose signal and target, then confirm
+            match action {
+                Action::MoveUp => {
+                    dialog.selected = dialog.selected.saturating_sub(1);
+                }
+                Action::MoveDown => {
+                    dialog.selected = (dialog.selected + 1).min(KillSignal::ALL.len() - 1);
+                }
+                Action::NextTab => dialog.target = dialog.target.next(),
+                Action::PrevTab => dialog.target = dialog.target.prev(),
+                Action::Select => {
+                    let (signal, target) = (dialog.signal(), dialog.target);
+                    let message = format!("Send {} to {} of '{}'?", signal.name(), target.description(), dialog.service);
+                    let (service, scope) = (dialog.service.clone(), dialog.scope);
+                    self.kill_dialog = None;
+                    self.show_confirmation(service, format!("kill ({})", signal.name()), scope, message);
+                    self.pending_kill = Some((target, signal));
+                }
+                Action::CancelAction | Action::GoBack => {
+                    self.kill_dialog = None;
+                }
+                _ => {}
+            }
+            DetailAction::None
         } else {
             // Normal action handling
             match action {
                 Action::GoBack => DetailAction::GoBack,
                 Action::ViewLogs => {
                     if let Some(detail) = &self.detail {
-                        DetailAction::ViewLogs(detail.service.name.clone())
+                        DetailAction::ViewLogs(detail.service.name.clone(), detail.service.scope)
                     } else {
                         DetailAction::None
                     }
@@ -105,7 +398,8 @@ impl DetailState {
                             self.show_confirmation(
                                 detail.service.name.clone(),
                                 "start".to_string(),
-                                format!("Start service '{}'?", detail.service.name),
+                                detail.service.scope,
+                                format!("Start {} '{}'?", detail.service.kind.label(), detail.service.name),
                             );
                         }
                     }
@@ -118,7 +412,8 @@ impl DetailState {
                             self.show_confirmation(
                                 detail.service.name.clone(),
                                 "stop".to_string(),
-                                format!("Stop service '{}'?", detail.service.name),
+                                detail.service.scope,
+                                format!("Stop {} '{}'?", detail.service.kind.label(), detail.service.name),
                             );
                         }
                     }
@@ -131,7 +426,8 @@ impl DetailState {
                             self.show_confirmation(
                                 detail.service.name.clone(),
                                 "restart".to_string(),
-                                format!("Restart service '{}'?", detail.service.name),
+                                detail.service.scope,
+                                format!("Restart {} '{}'?", detail.service.kind.label(), detail.service.name),
                             );
                         }
                     }
@@ -144,7 +440,8 @@ impl DetailState {
                             self.show_confirmation(
                                 detail.service.name.clone(),
                                 "enable".to_string(),
-                                format!("Enable service '{}' to start on boot?", detail.service.name),
+                                detail.service.scope,
+                                format!("Enable {} '{}' to start on boot?", detail.service.kind.label(), detail.service.name),
                             );
                         }
                     }
@@ -157,7 +454,8 @@ impl DetailState {
                             self.show_confirmation(
                                 detail.service.name.clone(),
                                 "disable".to_string(),
-                                format!("Disable service '{}' from starting on boot?", detail.service.name),
+                                detail.service.scope,
+                                format!("Disable {} '{}' from starting on boot?", detail.service.kind.label(), detail.service.name),
                             );
                         }
                     }
@@ -170,7 +468,142 @@ impl DetailState {
                             self.show_confirmation(
                                 detail.service.name.clone(),
                                 "reload".to_string(),
-                                format!("Reload service '{}' configuration?", detail.service.name),
+                                detail.service.scope,
+                                format!("Reload {} '{}' configuration?", detail.service.kind.label(), detail.service.name),
+                            );
+                        }
+                    }
+                    DetailAction::None
+                }
+                Action::KillService => {
+                    if let Some(detail) = &self.detail {
+                        // Only allow kill if there are processes to signal
+                        if detail.service.is_active() || detail.service.is_transitioning() {
+                            self.kill_dialog = Some(KillDialog::new(detail.service.name.clone(), detail.service.scope));
+                        }
+                    }
+                    DetailAction::None
+                }
+                Action::ToggleFreeze => {
+                    if let Some(detail) = &self.detail {
+                        let service = &detail.service;
+                        // Thaw a frozen unit; freeze only running units that support it
+                        if service.is_frozen() {
+                            self.show_confirmation(
+                                service.name.clone(),
+                                "thaw".to_string(),
+                                service.scope,
+                                format!("Thaw {} '{}' and resume its processes?", service.kind.label(), service.name),
+                            );
+                        } else if service.is_active() && service.kind.can_freeze() {
+                            self.show_confirmation(
+                                service.name.clone(),
+                                "freeze".to_string(),
+                                service.scope,
+                                format!(
+                                    "Freeze {} '{}'? Its processes stay paused until thawed.",
+                                    service.kind.label(),
+                                    service.name
+                                ),
+                            );
+                        }
+                    }
+                    DetailAction::None
+                }
+                Action::EditLimits => {
+                    if let Some(detail) = &self.detail {
+                        // Limits apply to the unit's cgroup
+                        if detail.service.kind.has_cgroup() {
+                            self.limits_editor = Some(LimitsEditor::from_detail(detail));
+                        }
+                    }
+                    DetailAction::None
+                }
+                Action::RemoveUnit => {
+                    if let Some(detail) = &self.detail {
+                        // Only locally created units; packaged ones would come back on upgrade
+                        if detail.is_removable() {
+                            self.remove_dialog = Some(RemoveDialog::new(
+                                detail.service.name.clone(),
+                                detail.service.scope,
+                                detail.load_path.clone(),
+                            ));
+                        }
+                    }
+                    DetailAction::None
+                }
+                Action::EditOverride => match &self.detail {
+                    Some(detail) => DetailAction::EditOverride {
+                        service: detail.service.name.clone(),
+                        scope: detail.service.scope,
+                    },
+                    None => DetailAction::None,
+                },
+                Action::ViewUnitFile => match &self.detail {
+                    Some(detail) => DetailAction::ViewUnitFile {
+                        service: detail.service.name.clone(),
+                        scope: detail.service.scope,
+                        fragment: detail.load_path.clone(),
+                        drop_ins: detail.drop_in_paths.clone(),
+                    },
+                    None => DetailAction::None,
+                },
+                Action::VendorDiff => match &self.detail {
+                    // Only units whose /etc copy hides a packaged one
+                    Some(detail) if detail.shadows_vendor() => DetailAction::VendorDiff {
+                        service: detail.service.name.clone(),
+                        scope: detail.service.scope,
+                        vendor: detail.vendor_path.clone(),
+                        local: detail.load_path.clone(),
+                        notes: detail.local_changes(),
+                    },
+                    _ => DetailAction::None,
+                },
+                Action::MaskService => {
+                    if let Some(detail) = &self.detail {
+                        // Only allow mask if unit is NOT already masked
+                        if !detail.service.is_masked() {
+                            self.show_confirmation(
+                                detail.service.name.clone(),
+                                "mask".to_string(),
+                                detail.service.scope,
+                                format!(
+                                    "Mask {} '{}'? It cannot be started, even as a dependency, until unmasked.",
+                                    detail.service.kind.label(),
+                                    detail.service.name
+                                ),
+                            );
+                        }
+                    }
+                    DetailAction::None
+                }
+                Action::UnmaskService => {
+                    if let Some(detail) = &self.detail {
+                        // Only allow unmask if unit IS masked
+                        if detail.service.is_masked() {
+                            self.show_confirmation(
+                                detail.service.name.clone(),
+                                "unmask".to_string(),
+                                detail.service.scope,
+                                format!("Unmask {} '{}'?", detail.service.kind.label(), detail.service.name),
+                            );
+                        }
+                    }
+                    DetailAction::None
+                }
+                Action::ResetFailed => {
+                    if let Some(detail) = &self.detail {
+                        // Only allow reset if unit IS failed
+                        if detail.service.is_failed() {
+                            self.show_confirmation(
+                                detail.service.name.clone(),
+                                "reset-failed".to_string(),
+                                detail.service.scope,
+                                format!(
+                                    "Reset failed state of {} '{}'?",
+                                    detail.service.kind.label(),
+                                    detail.service.name
+                                ),
                             );
                         }
                     }
@@ -184,7 +617,20 @@ impl DetailState {
     pub fn render(&mut self, frame: &mut Frame, area: Rect) {
         // If confirmation dialog is active, render it on top
         if let Some(dialog) = &self.confirmation_dialog {
-            self.render_confirmation_dialog(frame, area, dialog);
+            dialog.render(frame, area);
+            return;
+        }
+        if let Some(dialog) = &self.kill_dialog {
+            dialog.render(frame, area);
+            return;
+        }
+        if let Some(editor) = &self.limits_editor {
+            editor.render(frame, area);
+            return;
+        }
+
+        if let Some(dialog) = &self.remove_dialog {
+            dialog.render(frame, area);
             return;
         }
 
@@ -204,30 +650,65 @@ impl DetailState {
             return;
         };
 
+        // I/O and pressure rows only take space when there is something to show
+        let io_metrics = self
+            .metrics_history
+            .last()
+            .map(|latest| &latest.metrics)
+            .filter(|metrics| metrics.io_accounted() || metrics.ip_accounted());
+        let cgroup_stats = self.cgroup_stats.as_ref();
+
         // Split area into sections
+        let mut constraints = vec![
+            Constraint::Length(3),  // Header
+            Constraint::Length(7),  // Status
+            Constraint::Length(15), // Metrics (split columns with dot matrix graphs)
+        ];
+        if io_metrics.is_some() {
+            constraints.push(Constraint::Length(5)); // Disk and network I/O
+        }
+        if cgroup_stats.is_some() {
+            constraints.push(Constraint::Length(5)); // Pressure stall information
+        }
+        constraints.extend([
+            Constraint::Length(5), // Dependencies
+            Constraint::Min(6),    // Service Configuration
+            Constraint::Length(2), // Help
+        ]);
         let chunks = Layout::default()
             .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(3),  // Header
-                Constraint::Length(7),  // Status
-                Constraint::Length(15), // Metrics (split columns with dot matrix graphs)
-                Constraint::Length(5),  // Dependencies
-                Constraint::Min(6),     // Service Configuration
-                Constraint::Length(2),  // Help
-            ])
+            .constraints(constraints)
             .split(area);
+        let mut rows = chunks.iter().copied();
+        let mut next_row = || rows.next().unwrap_or_default();
 
         // Render each section
-        self.render_header(frame, chunks[0], detail);
-        self.render_status(frame, chunks[1], detail);
-        self.render_metrics(frame, chunks[2], detail);
-        self.render_dependencies(frame, chunks[3], detail);
-        self.render_service_config(frame, chunks[4], detail);
-        self.render_help(frame, chunks[5], detail);
+        self.render_header(frame, next_row(), detail);
+        self.render_status(frame, next_row(), detail);
+        self.render_metrics(frame, next_row(), detail);
+        if let Some(metrics) = io_metrics {
+            self.render_io(frame, next_row(), metrics);
+        }
+        if let Some(stats) = cgroup_stats {
+            self.render_pressure(frame, next_row(), stats);
+        }
+        self.render_dependencies(frame, next_row(), detail);
+        if detail.service.kind == UnitKind::Service {
+            self.render_service_config(frame, next_row(), detail);
+        } else {
+            self.render_type_config(frame, next_row(), detail);
+        }
+        self.render_help(frame, next_row(), detail);
     }
 
     fn render_header(&self, frame: &mut Frame, area: Rect, detail: &ServiceDetail) {
-        let icon = status_emoji(&detail.service.active_state);
+        use ratatui::text::{Line, Span};
+
+        let icon = if detail.service.is_frozen() {
+            FROZEN_EMOJI
+        } else {
+            unit_emoji(&detail.service.active_state, &detail.service.load_state)
+        };
         let title = format!("{} {}", icon, detail.service.name);
 
         let subtitle = format!(
@@ -235,12 +716,21 @@ impl DetailState {
             detail.service.active_state, detail.service.description
         );
 
-        let text = format!("{}\n{}", title, subtitle);
+        let mut lines = vec![Line::from(title), Line::from(subtitle)];
+
+        // Flag local edits that may hide newer vendor defaults
+        let changes = detail.local_changes();
+        if !changes.is_empty() {
+            lines.push(Line::from(Span::styled(
+                format!("⚠ Local changes: {}", changes.join(", ")),
+                Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+            )));
+        }
 
-        let header = Paragraph::new(text)
+        let header = Paragraph::new(lines)
             .style(
                 Style::default()
-                    .fg(state_color(&detail.service.active_state))
+                    .fg(unit_state_color(&detail.service.active_state, &detail.service.load_state))
                     .add_modifier(Modifier::BOLD),
             )
             .wrap(Wrap { trim: false });
@@ -257,15 +747,23 @@ impl DetailState {
             "-".to_string()
         };
 
+        let mut active_spans = vec![
+            Span::styled("Active State: ", Style::default().fg(Color::Cyan)),
+            Span::styled(&detail.service.active_state, Style::default().fg(state_color(&detail.service.active_state))),
+        ];
+        if let Some(color) = freezer_color(&detail.service.freezer_state) {
+            active_spans.push(Span::styled(
+                format!(" ({})", detail.service.freezer_state),
+                Style::default().fg(color).add_modifier(Modifier::BOLD),
+            ));
+        }
+
         let lines = vec![
             Line::from(vec![
                 Span::styled("Load State:   ", Style::default().fg(Color::Cyan)),
                 Span::styled(&detail.service.load_state, Style::default().fg(load_state_color(&detail.service.load_state))),
             ]),
-            Line::from(vec![
-                Span::styled("Active State: ", Style::default().fg(Color::Cyan)),
-                Span::styled(&detail.service.active_state, Style::default().fg(state_color(&detail.service.active_state))),
-            ]),
+            Line::from(active_spans),
             Line::from(vec![
                 Span::styled("Sub State:    ", Style::default().fg(Color::Cyan)),
                 Span::styled(&detail.service.sub_state, Style::default().fg(sub_state_color(&detail.service.sub_state))),
@@ -381,17 +879,24 @@ impl DetailState {
             },
         ]));
 
-        let memory_widget = ratatui::widgets::Paragraph::new(memory_lines)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title(" Memory ")
-                    .border_style(Style::default().fg(ratatui::style::Color::Cyan))
-                    .title_style(Style::default().fg(ratatui::style::Color::Cyan).add_modifier(ratatui::style::Modifier::BOLD)),
-            )
-            .wrap(Wrap { trim: false });
+        let memory_block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Memory ")
+            .border_style(Style::default().fg(ratatui::style::Color::Cyan))
+            .title_style(Style::default().fg(ratatui::style::Color::Cyan).add_modifier(ratatui::style::Modifier::BOLD));
+        let memory_rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(memory_lines.len() as u16), Constraint::Length(4), Constraint::Min(0)])
+            .split(memory_block.inner(columns[0]));
+        frame.render_widget(memory_block, columns[0]);
 
-        frame.render_widget(memory_widget, columns[0]);
+        let memory_widget = ratatui::widgets::Paragraph::new(memory_lines).wrap(Wrap { trim: false });
+        frame.render_widget(memory_widget, memory_rows[0]);
+
+        let memory_samples: Vec<u64> = self.metrics_history.iter().map(|s| s.metrics.memory_current).collect();
+        self.render_sparkline(frame, memory_rows[1], "Memory", &memory_samples, |v| format!("{} MB", v / 1024 / 1024), Color::Cyan);
+        let task_samples: Vec<u64> = self.metrics_history.iter().map(|s| s.metrics.tasks_current).collect();
+        self.render_sparkline(frame, memory_rows[2], "Tasks", &task_samples, |v| v.to_string(), Color::Magenta);
 
         // === RIGHT COLUMN: CPU ===
         let cpu_seconds = detail.cpu_usage_nsec as f64 / 1_000_000_000.0;
@@ -451,6 +956,14 @@ impl DetailState {
             ]));
         }
 
+        // Live share of the whole machine; the sparkline below is per core
+        if let Some(latest) = self.metrics_history.last() {
+            cpu_lines.push(Line::from(vec![
+                Span::styled("Machine: ", Style::default().fg(ratatui::style::Color::Cyan).add_modifier(ratatui::style::Modifier::BOLD)),
+                Span::styled(format!("{:.2}% of all CPUs", latest.cpu_percent_machine), Style::default().fg(ratatui::style::Color::White)),
+            ]));
+        }
+
         cpu_lines.push(Line::from(""));
         cpu_lines.push(Line::from(vec![
             Span::styled("Total Time", Style::default().fg(ratatui::style::Color::Cyan).add_modifier(ratatui::style::Modifier::BOLD)),
@@ -468,17 +981,181 @@ impl DetailState {
             Span::styled(format!("{}", detail.n_restarts), Style::default().fg(ratatui::style::Color::White).add_modifier(ratatui::style::Modifier::BOLD)),
         ]));
 
-        let cpu_widget = ratatui::widgets::Paragraph::new(cpu_lines)
-            .block(
-                Block::default()
-                    .borders(Borders::ALL)
-                    .title(" CPU ")
-                    .border_style(Style::default().fg(ratatui::style::Color::Cyan))
-                    .title_style(Style::default().fg(ratatui::style::Color::Cyan).add_modifier(ratatui::style::Modifier::BOLD)),
-            )
-            .wrap(Wrap { trim: false });
+        let cpu_block = Block::default()
+            .borders(Borders::ALL)
+            .title(" CPU ")
+            .border_style(Style::default().fg(ratatui::style::Color::Cyan))
+            .title_style(Style::default().fg(ratatui::style::Color::Cyan).add_modifier(ratatui::style::Modifier::BOLD));
+        let cpu_rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(cpu_lines.len() as u16), Constraint::Min(0)])
+            .split(cpu_block.inner(columns[1]));
+        frame.render_widget(cpu_block, columns[1]);
+
+        let cpu_widget = ratatui::widgets::Paragraph::new(cpu_lines).wrap(Wrap { trim: false });
+        frame.render_widget(cpu_widget, cpu_rows[0]);
+
+        // Tenths of a percent keep sub-1% load visible
+        let cpu_samples: Vec<u64> = self.metrics_history.iter().map(|s| (s.cpu_percent * 10.0).round() as u64).collect();
+        self.render_sparkline(frame, cpu_rows[1], "CPU", &cpu_samples, |v| format!("{:.1}%", v as f64 / 10.0), Color::Green);
+    }
+
+    /// Label with the latest and peak sample above a sparkline of the retained window
+    fn render_sparkline(&self, frame: &mut Frame, area: Rect, label: &str, samples: &[u64], format: impl Fn(u64) -> String, color: Color) {
+        use ratatui::text::{Line, Span};
+        use ratatui::widgets::Sparkline;
+
+        if area.height == 0 {
+            return;
+        }
+        let rows = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(1), Constraint::Min(0)])
+            .split(area);
+
+        let (Some(latest), Some(peak)) = (samples.last(), samples.iter().max()) else {
+            let waiting = Paragraph::new(Span::styled(format!("{}: waiting for samples…", label), Style::default().fg(Color::DarkGray)));
+            frame.render_widget(waiting, rows[0]);
+            return;
+        };
+
+        let window = match (self.metrics_history.first(), self.metrics_history.last()) {
+            (Some(first), Some(last)) => format_window((last.timestamp - first.timestamp).num_seconds()),
+            _ => String::new(),
+        };
+        frame.render_widget(
+            Paragraph::new(Line::from(vec![
+                Span::styled(format!("{} ", label), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("{} ", window), Style::default().fg(Color::DarkGray)),
+                Span::styled(format!("now {}", format(*latest)), Style::default().fg(Color::White)),
+                Span::styled(format!("  peak {}", format(*peak)), Style::default().fg(Color::DarkGray)),
+            ])),
+            rows[0],
+        );
+
+        // Newest samples on the right, as many as fit
+        let width = rows[1].width as usize;
+        let visible = &samples[samples.len().saturating_sub(width)..];
+        let sparkline = Sparkline::default()
+            .data(visible)
+            .max((*peak).max(1))
+            .style(Style::default().fg(color));
+        frame.render_widget(sparkline, rows[1]);
+    }
+
+    /// Disk and network throughput from the last two samples, plus memory peak and swap
+    fn render_io(&self, frame: &mut Frame, area: Rect, metrics: &Metrics) {
+        use ratatui::text::{Line, Span};
+
+        let label = |text: &'static str| Span::styled(format!("{:<9}", text), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD));
+        let muted = |text: String| Span::styled(text, Style::default().fg(Color::DarkGray));
+        let warning = |text: &'static str| Span::styled(format!("⚠ {}", text), Style::default().fg(Color::Yellow));
+        let rate = |value: Option<f64>| match value {
+            Some(bytes) => format!("{}/s", format_bytes(bytes)),
+            None => "…".to_string(),
+        };
+
+        let rates = IoRates::from_history(&self.metrics_history);
+
+        let disk = if metrics.io_accounted() {
+            Line::from(vec![
+                label("Disk"),
+                Span::raw(format!("read {:<12} write {:<12} ", rate(rates.io_read), rate(rates.io_write))),
+                muted(format!("({} read, {} written)", format_bytes(metrics.io_read_bytes as f64), format_bytes(metrics.io_write_bytes as f64))),
+            ])
+        } else {
+            Line::from(vec![label("Disk"), warning("IO accounting is off for this unit (IOAccounting=yes)")])
+        };
+
+        let network = if metrics.ip_accounted() {
+            Line::from(vec![
+                label("Network"),
+                Span::raw(format!("in   {:<12} out   {:<12} ", rate(rates.ip_ingress), rate(rates.ip_egress))),
+                muted(format!("({} in, {} out)", format_bytes(metrics.ip_ingress_bytes as f64), format_bytes(metrics.ip_egress_bytes as f64))),
+            ])
+        } else {
+            Line::from(vec![label("Network"), warning("IP accounting is off for this unit (IPAccounting=yes)")])
+        };
+
+        let available = |bytes: u64| if bytes == u64::MAX { "n/a".to_string() } else { format_bytes(bytes as f64) };
+        let memory = Line::from(vec![
+            label("Memory"),
+            Span::raw(format!("peak {:<12} swap  {}", available(metrics.memory_peak), available(metrics.memory_swap_current))),
+        ]);
+
+        let lines = vec![disk, network, memory];
+
+        let io = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" I/O & Network "),
+        );
+        frame.render_widget(io, area);
+    }
+
+    /// PSI stall percentages beside the memory.stat breakdown and memory.events counts
+    fn render_pressure(&self, frame: &mut Frame, area: Rect, stats: &CgroupStats) {
+        use ratatui::text::{Line, Span};
+
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(" Pressure (% stalled, avg10/60/300) ");
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let muted = |text: String| Span::styled(text, Style::default().fg(Color::DarkGray));
+        let label = |text: &'static str| Span::styled(format!("{:<8}", text), Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD));
+        let averages = |line: &PressureLine| {
+            let color = match line.avg10 {
+                p if p >= 10.0 => Color::Red,
+                p if p > 0.0 => Color::Yellow,
+                _ => Color::White,
+            };
+            Span::styled(format!("{:>6.2}{:>7.2}{:>7.2}", line.avg10, line.avg60, line.avg300), Style::default().fg(color))
+        };
+        let psi_line = |name: &'static str, pressure: &Option<Pressure>| {
+            let Some(pressure) = pressure else {
+                return Line::from(vec![label(name), muted("n/a".to_string())]);
+            };
+            let mut spans = vec![label(name), muted("some".to_string()), averages(&pressure.some)];
+            if let Some(full) = &pressure.full {
+                spans.push(muted("   full".to_string()));
+                spans.push(averages(full));
+            }
+            Line::from(spans)
+        };
+
+        let psi = vec![
+            psi_line("CPU", &stats.cpu_pressure),
+            psi_line("Memory", &stats.memory_pressure),
+            psi_line("IO", &stats.io_pressure),
+        ];
+
+        let mut memory = match &stats.memory_stat {
+            Some(m) => vec![
+                Line::from(format!("anon {}  file {}", format_bytes(m.anon as f64), format_bytes(m.file as f64))),
+                Line::from(format!("kernel {}  sock {}", format_bytes(m.kernel as f64), format_bytes(m.sock as f64))),
+            ],
+            None => vec![Line::from(muted("memory.stat n/a".to_string()))],
+        };
+        memory.push(match &stats.memory_events {
+            Some(e) => {
+                let style = if e.oom_kill > 0 || e.oom > 0 {
+                    Style::default().fg(Color::Red).add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                };
+                Line::from(Span::styled(format!("oom {}  oom_kill {}  high {}  max {}", e.oom, e.oom_kill, e.high, e.max), style))
+            }
+            None => Line::from(muted("memory.events n/a".to_string())),
+        });
 
-        frame.render_widget(cpu_widget, columns[1]);
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .split(inner);
+        frame.render_widget(Paragraph::new(psi), columns[0]);
+        frame.render_widget(Paragraph::new(memory), columns[1]);
     }
 
     fn render_dependencies(&self, frame: &mut Frame, area: Rect, detail: &ServiceDetail) {
@@ -597,11 +1274,41 @@ impl DetailState {
         frame.render_widget(config, area);
     }
 
+    /// Type-specific properties for non-service units (mount points, paths, ...)
+    fn render_type_config(&self, frame: &mut Frame, area: Rect, detail: &ServiceDetail) {
+        use ratatui::text::{Line, Span};
+
+        let lines: Vec<Line> = if detail.type_properties.is_empty() {
+            vec![Line::from(Span::styled("No type-specific properties", Style::default().fg(Color::DarkGray)))]
+        } else {
+            let width = detail.type_properties.iter().map(|(key, _)| key.len()).max().unwrap_or(0) + 2;
+            detail
+                .type_properties
+                .iter()
+                .map(|(key, value)| {
+                    Line::from(vec![
+                        Span::styled(format!("{:<width$}", format!("{}:", key)), Style::default().fg(Color::Cyan)),
+                        Span::styled(value.clone(), Style::default().fg(Color::White)),
+                    ])
+                })
+                .collect()
+        };
+
+        let kind = detail.service.kind.label();
+        let title = format!(" {}{} Configuration ", kind[..1].to_uppercase(), &kind[1..]);
+        let config = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title(title))
+            .wrap(Wrap { trim: false });
+
+        frame.render_widget(config, area);
+    }
+
     fn render_help(&self, frame: &mut Frame, area: Rect, detail: &ServiceDetail) {
         use ratatui::text::{Line, Span};
 
         let is_active = detail.service.is_active();
         let is_enabled = detail.service.enabled;
+        let is_masked = detail.service.is_masked();
 
         // Build help line with conditional formatting
         let mut spans = vec![
@@ -639,6 +1346,42 @@ impl DetailState {
         // Reload - only if active
         if is_active {
             spans.push(Span::styled("[L] Reload | ", Style::default().fg(ratatui::style::Color::DarkGray)));
+            spans.push(Span::styled("[K] Kill | ", Style::default().fg(ratatui::style::Color::DarkGray)));
+        }
+
+        // Resource limits - any unit with a cgroup
+        if detail.service.kind.has_cgroup() {
+            spans.push(Span::styled("[C] Limits | ", Style::default().fg(ratatui::style::Color::DarkGray)));
+        }
+
+        spans.push(Span::styled("[i] Unit file | ", Style::default().fg(ratatui::style::Color::DarkGray)));
+        if detail.shadows_vendor() {
+            spans.push(Span::styled("[d] Vendor diff | ", Style::default().fg(Color::Yellow)));
+        }
+        spans.push(Span::styled("[e] Edit override | ", Style::default().fg(ratatui::style::Color::DarkGray)));
+
+        // Freeze/Thaw - whichever applies
+        if detail.service.is_frozen() {
+            spans.push(Span::styled("[Z] Thaw | ", Style::default().fg(FROZEN)));
+        } else if is_active && detail.service.kind.can_freeze() {
+            spans.push(Span::styled("[Z] Freeze | ", Style::default().fg(ratatui::style::Color::DarkGray)));
+        }
+
+        // Mask/Unmask - whichever applies
+        if is_masked {
+            spans.push(Span::styled("[U] Unmask | ", Style::default().fg(MASKED)));
+        } else {
+            spans.push(Span::styled("[M] Mask | ", Style::default().fg(ratatui::style::Color::DarkGray)));
+        }
+
+        // Reset failed - only if failed
+        if detail.service.is_failed() {
+            spans.push(Span::styled("[F] Reset failed | ", Style::default().fg(ratatui::style::Color::DarkGray)));
+        }
+
+        // Remove - only units created locally
+        if detail.is_removable() {
+            spans.push(Span::styled("[X] Remove | ", Style::default().fg(ratatui::style::Color::Red)));
         }
 
         spans.push(Span::styled("[Esc/←] Back | [q] Quit ", Style::default().fg(ratatui::style::Color::DarkGray)));
@@ -648,46 +1391,30 @@ impl DetailState {
 
         frame.render_widget(help, area);
     }
+}
 
-    fn render_confirmation_dialog(&self, frame: &mut Frame, area: Rect, dialog: &ConfirmationDialog) {
-        // Create dialog area (centered)
-        let dialog_width = 60.min(area.width - 4);
-        let dialog_height = 8.min(area.height - 4);
-        let dialog_x = (area.width - dialog_width) / 2;
-        let dialog_y = (area.height - dialog_height) / 2;
-
-        let dialog_area = Rect {
-            x: area.x + dialog_x,
-            y: area.y + dialog_y,
-            width: dialog_width,
-            height: dialog_height,
-        };
-
-        // Darken background
-        let background = Block::default()
-            .style(Style::default().bg(ratatui::style::Color::DarkGray).fg(ratatui::style::Color::Reset));
-        frame.render_widget(background, area);
-
-        // Dialog box
-        let dialog = Paragraph::new(format!(
-            "{}\n\n[y] Yes  [n] No  [Esc] Cancel",
-            dialog.message
-        ))
-        .block(
-            Block::default()
-                .borders(Borders::ALL)
-                .title(format!(" Confirm {} ", dialog.operation))
-                .border_style(Style::default().fg(ratatui::style::Color::Yellow))
-                .style(
-                    Style::default()
-                        .bg(ratatui::style::Color::Black)
-                        .fg(ratatui::style::Color::White)
-                )
-        )
-        .alignment(ratatui::layout::Alignment::Center)
-        .wrap(Wrap { trim: false });
+/// Binary byte count, e.g. "512 B", "1.5 KiB", "3.2 MiB"
+fn format_bytes(bytes: f64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    let mut value = bytes;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    if unit == 0 {
+        format!("{:.0} B", value)
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
 
-        frame.render_widget(dialog, dialog_area);
+/// Span of the sparkline window, e.g. "(2m 30s)"
+fn format_window(secs: i64) -> String {
+    match secs {
+        s if s < 60 => format!("({}s)", s),
+        s if s % 60 == 0 => format!("({}m)", s / 60),
+        s => format!("({}m {}s)", s / 60, s % 60),
     }
 }
 
@@ -695,9 +1422,36 @@ impl DetailState {
 pub enum DetailAction {
     None,
     GoBack,
-    ViewLogs(String),
+    ViewLogs(String, ServiceScope),
     ExecuteServiceControl {
         service: String,
         operation: String,
+        scope: ServiceScope,
+    },
+    KillUnit {
+        service: String,
+        scope: ServiceScope,
+        target: KillTarget,
+        signal: KillSignal,
+    },
+    /// Show the unit's fragment and drop-ins
+    ViewUnitFile {
+        service: String,
+        scope: ServiceScope,
+        fragment: String,
+        drop_ins: Vec<String>,
+    },
+    /// Compare the packaged unit file with the local copy hiding it
+    VendorDiff {
+        service: String,
+        scope: ServiceScope,
+        vendor: String,
+        local: String,
+        notes: Vec<String>,
+    },
+    /// Open the unit's override.conf in $EDITOR
+    EditOverride {
+        service: String,
+        scope: ServiceScope,
     },
 }
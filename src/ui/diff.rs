@@ -0,0 +1,113 @@
+// Diff view - line changes made to a unit file or drop-in
+
+use crate::diff::{diff_stats, DiffLine};
+use crate::events::Action;
+use crate::systemd::ServiceScope;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+#[derive(Debug)]
+pub struct DiffState {
+    pub title: String,
+    pub lines: Vec<DiffLine>,
+    pub scroll: usize,
+    /// Unit to return to when the view closes
+    pub unit: String,
+    pub scope: ServiceScope,
+    /// Extra flags shown next to the change summary
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum DiffAction {
+    None,
+    GoBack(String, ServiceScope),
+}
+
+impl DiffState {
+    pub fn new(title: String, unit: String, scope: ServiceScope, lines: Vec<DiffLine>) -> Self {
+        Self {
+            title,
+            lines,
+            scroll: 0,
+            unit,
+            scope,
+            notes: Vec::new(),
+        }
+    }
+
+    pub fn handle_action(&mut self, action: Action) -> DiffAction {
+        let last = self.lines.len().saturating_sub(1);
+        match action {
+            Action::GoBack | Action::Back => return DiffAction::GoBack(self.unit.clone(), self.scope),
+            Action::MoveUp => self.scroll = self.scroll.saturating_sub(1),
+            Action::MoveDown => self.scroll = (self.scroll + 1).min(last),
+            Action::MoveTop => self.scroll = 0,
+            Action::MoveBottom => self.scroll = last,
+            _ => {}
+        }
+        DiffAction::None
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Header
+                Constraint::Min(0),    // Diff
+                Constraint::Length(1), // Help footer
+            ])
+            .split(area);
+
+        let (added, removed) = diff_stats(&self.lines);
+        let mut summary = if added == 0 && removed == 0 {
+            "No changes".to_string()
+        } else {
+            format!("{} added, {} removed", added, removed)
+        };
+        if !self.notes.is_empty() {
+            summary = format!("{} | {}", summary, self.notes.join(", "));
+        }
+        let header = Paragraph::new(summary)
+            .style(Style::default().fg(Color::Yellow))
+            .alignment(ratatui::layout::Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).title(format!(" {} ", self.title)));
+        frame.render_widget(header, chunks[0]);
+
+        let lines: Vec<Line> = self
+            .lines
+            .iter()
+            .skip(self.scroll)
+            .map(diff_line)
+            .collect();
+
+        let body = Paragraph::new(lines).block(Block::default().borders(Borders::ALL));
+        frame.render_widget(body, chunks[1]);
+
+        let help = Paragraph::new("[↑↓/jk] Scroll | [g/G] Top/bottom | [Esc] Back to unit")
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(ratatui::layout::Alignment::Center);
+        frame.render_widget(help, chunks[2]);
+    }
+}
+
+/// One diff line colored by kind, with its +/- marker
+pub fn diff_line(line: &DiffLine) -> Line<'static> {
+    let style = match line {
+        DiffLine::Added(_) => Style::default().fg(Color::Green),
+        DiffLine::Removed(_) => Style::default().fg(Color::Red),
+        DiffLine::Same(_) => Style::default().fg(Color::Gray),
+        DiffLine::Hunk(header) => {
+            return Line::from(Span::styled(header.clone(), Style::default().fg(Color::Cyan)));
+        }
+    };
+    Line::from(vec![
+        Span::styled(format!("{} ", line.marker()), style.add_modifier(Modifier::BOLD)),
+        Span::styled(line.text().to_string(), style),
+    ])
+}
@@ -57,15 +57,44 @@ impl HelpState {
             Line::from("  g/G           - Jump to top/bottom"),
             Line::from("  Enter         - Select item"),
             Line::from("  Esc           - Go back"),
+            Line::from("  Tab/Shift+Tab - Switch list: Services/Timers/Sockets/Jobs"),
             Line::from(""),
             Line::from(vec![
                 Span::styled("Dashboard", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
                 Span::raw(": "),
             ]),
             Line::from("  a/r/s/f       - Filter: All/Running/Stopped/Failed"),
+            Line::from("  u             - Cycle unit type: service/all/socket/target/mount/..."),
+            Line::from("  m             - Toggle scope: all/system/user"),
             Line::from("  /             - Search services"),
             Line::from("  c             - Clear search"),
+            Line::from("  v             - Toggle CPU/memory/tasks/uptime columns"),
+            Line::from("  o / Shift+O   - Cycle sort column / reverse sort order"),
             Line::from("  l             - View logs for selected service"),
+            Line::from("  !             - Run a command as a transient service or scope"),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Timers", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                Span::raw(": "),
+            ]),
+            Line::from("  Enter         - Open the unit the timer triggers"),
+            Line::from("  Shift+S/T     - Start/stop timer"),
+            Line::from("  m             - Toggle scope"),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Sockets", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                Span::raw(": "),
+            ]),
+            Line::from("  Enter         - Open the unit the socket activates"),
+            Line::from("  Shift+S/T     - Start/stop socket"),
+            Line::from("  m             - Toggle scope"),
+            Line::from(""),
+            Line::from(vec![
+                Span::styled("Jobs", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
+                Span::raw(": "),
+            ]),
+            Line::from("  Enter         - Open the job's unit"),
+            Line::from("  x             - Cancel selected job"),
             Line::from(""),
             Line::from(vec![
                 Span::styled("Service Control", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
@@ -76,6 +105,16 @@ impl HelpState {
             Line::from("  Shift+R       - Restart service"),
             Line::from("  Shift+E       - Enable service"),
             Line::from("  Shift+D       - Disable service"),
+            Line::from("  Shift+M/U     - Mask/unmask unit"),
+            Line::from("  Shift+F       - Reset failed state"),
+            Line::from("  Shift+K       - Send signal (HUP/TERM/KILL/USR1/USR2)"),
+            Line::from("  Shift+Z       - Freeze/thaw unit processes"),
+            Line::from("  Shift+C       - Edit resource limits (Memory/CPU/Tasks/IO)"),
+            Line::from("  e             - Edit drop-in override in $EDITOR, then reload"),
+            Line::from("  i             - View unit file, drop-ins and effective config"),
+            Line::from("  d             - Diff vendor unit file against the local copy in /etc"),
+            Line::from("  Shift+X       - Remove a locally created unit (type its name to confirm)"),
+            Line::from("  (user units go to the session manager, without polkit)"),
             Line::from(""),
             Line::from(vec![
                 Span::styled("Logs View", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)),
@@ -0,0 +1,255 @@
+// Jobs view - systemd job queue with cancel support
+
+use crate::events::Action;
+use crate::systemd::{Job, ServiceScope};
+use crate::ui::detail::ConfirmationDialog;
+use crate::ui::tabs::{tab_title, ListTab};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+#[derive(Debug)]
+pub struct JobsState {
+    pub jobs: Vec<Job>,
+    pub table_state: TableState,
+    pub loading: bool,
+    pub confirmation_dialog: Option<ConfirmationDialog>,
+    /// Job the confirmation dialog is about
+    pending_cancel: Option<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum JobsAction {
+    None,
+    GoBack,
+    /// Open the detail view of the job's unit
+    ViewUnit(String, ServiceScope),
+    CancelJob {
+        id: u32,
+        unit: String,
+        scope: ServiceScope,
+    },
+}
+
+impl Default for JobsState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl JobsState {
+    pub fn new() -> Self {
+        let mut table_state = TableState::default();
+        table_state.select(Some(0));
+
+        Self {
+            jobs: Vec::new(),
+            table_state,
+            loading: true,
+            confirmation_dialog: None,
+            pending_cancel: None,
+        }
+    }
+
+    pub fn set_jobs(&mut self, mut jobs: Vec<Job>) {
+        // Queue order: lower ids were queued first
+        jobs.sort_by_key(|j| (j.scope.label(), j.id));
+
+        // Keep the same job selected while the queue shifts
+        let selected_id = self.get_selected_job().map(|j| (j.id, j.scope));
+        self.jobs = jobs;
+        self.loading = false;
+
+        let idx = selected_id
+            .and_then(|(id, scope)| self.jobs.iter().position(|j| j.id == id && j.scope == scope))
+            .unwrap_or_else(|| self.table_state.selected().unwrap_or(0).min(self.jobs.len().saturating_sub(1)));
+        self.table_state.select(Some(idx));
+
+        // The job being confirmed may have finished on its own
+        if let Some(id) = self.pending_cancel {
+            if !self.jobs.iter().any(|j| j.id == id) {
+                self.confirmation_dialog = None;
+                self.pending_cancel = None;
+            }
+        }
+    }
+
+    pub fn get_selected_job(&self) -> Option<&Job> {
+        self.table_state.selected().and_then(|i| self.jobs.get(i))
+    }
+
+    pub fn handle_action(&mut self, action: Action) -> JobsAction {
+        // Handle confirmation dialog first
+        if self.confirmation_dialog.is_some() {
+            return match action {
+                Action::ConfirmAction => {
+                    let dialog = self.confirmation_dialog.take();
+                    let id = self.pending_cancel.take();
+                    match (dialog, id) {
+                        (Some(dialog), Some(id)) => JobsAction::CancelJob {
+                            id,
+                            unit: dialog.service,
+                            scope: dialog.scope,
+                        },
+                        _ => JobsAction::None,
+                    }
+                }
+                Action::CancelAction | Action::GoBack => {
+                    self.confirmation_dialog = None;
+                    self.pending_cancel = None;
+                    JobsAction::None
+                }
+                _ => JobsAction::None,
+            };
+        }
+
+        match action {
+            Action::GoBack => return JobsAction::GoBack,
+            Action::MoveUp => self.move_selection(-1),
+            Action::MoveDown => self.move_selection(1),
+            Action::MoveTop => self.table_state.select(Some(0)),
+            Action::MoveBottom if !self.jobs.is_empty() => {
+                self.table_state.select(Some(self.jobs.len() - 1));
+            }
+            Action::Select => {
+                if let Some(job) = self.get_selected_job() {
+                    return JobsAction::ViewUnit(job.unit.clone(), job.scope);
+                }
+            }
+            Action::CancelJob => {
+                if let Some(job) = self.get_selected_job().cloned() {
+                    let message = format!("Cancel {} job {} for '{}'?", job.job_type, job.id, job.unit);
+                    self.confirmation_dialog = Some(ConfirmationDialog {
+                        service: job.unit.clone(),
+                        operation: "cancel job".to_string(),
+                        scope: job.scope,
+                        message,
+                        confirmed: false,
+                    });
+                    self.pending_cancel = Some(job.id);
+                }
+            }
+            _ => {}
+        }
+
+        JobsAction::None
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        if self.jobs.is_empty() {
+            return;
+        }
+
+        let current = self.table_state.selected().unwrap_or(0);
+        let new_index = if delta < 0 {
+            current.saturating_sub(delta.unsigned_abs())
+        } else {
+            (current + delta as usize).min(self.jobs.len() - 1)
+        };
+
+        self.table_state.select(Some(new_index));
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, show_footer: bool) {
+        if let Some(dialog) = &self.confirmation_dialog {
+            dialog.render(frame, area);
+            return;
+        }
+
+        let mut constraints = vec![
+            Constraint::Length(3), // Header
+            Constraint::Min(0),    // Jobs table
+        ];
+        if show_footer {
+            constraints.push(Constraint::Length(1)); // Help footer
+        }
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(constraints)
+            .split(area);
+
+        self.render_header(frame, chunks[0]);
+        self.render_table(frame, chunks[1]);
+
+        if show_footer {
+            let help = Paragraph::new(
+                "[Enter] Unit details | [↑↓/jk] Navigate | [x] Cancel job | [Tab] Next list | [q] Quit",
+            )
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(ratatui::layout::Alignment::Center);
+            frame.render_widget(help, chunks[2]);
+        }
+    }
+
+    fn render_header(&self, frame: &mut Frame, area: Rect) {
+        let text = if self.loading {
+            "Loading job queue...".to_string()
+        } else if self.jobs.is_empty() {
+            "Job queue is empty - all units are settled".to_string()
+        } else {
+            let waiting = self.jobs.iter().filter(|j| j.is_waiting()).count();
+            format!(
+                "Queued: {} | Running: {} | Waiting: {}",
+                self.jobs.len(),
+                self.jobs.len() - waiting,
+                waiting
+            )
+        };
+
+        let header = Paragraph::new(text)
+            .style(Style::default().fg(Color::Yellow))
+            .alignment(ratatui::layout::Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).title(tab_title(ListTab::Jobs)));
+
+        frame.render_widget(header, area);
+    }
+
+    fn render_table(&mut self, frame: &mut Frame, area: Rect) {
+        let rows: Vec<Row> = self
+            .jobs
+            .iter()
+            .map(|job| {
+                let state_color = if job.is_waiting() { Color::Yellow } else { Color::Green };
+
+                Row::new(vec![
+                    Cell::from(job.id.to_string()),
+                    Cell::from(job.unit.clone()),
+                    Cell::from(job.scope.label()).style(Style::default().fg(
+                        if job.scope == ServiceScope::User { Color::LightBlue } else { Color::Gray },
+                    )),
+                    Cell::from(job.job_type.clone()),
+                    Cell::from(job.state.clone()).style(Style::default().fg(state_color)),
+                ])
+            })
+            .collect();
+
+        let widths = [
+            Constraint::Length(8),
+            Constraint::Percentage(50),
+            Constraint::Length(7),
+            Constraint::Percentage(20),
+            Constraint::Percentage(15),
+        ];
+
+        let table = Table::new(rows, widths)
+            .header(
+                Row::new(vec!["Job", "Unit", "Scope", "Type", "State"])
+                    .style(
+                        Style::default()
+                            .bg(Color::DarkGray)
+                            .fg(Color::White)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                    .bottom_margin(1),
+            )
+            .block(Block::default().title(" Job Queue ").borders(Borders::ALL))
+            .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+            .highlight_symbol(">> ");
+
+        frame.render_stateful_widget(table, area, &mut self.table_state);
+    }
+}
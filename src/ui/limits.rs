@@ -0,0 +1,167 @@
+// Resource limit editor - runtime cgroup limits via SetUnitProperties
+
+use crate::systemd::{ResourceLimit, ServiceDetail, ServiceScope};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+#[derive(Debug, Clone)]
+pub struct LimitField {
+    pub limit: ResourceLimit,
+    /// Value the unit had when the editor opened
+    pub original: String,
+    pub input: String,
+}
+
+#[derive(Debug)]
+pub struct LimitsEditor {
+    pub service: String,
+    pub scope: ServiceScope,
+    pub fields: Vec<LimitField>,
+    pub selected: usize,
+    /// Runtime changes are lost on reboot; persistent ones write a drop-in
+    pub runtime: bool,
+    pub error: Option<String>,
+}
+
+impl LimitsEditor {
+    /// Open the editor prefilled with the unit's current limits
+    pub fn from_detail(detail: &ServiceDetail) -> Self {
+        let fields = ResourceLimit::ALL
+            .iter()
+            .map(|limit| {
+                let value = limit.format(limit.current(detail));
+                LimitField {
+                    limit: *limit,
+                    original: value.clone(),
+                    input: value,
+                }
+            })
+            .collect();
+
+        Self {
+            service: detail.service.name.clone(),
+            scope: detail.service.scope,
+            fields,
+            selected: 0,
+            runtime: true,
+            error: None,
+        }
+    }
+
+    pub fn handle_char(&mut self, c: char) {
+        self.error = None;
+        self.fields[self.selected].input.push(c);
+    }
+
+    pub fn backspace(&mut self) {
+        self.error = None;
+        self.fields[self.selected].input.pop();
+    }
+
+    pub fn next_field(&mut self) {
+        self.selected = (self.selected + 1) % self.fields.len();
+    }
+
+    pub fn prev_field(&mut self) {
+        self.selected = (self.selected + self.fields.len() - 1) % self.fields.len();
+    }
+
+    pub fn toggle_runtime(&mut self) {
+        self.runtime = !self.runtime;
+    }
+
+    /// Parse the edited fields; on error, select the bad field and keep editing
+    pub fn changes(&mut self) -> Option<Vec<(ResourceLimit, u64)>> {
+        let mut changes = Vec::new();
+
+        for (i, field) in self.fields.iter().enumerate() {
+            if field.input.trim() == field.original {
+                continue;
+            }
+            match field.limit.parse(&field.input) {
+                Ok(value) => changes.push((field.limit, value)),
+                Err(e) => {
+                    self.selected = i;
+                    self.error = Some(e.to_string());
+                    return None;
+                }
+            }
+        }
+
+        if changes.is_empty() {
+            self.error = Some("No limits changed".to_string());
+            return None;
+        }
+
+        Some(changes)
+    }
+
+    /// Render the editor centered over a darkened area
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let dialog_width = 72.min(area.width - 4);
+        let dialog_height = (self.fields.len() as u16 + 8).min(area.height - 4);
+        let dialog_area = Rect {
+            x: area.x + (area.width - dialog_width) / 2,
+            y: area.y + (area.height - dialog_height) / 2,
+            width: dialog_width,
+            height: dialog_height,
+        };
+
+        let background = Block::default()
+            .style(Style::default().bg(Color::DarkGray).fg(Color::Reset));
+        frame.render_widget(background, area);
+
+        let mut lines = vec![Line::from("")];
+        for (i, field) in self.fields.iter().enumerate() {
+            let selected = i == self.selected;
+            let changed = field.input.trim() != field.original;
+
+            let value_style = if selected {
+                Style::default().fg(Color::Black).bg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else if changed {
+                Style::default().fg(Color::Yellow)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            let cursor = if selected { "▏" } else { " " };
+
+            lines.push(Line::from(vec![
+                Span::styled(format!(" {:<11}", field.limit.label()), Style::default().fg(Color::Cyan)),
+                Span::styled(format!(" {:<12}{}", field.input, cursor), value_style),
+                Span::styled(format!("  {}", field.limit.hint()), Style::default().fg(Color::DarkGray)),
+            ]));
+        }
+
+        lines.push(Line::from(""));
+        let mode = if self.runtime {
+            Span::styled("runtime (until reboot)", Style::default().fg(Color::Green).add_modifier(Modifier::BOLD))
+        } else {
+            Span::styled("persistent (writes a drop-in)", Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+        };
+        lines.push(Line::from(vec![Span::raw(" Apply: "), mode]));
+
+        match &self.error {
+            Some(error) => lines.push(Line::from(Span::styled(format!(" ✗ {}", error), Style::default().fg(Color::Red)))),
+            None => lines.push(Line::from("")),
+        }
+        lines.push(Line::from(Span::styled(
+            " [↑↓/Tab] Field  [Ctrl+R] Runtime/persistent  [Enter] Apply  [Esc] Cancel",
+            Style::default().fg(Color::DarkGray),
+        )));
+
+        let dialog = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(format!(" Resource limits: {} ", self.service))
+                .border_style(Style::default().fg(Color::Yellow))
+                .style(Style::default().bg(Color::Black).fg(Color::White)),
+        );
+
+        frame.render_widget(dialog, dialog_area);
+    }
+}
@@ -1,7 +1,7 @@
 // Logs view - log streaming viewer
 
 use crate::events::Action;
-use crate::systemd::{JournalReader, LogLine};
+use crate::systemd::{JournalReader, LogLine, ServiceScope};
 use crate::ui::priority_color;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
@@ -72,6 +72,7 @@ impl LogFilters {
 #[derive(Debug)]
 pub struct LogsState {
     pub service_name: String,
+    pub scope: ServiceScope,
     pub lines: Vec<LogLine>,
     pub offset: usize,
     pub follow_mode: bool,
@@ -83,9 +84,10 @@ pub struct LogsState {
 }
 
 impl LogsState {
-    pub fn new(service_name: String) -> Self {
+    pub fn new(service_name: String, scope: ServiceScope) -> Self {
         Self {
             service_name,
+            scope,
             lines: Vec::new(),
             offset: 0,
             follow_mode: true,
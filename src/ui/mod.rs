@@ -2,17 +2,33 @@
 
 pub mod dashboard;
 pub mod detail;
+pub mod diff;
 pub mod logs;
 pub mod help;
+pub mod jobs;
+pub mod limits;
 pub mod new_service;
+pub mod run;
+pub mod sockets;
 pub mod styles;
+pub mod tabs;
+pub mod timers;
+pub mod unit_file;
 
 #[cfg(test)]
 mod tests;
 
-pub use dashboard::{DashboardState, FilterType};
-pub use detail::{DetailAction, DetailState};
+pub use dashboard::{ColumnSettings, DashboardState, FilterType, SortField, UnitKindFilter};
+pub use detail::{ConfirmationDialog, DetailAction, DetailState, RemoveDialog};
+pub use diff::{DiffAction, DiffState};
 pub use logs::{LogsAction, LogsState};
 pub use help::HelpState;
+pub use jobs::{JobsAction, JobsState};
+pub use limits::LimitsEditor;
 pub use new_service::NewServiceForm;
+pub use run::{RunDialog, RunField};
+pub use sockets::{SocketsAction, SocketsState};
 pub use styles::*;
+pub use tabs::ListTab;
+pub use timers::{TimersAction, TimersState};
+pub use unit_file::{UnitFileAction, UnitFilePane, UnitFileView};
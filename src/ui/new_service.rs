@@ -1,6 +1,13 @@
 // New service creation form
 
+use crate::diff::{diff_lines, unified, DiffLine};
 use crate::events::Action;
+use crate::systemd::dropin;
+use crate::systemd::lint::{lint_unit, validate_unit_name, LintIssue, Severity, SERVICE_TYPES};
+use crate::systemd::unit_file::{parse_line, UnitLine};
+use crate::systemd::{ResourceLimit, ServiceScope, UnitKind};
+use crate::ui::diff::diff_line;
+use crate::ui::unit_file::highlight_line;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -8,15 +15,145 @@ use ratatui::{
     widgets::{Block, Borders, Paragraph, Wrap},
     Frame,
 };
+use std::path::{Path, PathBuf};
 
-#[derive(Debug, Clone, PartialEq)]
+const SCOPE_OPTIONS: &[&str] = &["user", "system"];
+const RESTART_OPTIONS: &[&str] = &["no", "on-failure", "always", "on-abnormal", "on-abort"];
+const HARDENING_OPTIONS: &[&str] = &["none", "basic", "strict"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
 enum FormField {
     Name,
     Description,
+    Scope,
+    Type,
     ExecStart,
+    ExecStartPre,
     WorkingDirectory,
+    User,
+    Group,
     Restart,
+    RestartSec,
     Environment,
+    EnvironmentFile,
+    MemoryMax,
+    CPUQuota,
+    TasksMax,
+    Hardening,
+    OnCalendar,
+}
+
+impl FormField {
+    /// Fields in form order
+    const ALL: [FormField; 18] = [
+        FormField::Name,
+        FormField::Description,
+        FormField::Scope,
+        FormField::Type,
+        FormField::ExecStart,
+        FormField::ExecStartPre,
+        FormField::WorkingDirectory,
+        FormField::User,
+        FormField::Group,
+        FormField::Restart,
+        FormField::RestartSec,
+        FormField::Environment,
+        FormField::EnvironmentFile,
+        FormField::MemoryMax,
+        FormField::CPUQuota,
+        FormField::TasksMax,
+        FormField::Hardening,
+        FormField::OnCalendar,
+    ];
+
+    /// Unit file setting the field writes, for showing lint issues inline
+    fn key(&self) -> &'static str {
+        match self {
+            FormField::Name => "Name",
+            FormField::Description => "Description",
+            FormField::Scope => "Scope",
+            FormField::Type => "Type",
+            FormField::ExecStart => "ExecStart",
+            FormField::ExecStartPre => "ExecStartPre",
+            FormField::WorkingDirectory => "WorkingDirectory",
+            FormField::User => "User",
+            FormField::Group => "Group",
+            FormField::Restart => "Restart",
+            FormField::RestartSec => "RestartSec",
+            FormField::Environment => "Environment",
+            FormField::EnvironmentFile => "EnvironmentFile",
+            FormField::MemoryMax => "MemoryMax",
+            FormField::CPUQuota => "CPUQuota",
+            FormField::TasksMax => "TasksMax",
+            FormField::Hardening => "ProtectSystem",
+            FormField::OnCalendar => "OnCalendar",
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            FormField::Name => "Service Name",
+            FormField::Description => "Description",
+            FormField::Scope => "Scope",
+            FormField::Type => "Type",
+            FormField::ExecStart => "ExecStart",
+            FormField::ExecStartPre => "ExecStartPre",
+            FormField::WorkingDirectory => "WorkingDirectory",
+            FormField::User => "User",
+            FormField::Group => "Group",
+            FormField::Restart => "Restart Policy",
+            FormField::RestartSec => "RestartSec",
+            FormField::Environment => "Environment",
+            FormField::EnvironmentFile => "EnvironmentFile",
+            FormField::MemoryMax => "MemoryMax",
+            FormField::CPUQuota => "CPUQuota",
+            FormField::TasksMax => "TasksMax",
+            FormField::Hardening => "Hardening",
+            FormField::OnCalendar => "Timer OnCalendar",
+        }
+    }
+
+    fn placeholder(&self) -> &'static str {
+        match self {
+            FormField::Name => "my-app",
+            FormField::Description => "My custom service",
+            FormField::ExecStart => "/usr/bin/myapp --daemon",
+            FormField::ExecStartPre => "optional, e.g. /usr/bin/myapp --check-config",
+            FormField::WorkingDirectory => "optional, e.g. /home/user/myapp",
+            FormField::User => "optional, system scope only",
+            FormField::Group => "optional, system scope only",
+            FormField::RestartSec => "optional, e.g. 5s",
+            FormField::Environment => "optional, KEY=value KEY2=value2",
+            FormField::EnvironmentFile => "optional, e.g. /etc/myapp.env",
+            FormField::MemoryMax => "optional, e.g. 512M",
+            FormField::CPUQuota => "optional, e.g. 50%",
+            FormField::TasksMax => "optional, e.g. 64",
+            FormField::OnCalendar => "optional, e.g. daily or *-*-* 02:00:00 (adds a .timer)",
+            FormField::Scope | FormField::Type | FormField::Restart | FormField::Hardening => "",
+        }
+    }
+
+    /// Choices for fields picked with ↑↓ instead of typed
+    fn options(&self) -> Option<&'static [&'static str]> {
+        match self {
+            FormField::Scope => Some(SCOPE_OPTIONS),
+            FormField::Type => Some(SERVICE_TYPES),
+            FormField::Restart => Some(RESTART_OPTIONS),
+            FormField::Hardening => Some(HARDENING_OPTIONS),
+            _ => None,
+        }
+    }
+
+    /// Heading printed above the field's group
+    fn group(&self) -> Option<&'static str> {
+        match self {
+            FormField::Name => Some("Basics"),
+            FormField::User => Some("Runtime"),
+            FormField::MemoryMax => Some("Resources & Hardening"),
+            FormField::OnCalendar => Some("Schedule"),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -24,16 +161,31 @@ pub struct NewServiceForm {
     // Form fields
     pub name: String,
     pub description: String,
+    pub scope: ServiceScope,
+    pub service_type: String,
     pub exec_start: String,
+    pub exec_start_pre: String,
     pub working_directory: String,
+    pub user: String,
+    pub group: String,
     pub restart: String, // "no", "on-failure", "always"
+    pub restart_sec: String,
     pub environment: String, // Space-separated KEY=VALUE pairs
+    pub environment_file: String,
+    pub memory_max: String,
+    pub cpu_quota: String,
+    pub tasks_max: String,
+    pub hardening: String, // "none", "basic", "strict"
+    /// Schedule for a companion .timer; empty means no timer
+    pub on_calendar: String,
 
     // UI state
     current_field: FormField,
-    restart_options: Vec<&'static str>,
-    restart_selected: usize,
     error_message: Option<String>,
+    /// Linter findings from the last validation
+    pub lint_issues: Vec<LintIssue>,
+    /// Changes to existing unit files (file name, diff), shown before they are overwritten
+    pub overwrite_diff: Option<Vec<(String, Vec<DiffLine>)>>,
 }
 
 impl Default for NewServiceForm {
@@ -41,14 +193,26 @@ impl Default for NewServiceForm {
         Self {
             name: String::new(),
             description: String::new(),
+            scope: ServiceScope::User,
+            service_type: "simple".to_string(),
             exec_start: String::new(),
+            exec_start_pre: String::new(),
             working_directory: String::new(),
+            user: String::new(),
+            group: String::new(),
             restart: "no".to_string(),
+            restart_sec: String::new(),
             environment: String::new(),
+            environment_file: String::new(),
+            memory_max: String::new(),
+            cpu_quota: String::new(),
+            tasks_max: String::new(),
+            hardening: "none".to_string(),
+            on_calendar: String::new(),
             current_field: FormField::Name,
-            restart_options: vec!["no", "on-failure", "always", "on-abnormal", "on-abort"],
-            restart_selected: 0,
             error_message: None,
+            lint_issues: Vec::new(),
+            overwrite_diff: None,
         }
     }
 }
@@ -61,6 +225,8 @@ impl NewServiceForm {
     pub fn handle_key(&mut self, key: char) -> Option<Action> {
         // Clear error on any input
         self.error_message = None;
+        self.lint_issues.clear();
+        self.overwrite_diff = None;
 
         match key {
             '\n' => {
@@ -89,25 +255,19 @@ impl NewServiceForm {
     }
 
     pub fn handle_special_key(&mut self, key: &str) -> Option<Action> {
+        let is_selector = self.current_field.options().is_some();
         match key {
-            "up" => {
-                if self.current_field == FormField::Restart {
-                    self.restart_selected = self.restart_selected.saturating_sub(1);
-                    self.restart = self.restart_options[self.restart_selected].to_string();
-                } else {
-                    self.prev_field();
-                }
+            "up" | "down" if is_selector => {
+                self.overwrite_diff = None;
+                self.cycle_option(if key == "up" { -1 } else { 1 });
+                None
+            }
+            "up" | "backtab" => {
+                self.prev_field();
                 None
             }
             "down" => {
-                if self.current_field == FormField::Restart {
-                    if self.restart_selected < self.restart_options.len() - 1 {
-                        self.restart_selected += 1;
-                        self.restart = self.restart_options[self.restart_selected].to_string();
-                    }
-                } else {
-                    self.next_field();
-                }
+                self.next_field();
                 None
             }
             "esc" => Some(Action::Back),
@@ -115,48 +275,101 @@ impl NewServiceForm {
         }
     }
 
+    /// Value of a selector field
+    fn option_value(&self, field: FormField) -> &str {
+        match field {
+            FormField::Scope => self.scope.label(),
+            FormField::Type => &self.service_type,
+            FormField::Restart => &self.restart,
+            FormField::Hardening => &self.hardening,
+            _ => "",
+        }
+    }
+
+    /// Step through a selector's options without wrapping
+    fn cycle_option(&mut self, delta: isize) {
+        let field = self.current_field;
+        let Some(options) = field.options() else {
+            return;
+        };
+        let current = options.iter().position(|o| *o == self.option_value(field)).unwrap_or(0);
+        let next = current.saturating_add_signed(delta).min(options.len() - 1);
+        let value = options[next];
+
+        match field {
+            FormField::Scope => {
+                self.scope = if value == "system" { ServiceScope::System } else { ServiceScope::User };
+            }
+            FormField::Type => self.service_type = value.to_string(),
+            FormField::Restart => self.restart = value.to_string(),
+            FormField::Hardening => self.hardening = value.to_string(),
+            _ => {}
+        }
+    }
+
+    /// Text of a typed field
+    fn text(&self, field: FormField) -> &str {
+        match field {
+            FormField::Name => &self.name,
+            FormField::Description => &self.description,
+            FormField::ExecStart => &self.exec_start,
+            FormField::ExecStartPre => &self.exec_start_pre,
+            FormField::WorkingDirectory => &self.working_directory,
+            FormField::User => &self.user,
+            FormField::Group => &self.group,
+            FormField::RestartSec => &self.restart_sec,
+            FormField::Environment => &self.environment,
+            FormField::EnvironmentFile => &self.environment_file,
+            FormField::MemoryMax => &self.memory_max,
+            FormField::CPUQuota => &self.cpu_quota,
+            FormField::TasksMax => &self.tasks_max,
+            FormField::OnCalendar => &self.on_calendar,
+            _ => self.option_value(field),
+        }
+    }
+
+    fn text_mut(&mut self, field: FormField) -> Option<&mut String> {
+        match field {
+            FormField::Name => Some(&mut self.name),
+            FormField::Description => Some(&mut self.description),
+            FormField::ExecStart => Some(&mut self.exec_start),
+            FormField::ExecStartPre => Some(&mut self.exec_start_pre),
+            FormField::WorkingDirectory => Some(&mut self.working_directory),
+            FormField::User => Some(&mut self.user),
+            FormField::Group => Some(&mut self.group),
+            FormField::RestartSec => Some(&mut self.restart_sec),
+            FormField::Environment => Some(&mut self.environment),
+            FormField::EnvironmentFile => Some(&mut self.environment_file),
+            FormField::MemoryMax => Some(&mut self.memory_max),
+            FormField::CPUQuota => Some(&mut self.cpu_quota),
+            FormField::TasksMax => Some(&mut self.tasks_max),
+            FormField::OnCalendar => Some(&mut self.on_calendar),
+            // Selectors are handled by up/down arrows
+            FormField::Scope | FormField::Type | FormField::Restart | FormField::Hardening => None,
+        }
+    }
+
     fn insert_char(&mut self, c: char) {
-        match self.current_field {
-            FormField::Name => self.name.push(c),
-            FormField::Description => self.description.push(c),
-            FormField::ExecStart => self.exec_start.push(c),
-            FormField::WorkingDirectory => self.working_directory.push(c),
-            FormField::Restart => {}, // Handled by up/down arrows
-            FormField::Environment => self.environment.push(c),
+        if let Some(text) = self.text_mut(self.current_field) {
+            text.push(c);
         }
     }
 
     fn delete_char(&mut self) {
-        match self.current_field {
-            FormField::Name => { self.name.pop(); },
-            FormField::Description => { self.description.pop(); },
-            FormField::ExecStart => { self.exec_start.pop(); },
-            FormField::WorkingDirectory => { self.working_directory.pop(); },
-            FormField::Restart => {}, // Handled by up/down arrows
-            FormField::Environment => { self.environment.pop(); },
+        if let Some(text) = self.text_mut(self.current_field) {
+            text.pop();
         }
     }
 
     fn next_field(&mut self) {
-        self.current_field = match self.current_field {
-            FormField::Name => FormField::Description,
-            FormField::Description => FormField::ExecStart,
-            FormField::ExecStart => FormField::WorkingDirectory,
-            FormField::WorkingDirectory => FormField::Restart,
-            FormField::Restart => FormField::Environment,
-            FormField::Environment => FormField::Name, // Loop back
-        };
+        let idx = FormField::ALL.iter().position(|f| *f == self.current_field).unwrap_or(0);
+        // Loop back after the last field
+        self.current_field = FormField::ALL[(idx + 1) % FormField::ALL.len()];
     }
 
     fn prev_field(&mut self) {
-        self.current_field = match self.current_field {
-            FormField::Name => FormField::Environment,
-            FormField::Description => FormField::Name,
-            FormField::ExecStart => FormField::Description,
-            FormField::WorkingDirectory => FormField::ExecStart,
-            FormField::Restart => FormField::WorkingDirectory,
-            FormField::Environment => FormField::Restart,
-        };
+        let idx = FormField::ALL.iter().position(|f| *f == self.current_field).unwrap_or(0);
+        self.current_field = FormField::ALL[(idx + FormField::ALL.len() - 1) % FormField::ALL.len()];
     }
 
     pub fn validate(&mut self) -> Result<(), String> {
@@ -175,23 +388,167 @@ impl NewServiceForm {
             return Err("ExecStart command is required".to_string());
         }
 
+        validate_unit_name(&format!("{}.service", self.name))?;
+
+        // The user manager cannot switch to another account
+        if self.scope == ServiceScope::User && !(self.user.trim().is_empty() && self.group.trim().is_empty()) {
+            return Err("User= and Group= need a system-scope service".to_string());
+        }
+
+        // Resource limits use the same syntax as the limits editor
+        for (limit, input) in [
+            (ResourceLimit::MemoryMax, &self.memory_max),
+            (ResourceLimit::CPUQuota, &self.cpu_quota),
+            (ResourceLimit::TasksMax, &self.tasks_max),
+        ] {
+            if !input.trim().is_empty() {
+                limit.parse(input).map_err(|e| e.to_string())?;
+            }
+        }
+
         // Description is recommended but not required
         if self.description.trim().is_empty() {
-            self.description = format!("User service: {}", self.name);
+            let scope = match self.scope {
+                ServiceScope::System => "System",
+                ServiceScope::User => "User",
+            };
+            self.description = format!("{} service: {}", scope, self.name);
+        }
+
+        // Lint the files that would be written; errors block creation
+        self.lint_issues = lint_unit(&self.generate_service_file(), Some(UnitKind::Service), false);
+        if let Some(timer) = self.generate_timer_file() {
+            self.lint_issues.extend(lint_unit(&timer, Some(UnitKind::Timer), false));
+        }
+        let errors = self.lint_issues.iter().filter(|issue| issue.is_error()).count();
+        if errors > 0 {
+            return Err(format!("{} error(s) in the unit file, see the marked fields", errors));
         }
 
         Ok(())
     }
 
+    /// Where the service file will be written
+    pub fn target_path(&self) -> anyhow::Result<PathBuf> {
+        Ok(dropin::unit_dir(self.scope)?.join(format!("{}.service", self.name)))
+    }
+
+    /// Guard against silently replacing an existing service or timer file
+    ///
+    /// Returns true when it is fine to write; otherwise stores the diffs for
+    /// review, and the next call (the second Ctrl+S) confirms.
+    pub fn check_overwrite(&mut self) -> bool {
+        match self.target_path() {
+            Ok(path) => path.parent().map_or(true, |dir| self.check_overwrite_in(dir)),
+            Err(_) => true,
+        }
+    }
+
+    /// `check_overwrite` against an explicit unit directory
+    pub fn check_overwrite_in(&mut self, dir: &Path) -> bool {
+        if self.overwrite_diff.is_some() {
+            return true;
+        }
+        let files = [
+            (format!("{}.service", self.name), Some(self.generate_service_file())),
+            (format!("{}.timer", self.name), self.generate_timer_file()),
+        ];
+        let changes: Vec<(String, Vec<DiffLine>)> = files
+            .into_iter()
+            .filter_map(|(file, generated)| {
+                let generated = generated?;
+                let existing = std::fs::read_to_string(dir.join(&file)).ok()?;
+                (existing != generated).then(|| (file, unified(&diff_lines(&existing, &generated), 3)))
+            })
+            .collect();
+        if changes.is_empty() {
+            return true;
+        }
+
+        let paths: Vec<String> = changes.iter().map(|(file, _)| dir.join(file).display().to_string()).collect();
+        let verb = if paths.len() == 1 { "exists" } else { "exist" };
+        self.set_error(format!(
+            "{} already {} - review the changes and press Ctrl+S again to overwrite",
+            paths.join(" and "),
+            verb
+        ));
+        self.overwrite_diff = Some(changes);
+        false
+    }
+
+    /// Unit file settings written from the focused field
+    fn focused_keys(&self) -> Vec<&'static str> {
+        match self.current_field {
+            FormField::Name => vec![],
+            FormField::Scope => vec!["WantedBy"],
+            FormField::Hardening => self
+                .hardening_settings()
+                .iter()
+                .filter_map(|setting| setting.split_once('=').map(|(key, _)| key))
+                .collect(),
+            field => vec![field.key()],
+        }
+    }
+
+    /// Whether a generated line comes from the focused field
+    pub fn is_focused_line(&self, line: &str) -> bool {
+        match parse_line(line) {
+            UnitLine::Setting { key, .. } => self.focused_keys().contains(&key),
+            _ => false,
+        }
+    }
+
+    /// Linter findings for the files as they would be written right now
+    pub fn preview_issues(&self) -> (Vec<LintIssue>, Vec<LintIssue>) {
+        let service = lint_unit(&self.generate_service_file(), Some(UnitKind::Service), false);
+        let timer = self
+            .generate_timer_file()
+            .map(|timer| lint_unit(&timer, Some(UnitKind::Timer), false))
+            .unwrap_or_default();
+        (service, timer)
+    }
+
+    /// Target the unit is installed into
+    fn wanted_by(&self) -> &'static str {
+        match self.scope {
+            ServiceScope::System => "multi-user.target",
+            ServiceScope::User => "default.target",
+        }
+    }
+
+    /// Settings written for the selected hardening preset
+    pub fn hardening_settings(&self) -> &'static [&'static str] {
+        match self.hardening.as_str() {
+            "basic" => &["NoNewPrivileges=yes", "PrivateTmp=yes", "ProtectSystem=full"],
+            "strict" => &[
+                "NoNewPrivileges=yes",
+                "PrivateTmp=yes",
+                "ProtectSystem=strict",
+                "ProtectHome=read-only",
+                "ProtectKernelTunables=yes",
+                "ProtectControlGroups=yes",
+            ],
+            _ => &[],
+        }
+    }
+
     pub fn generate_service_file(&self) -> String {
         let mut content = String::new();
+        let optional = |content: &mut String, key: &str, value: &str| {
+            if !value.trim().is_empty() {
+                content.push_str(&format!("{}={}\n", key, value.trim()));
+            }
+        };
 
         content.push_str("[Unit]\n");
         content.push_str(&format!("Description={}\n", self.description));
         content.push('\n');
 
         content.push_str("[Service]\n");
-        content.push_str("Type=simple\n");
+        content.push_str(&format!("Type={}\n", self.service_type));
+        optional(&mut content, "User", &self.user);
+        optional(&mut content, "Group", &self.group);
+        optional(&mut content, "ExecStartPre", &self.exec_start_pre);
         content.push_str(&format!("ExecStart={}\n", self.exec_start));
 
         if !self.working_directory.trim().is_empty() {
@@ -199,6 +556,7 @@ impl NewServiceForm {
         }
 
         content.push_str(&format!("Restart={}\n", self.restart));
+        optional(&mut content, "RestartSec", &self.restart_sec);
 
         if !self.environment.trim().is_empty() {
             for env_var in self.environment.split_whitespace() {
@@ -207,14 +565,48 @@ impl NewServiceForm {
                 }
             }
         }
+        optional(&mut content, "EnvironmentFile", &self.environment_file);
 
-        content.push('\n');
-        content.push_str("[Install]\n");
-        content.push_str("WantedBy=default.target\n");
+        optional(&mut content, "MemoryMax", &self.memory_max);
+        optional(&mut content, "CPUQuota", &self.cpu_quota);
+        optional(&mut content, "TasksMax", &self.tasks_max);
+
+        for setting in self.hardening_settings() {
+            content.push_str(setting);
+            content.push('\n');
+        }
+
+        // A timer-activated service is started by its timer, not at boot
+        if self.on_calendar.trim().is_empty() {
+            content.push('\n');
+            content.push_str("[Install]\n");
+            content.push_str(&format!("WantedBy={}\n", self.wanted_by()));
+        }
 
         content
     }
 
+    /// Companion .timer activating the service, if a schedule is set
+    pub fn generate_timer_file(&self) -> Option<String> {
+        if self.on_calendar.trim().is_empty() {
+            return None;
+        }
+
+        Some(format!(
+            "[Unit]\n\
+             Description=Timer for {}.service\n\
+             \n\
+             [Timer]\n\
+             OnCalendar={}\n\
+             Persistent=true\n\
+             \n\
+             [Install]\n\
+             WantedBy=timers.target\n",
+            self.name,
+            self.on_calendar.trim()
+        ))
+    }
+
     pub fn render(&self, frame: &mut Frame, area: Rect) {
         let chunks = Layout::default()
             .direction(Direction::Vertical)
@@ -225,66 +617,47 @@ impl NewServiceForm {
             ])
             .split(area);
 
+        let body = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(55), Constraint::Percentage(45)])
+            .split(chunks[1]);
+
         self.render_header(frame, chunks[0]);
-        self.render_form(frame, chunks[1]);
+        self.render_form(frame, body[0]);
+        self.render_preview(frame, body[1]);
         self.render_footer(frame, chunks[2]);
     }
 
     fn render_header(&self, frame: &mut Frame, area: Rect) {
-        let title = Paragraph::new("📝 Create New User Service")
+        let title = Paragraph::new(format!("📝 Create New Service ({} scope)", self.scope.label()))
             .style(Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD))
             .block(Block::default().borders(Borders::ALL));
         frame.render_widget(title, area);
     }
 
-    #[allow(clippy::vec_init_then_push)]
     fn render_form(&self, frame: &mut Frame, area: Rect) {
         let mut lines = vec![];
 
-        // Service Name
-        lines.push(self.render_field_label("Service Name", FormField::Name));
-        lines.push(self.render_field_value(&self.name, FormField::Name, "my-app"));
-        lines.push(Line::from(""));
-
-        // Description
-        lines.push(self.render_field_label("Description", FormField::Description));
-        lines.push(self.render_field_value(&self.description, FormField::Description, "My custom service"));
-        lines.push(Line::from(""));
-
-        // ExecStart
-        lines.push(self.render_field_label("ExecStart (command)", FormField::ExecStart));
-        lines.push(self.render_field_value(&self.exec_start, FormField::ExecStart, "/usr/bin/myapp --daemon"));
-        lines.push(Line::from(""));
-
-        // WorkingDirectory
-        lines.push(self.render_field_label("WorkingDirectory (optional)", FormField::WorkingDirectory));
-        lines.push(self.render_field_value(&self.working_directory, FormField::WorkingDirectory, "/home/user/myapp"));
-        lines.push(Line::from(""));
-
-        // Restart Policy
-        lines.push(self.render_field_label("Restart Policy", FormField::Restart));
-        let restart_line = if self.current_field == FormField::Restart {
-            Line::from(vec![
-                Span::raw("  "),
-                Span::styled(
-                    format!("< {} >", self.restart),
-                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD)
-                ),
-                Span::styled(" (use ↑↓ to change)", Style::default().fg(Color::DarkGray)),
-            ])
-        } else {
-            Line::from(vec![
-                Span::raw("  "),
-                Span::styled(&self.restart, Style::default().fg(Color::White)),
-            ])
-        };
-        lines.push(restart_line);
-        lines.push(Line::from(""));
+        for field in FormField::ALL {
+            if let Some(group) = field.group() {
+                if !lines.is_empty() {
+                    lines.push(Line::from(""));
+                }
+                lines.push(Line::from(Span::styled(
+                    group,
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                )));
+            }
+            lines.push(self.render_field(field));
+            lines.extend(self.render_field_issues(field));
+        }
 
-        // Environment Variables
-        lines.push(self.render_field_label("Environment (optional)", FormField::Environment));
-        lines.push(self.render_field_value(&self.environment, FormField::Environment, "KEY=value KEY2=value2"));
-        lines.push(Line::from(""));
+        // Issues not tied to a field, e.g. a missing ExecStart=
+        for issue in &self.lint_issues {
+            if !FormField::ALL.iter().any(|f| issue.key.as_deref() == Some(f.key())) {
+                lines.push(Self::render_issue(issue));
+            }
+        }
 
         let paragraph = Paragraph::new(lines)
             .block(Block::default().borders(Borders::ALL).title(" Form "))
@@ -293,52 +666,145 @@ impl NewServiceForm {
         frame.render_widget(paragraph, area);
     }
 
-    fn render_field_label<'a>(&self, label: &'a str, field: FormField) -> Line<'a> {
+    /// Generated files with the focused field's lines marked, or the
+    /// changes to an existing file once an overwrite needs confirming
+    fn render_preview(&self, frame: &mut Frame, area: Rect) {
+        let name = if self.name.is_empty() { "<name>" } else { self.name.as_str() };
+
+        if let Some(diffs) = &self.overwrite_diff {
+            let mut lines: Vec<Line> = Vec::new();
+            for (file, diff) in diffs {
+                if !lines.is_empty() {
+                    lines.push(Line::from(""));
+                }
+                lines.push(Line::from(Span::styled(
+                    format!("# {}", file),
+                    Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD),
+                )));
+                lines.extend(diff.iter().map(diff_line));
+            }
+            let files: Vec<&str> = diffs.iter().map(|(file, _)| file.as_str()).collect();
+            let paragraph = Paragraph::new(lines).block(
+                Block::default()
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::Yellow))
+                    .title(format!(" Overwrite {}? ", files.join(" and "))),
+            );
+            frame.render_widget(paragraph, area);
+            return;
+        }
+
+        let (service_issues, timer_issues) = self.preview_issues();
+        let mut lines = self.preview_file(&self.generate_service_file(), &service_issues);
+        if let Some(timer) = self.generate_timer_file() {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(
+                format!("# {}.timer", name),
+                Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD),
+            )));
+            lines.extend(self.preview_file(&timer, &timer_issues));
+        }
+
+        if !service_issues.is_empty() || !timer_issues.is_empty() {
+            lines.push(Line::from(""));
+            lines.extend(service_issues.iter().chain(&timer_issues).map(Self::render_issue));
+        }
+
+        let paragraph = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL).title(format!(" Preview: {}.service ", name)));
+        frame.render_widget(paragraph, area);
+    }
+
+    fn preview_file(&self, content: &str, issues: &[LintIssue]) -> Vec<Line<'static>> {
+        content
+            .lines()
+            .enumerate()
+            .map(|(idx, raw)| {
+                let issue = issues.iter().find(|issue| issue.line == idx + 1);
+                let gutter = match issue.map(|issue| issue.severity) {
+                    Some(Severity::Error) => Span::styled("✗ ", Style::default().fg(Color::Red)),
+                    Some(Severity::Warning) => Span::styled("⚠ ", Style::default().fg(Color::Yellow)),
+                    None => Span::raw("  "),
+                };
+                let mut spans = vec![gutter];
+                spans.extend(highlight_line(raw));
+
+                let line = Line::from(spans);
+                if self.is_focused_line(raw) {
+                    line.style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+                } else {
+                    line
+                }
+            })
+            .collect()
+    }
+
+    /// One field as "▶ Label            value"
+    fn render_field(&self, field: FormField) -> Line<'static> {
         let is_current = self.current_field == field;
-        let style = if is_current {
+        let label_style = if is_current {
             Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD)
         } else {
             Style::default().fg(Color::Gray)
         };
 
-        Line::from(vec![
+        let mut spans = vec![
             if is_current {
                 Span::styled("▶ ", Style::default().fg(Color::Green))
             } else {
                 Span::raw("  ")
             },
-            Span::styled(label, style),
-        ])
-    }
+            Span::styled(format!("{:<18}", field.label()), label_style),
+        ];
 
-    fn render_field_value(&self, value: &str, field: FormField, placeholder: &str) -> Line<'static> {
-        let is_current = self.current_field == field;
-
-        if value.is_empty() {
-            Line::from(vec![
-                Span::raw("  "),
-                Span::styled(placeholder.to_string(), Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC)),
-                if is_current {
-                    Span::styled("█", Style::default().fg(Color::Green))
-                } else {
-                    Span::raw("")
-                },
-            ])
+        let value = self.text(field).to_string();
+        if field.options().is_some() {
+            if is_current {
+                spans.push(Span::styled(
+                    format!("< {} >", value),
+                    Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+                ));
+                spans.push(Span::styled(" (use ↑↓ to change)", Style::default().fg(Color::DarkGray)));
+            } else {
+                spans.push(Span::styled(value, Style::default().fg(Color::White)));
+            }
+        } else if value.is_empty() {
+            spans.push(Span::styled(
+                field.placeholder(),
+                Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC),
+            ));
         } else {
-            Line::from(vec![
-                Span::raw("  "),
-                Span::styled(value.to_string(), if is_current {
+            spans.push(Span::styled(
+                value,
+                if is_current {
                     Style::default().fg(Color::White).add_modifier(Modifier::BOLD)
                 } else {
                     Style::default().fg(Color::White)
-                }),
-                if is_current {
-                    Span::styled("█", Style::default().fg(Color::Green))
-                } else {
-                    Span::raw("")
                 },
-            ])
+            ));
+        }
+
+        if is_current && field.options().is_none() {
+            spans.push(Span::styled("█", Style::default().fg(Color::Green)));
         }
+
+        Line::from(spans)
+    }
+
+    fn render_field_issues(&self, field: FormField) -> Vec<Line<'static>> {
+        self.lint_issues
+            .iter()
+            .filter(|issue| issue.key.as_deref() == Some(field.key()))
+            .map(Self::render_issue)
+            .collect()
+    }
+
+    fn render_issue(issue: &LintIssue) -> Line<'static> {
+        let (symbol, color) = match issue.severity {
+            Severity::Error => ("✗", Color::Red),
+            Severity::Warning => ("⚠", Color::Yellow),
+        };
+        Line::from(Span::styled(format!("    {} {}", symbol, issue.message), Style::default().fg(color)))
     }
 
     fn render_footer(&self, frame: &mut Frame, area: Rect) {
@@ -351,6 +817,8 @@ impl NewServiceForm {
             Line::from(vec![
                 Span::styled("[Tab/Enter]", Style::default().fg(Color::Cyan)),
                 Span::raw(" Next field | "),
+                Span::styled("[Shift+Tab]", Style::default().fg(Color::Cyan)),
+                Span::raw(" Previous | "),
                 Span::styled("[Ctrl+S]", Style::default().fg(Color::Green)),
                 Span::raw(" Save & Create | "),
                 Span::styled("[Esc]", Style::default().fg(Color::Yellow)),
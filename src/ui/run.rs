@@ -0,0 +1,293 @@
+// Run dialog - start a command as a transient service or scope, like `systemd-run`
+
+use crate::systemd::lint::parse_time_span;
+use crate::systemd::{ResourceLimit, ServiceScope, TransientKind, TransientSpec};
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RunField {
+    Command,
+    Description,
+    Name,
+    Kind,
+    Manager,
+    Slice,
+    MemoryMax,
+    CPUQuota,
+    RuntimeMaxSec,
+}
+
+impl RunField {
+    pub const ALL: [RunField; 9] = [
+        RunField::Command,
+        RunField::Description,
+        RunField::Name,
+        RunField::Kind,
+        RunField::Manager,
+        RunField::Slice,
+        RunField::MemoryMax,
+        RunField::CPUQuota,
+        RunField::RuntimeMaxSec,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            RunField::Command => "Command",
+            RunField::Description => "Description",
+            RunField::Name => "Unit name",
+            RunField::Kind => "Run as",
+            RunField::Manager => "Manager",
+            RunField::Slice => "Slice",
+            RunField::MemoryMax => "MemoryMax",
+            RunField::CPUQuota => "CPUQuota",
+            RunField::RuntimeMaxSec => "RuntimeMaxSec",
+        }
+    }
+
+    fn hint(&self) -> &'static str {
+        match self {
+            RunField::Command => "e.g. rsync -a /srv/ /backup/",
+            RunField::Description => "optional",
+            RunField::Name => "optional, default run-sysprox-<time>",
+            RunField::Kind | RunField::Manager => "←→ to change",
+            RunField::Slice => "optional, e.g. background.slice",
+            RunField::MemoryMax => "optional, e.g. 512M",
+            RunField::CPUQuota => "optional, e.g. 50%",
+            RunField::RuntimeMaxSec => "optional, e.g. 30min",
+        }
+    }
+
+    /// Fields chosen with the arrows instead of typed
+    fn is_choice(&self) -> bool {
+        matches!(self, RunField::Kind | RunField::Manager)
+    }
+}
+
+#[derive(Debug)]
+pub struct RunDialog {
+    pub command: String,
+    pub description: String,
+    pub name: String,
+    pub kind: TransientKind,
+    pub scope: ServiceScope,
+    pub slice: String,
+    pub memory_max: String,
+    pub cpu_quota: String,
+    pub runtime_max: String,
+    pub selected: usize,
+    pub error: Option<String>,
+}
+
+impl RunDialog {
+    pub fn new(scope: ServiceScope) -> Self {
+        Self {
+            command: String::new(),
+            description: String::new(),
+            name: String::new(),
+            kind: TransientKind::Service,
+            scope,
+            slice: String::new(),
+            memory_max: String::new(),
+            cpu_quota: String::new(),
+            runtime_max: String::new(),
+            selected: 0,
+            error: None,
+        }
+    }
+
+    pub fn field(&self) -> RunField {
+        RunField::ALL[self.selected]
+    }
+
+    fn input(&self, field: RunField) -> String {
+        match field {
+            RunField::Command => self.command.clone(),
+            RunField::Description => self.description.clone(),
+            RunField::Name => self.name.clone(),
+            RunField::Kind => self.kind.suffix().to_string(),
+            RunField::Manager => self.scope.label().to_string(),
+            RunField::Slice => self.slice.clone(),
+            RunField::MemoryMax => self.memory_max.clone(),
+            RunField::CPUQuota => self.cpu_quota.clone(),
+            RunField::RuntimeMaxSec => self.runtime_max.clone(),
+        }
+    }
+
+    fn input_mut(&mut self) -> Option<&mut String> {
+        match self.field() {
+            RunField::Command => Some(&mut self.command),
+            RunField::Description => Some(&mut self.description),
+            RunField::Name => Some(&mut self.name),
+            RunField::Slice => Some(&mut self.slice),
+            RunField::MemoryMax => Some(&mut self.memory_max),
+            RunField::CPUQuota => Some(&mut self.cpu_quota),
+            RunField::RuntimeMaxSec => Some(&mut self.runtime_max),
+            RunField::Kind | RunField::Manager => None,
+        }
+    }
+
+    pub fn handle_char(&mut self, c: char) {
+        self.error = None;
+        if let Some(input) = self.input_mut() {
+            input.push(c);
+        }
+    }
+
+    pub fn backspace(&mut self) {
+        self.error = None;
+        if let Some(input) = self.input_mut() {
+            input.pop();
+        }
+    }
+
+    pub fn next_field(&mut self) {
+        self.selected = (self.selected + 1) % RunField::ALL.len();
+    }
+
+    pub fn prev_field(&mut self) {
+        self.selected = (self.selected + RunField::ALL.len() - 1) % RunField::ALL.len();
+    }
+
+    /// Flip the selected choice field (service/scope, user/system)
+    pub fn toggle_choice(&mut self) {
+        match self.field() {
+            RunField::Kind => {
+                self.kind = match self.kind {
+                    TransientKind::Service => TransientKind::Scope,
+                    TransientKind::Scope => TransientKind::Service,
+                };
+            }
+            RunField::Manager => {
+                self.scope = match self.scope {
+                    ServiceScope::User => ServiceScope::System,
+                    ServiceScope::System => ServiceScope::User,
+                };
+            }
+            _ => {}
+        }
+    }
+
+    /// Parse the inputs; on error, select the bad field and keep editing
+    pub fn spec(&mut self) -> Option<TransientSpec> {
+        match self.build() {
+            Ok(spec) => Some(spec),
+            Err((field, message)) => {
+                self.selected = RunField::ALL.iter().position(|f| *f == field).unwrap_or(0);
+                self.error = Some(message);
+                None
+            }
+        }
+    }
+
+    fn build(&self) -> Result<TransientSpec, (RunField, String)> {
+        let argv = TransientSpec::command(&self.command).map_err(|e| (RunField::Command, e.to_string()))?;
+        let name = TransientSpec::unit_name(&self.name, self.kind).map_err(|e| (RunField::Name, e.to_string()))?;
+
+        let slice = self.slice.trim().to_string();
+        if !slice.is_empty() && !slice.ends_with(".slice") {
+            return Err((RunField::Slice, "Slice must end in .slice, e.g. background.slice".to_string()));
+        }
+
+        let limit = |field: RunField, input: &str, limit: ResourceLimit| match input.trim() {
+            "" => Ok(None),
+            value => limit.parse(value).map(Some).map_err(|e| (field, e.to_string())),
+        };
+        let memory_max = limit(RunField::MemoryMax, &self.memory_max, ResourceLimit::MemoryMax)?;
+        let cpu_quota = limit(RunField::CPUQuota, &self.cpu_quota, ResourceLimit::CPUQuota)?;
+        let runtime_max_usec = match self.runtime_max.trim() {
+            "" => None,
+            value => Some(parse_time_span(value).ok_or_else(|| {
+                (RunField::RuntimeMaxSec, "RuntimeMaxSec must be a time span like 30s or 1h".to_string())
+            })?),
+        };
+
+        let description = match self.description.trim() {
+            "" => format!("sysprox run: {}", self.command.trim()),
+            given => given.to_string(),
+        };
+
+        Ok(TransientSpec {
+            name,
+            kind: self.kind,
+            argv,
+            description,
+            slice,
+            memory_max,
+            cpu_quota,
+            runtime_max_usec,
+        })
+    }
+
+    /// Render the dialog centered over a darkened area
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let dialog_width = 76.min(area.width - 4);
+        let dialog_height = (RunField::ALL.len() as u16 + 7).min(area.height - 4);
+        let dialog_area = Rect {
+            x: area.x + (area.width - dialog_width) / 2,
+            y: area.y + (area.height - dialog_height) / 2,
+            width: dialog_width,
+            height: dialog_height,
+        };
+
+        let background = Block::default()
+            .style(Style::default().bg(Color::DarkGray).fg(Color::Reset));
+        frame.render_widget(background, area);
+
+        let mut lines = vec![Line::from("")];
+        for (i, field) in RunField::ALL.iter().enumerate() {
+            let selected = i == self.selected;
+            let input = self.input(*field);
+
+            let value_style = if selected {
+                Style::default().fg(Color::Black).bg(Color::Yellow).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::White)
+            };
+            let value = if field.is_choice() {
+                format!("< {} >", input)
+            } else {
+                let cursor = if selected { "▏" } else { " " };
+                format!("{}{}", input, cursor)
+            };
+
+            let mut spans = vec![
+                Span::styled(format!(" {:<14}", field.label()), Style::default().fg(Color::Cyan)),
+                Span::styled(value, value_style),
+            ];
+            if input.is_empty() || field.is_choice() {
+                spans.push(Span::styled(format!("  {}", field.hint()), Style::default().fg(Color::DarkGray)));
+            }
+            lines.push(Line::from(spans));
+        }
+
+        lines.push(Line::from(""));
+        match &self.error {
+            Some(error) => lines.push(Line::from(Span::styled(format!(" ✗ {}", error), Style::default().fg(Color::Red)))),
+            None if self.kind == TransientKind::Scope => lines.push(Line::from(Span::styled(
+                " Scopes run the command from sysprox; its output is discarded",
+                Style::default().fg(Color::DarkGray),
+            ))),
+            None => lines.push(Line::from("")),
+        }
+        lines.push(Line::from(Span::styled(
+            " [↑↓/Tab] Field  [←→] Change  [Enter] Run  [Esc] Cancel",
+            Style::default().fg(Color::DarkGray),
+        )));
+
+        let dialog = Paragraph::new(lines).block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(" Run transient unit ")
+                .border_style(Style::default().fg(Color::Yellow))
+                .style(Style::default().bg(Color::Black).fg(Color::White)),
+        );
+
+        frame.render_widget(dialog, dialog_area);
+    }
+}
@@ -0,0 +1,288 @@
+// Sockets view - socket units with listen addresses and connection counters
+
+use crate::events::Action;
+use crate::systemd::{ServiceScope, Socket};
+use crate::ui::dashboard::ScopeFilter;
+use crate::ui::detail::ConfirmationDialog;
+use crate::ui::tabs::{tab_title, ListTab};
+use crate::ui::{state_color, status_emoji};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+#[derive(Debug)]
+pub struct SocketsState {
+    pub sockets: Vec<Socket>,
+    pub scope_filter: ScopeFilter,
+    pub table_state: TableState,
+    pub loading: bool,
+    pub confirmation_dialog: Option<ConfirmationDialog>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SocketsAction {
+    None,
+    GoBack,
+    /// Open the detail view of the unit the socket activates
+    ViewUnit(String, ServiceScope),
+    ExecuteServiceControl {
+        service: String,
+        operation: String,
+        scope: ServiceScope,
+    },
+}
+
+impl Default for SocketsState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SocketsState {
+    pub fn new() -> Self {
+        let mut table_state = TableState::default();
+        table_state.select(Some(0));
+
+        Self {
+            sockets: Vec::new(),
+            scope_filter: ScopeFilter::All,
+            table_state,
+            loading: true,
+            confirmation_dialog: None,
+        }
+    }
+
+    pub fn set_sockets(&mut self, mut sockets: Vec<Socket>) {
+        sockets.sort_by(|a, b| a.name.cmp(&b.name));
+        self.sockets = sockets;
+        self.loading = false;
+
+        let len = self.filtered_sockets().len();
+        match self.table_state.selected() {
+            Some(idx) if idx < len => {}
+            _ => self.table_state.select(Some(0)),
+        }
+    }
+
+    pub fn filtered_sockets(&self) -> Vec<&Socket> {
+        self.sockets
+            .iter()
+            .filter(|s| match self.scope_filter {
+                ScopeFilter::All => true,
+                ScopeFilter::System => s.scope == ServiceScope::System,
+                ScopeFilter::User => s.scope == ServiceScope::User,
+            })
+            .collect()
+    }
+
+    pub fn get_selected_socket(&self) -> Option<&Socket> {
+        let filtered = self.filtered_sockets();
+        self.table_state
+            .selected()
+            .and_then(|i| filtered.get(i).copied())
+    }
+
+    pub fn handle_action(&mut self, action: Action) -> SocketsAction {
+        // Handle confirmation dialog first
+        if let Some(dialog) = &self.confirmation_dialog {
+            return match action {
+                Action::ConfirmAction => {
+                    let service = dialog.service.clone();
+                    let operation = dialog.operation.clone();
+                    let scope = dialog.scope;
+                    self.confirmation_dialog = None;
+                    SocketsAction::ExecuteServiceControl { service, operation, scope }
+                }
+                Action::CancelAction | Action::GoBack => {
+                    self.confirmation_dialog = None;
+                    SocketsAction::None
+                }
+                _ => SocketsAction::None,
+            };
+        }
+
+        match action {
+            Action::GoBack => return SocketsAction::GoBack,
+            Action::MoveUp => self.move_selection(-1),
+            Action::MoveDown => self.move_selection(1),
+            Action::MoveTop => self.table_state.select(Some(0)),
+            Action::MoveBottom => {
+                let len = self.filtered_sockets().len();
+                if len > 0 {
+                    self.table_state.select(Some(len - 1));
+                }
+            }
+            Action::ToggleScope => {
+                self.scope_filter = self.scope_filter.next();
+                self.table_state.select(Some(0));
+            }
+            Action::Select => {
+                if let Some(socket) = self.get_selected_socket() {
+                    if !socket.unit.is_empty() {
+                        return SocketsAction::ViewUnit(socket.unit.clone(), socket.scope);
+                    }
+                }
+            }
+            Action::StartService => {
+                if let Some(socket) = self.get_selected_socket() {
+                    if !socket.is_active() {
+                        let (name, scope) = (socket.name.clone(), socket.scope);
+                        self.show_confirmation(name.clone(), "start", scope, format!("Start socket '{}'?", name));
+                    }
+                }
+            }
+            Action::StopService => {
+                if let Some(socket) = self.get_selected_socket() {
+                    if socket.is_active() {
+                        let (name, scope) = (socket.name.clone(), socket.scope);
+                        self.show_confirmation(name.clone(), "stop", scope, format!("Stop socket '{}'?", name));
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        SocketsAction::None
+    }
+
+    fn show_confirmation(&mut self, service: String, operation: &str, scope: ServiceScope, message: String) {
+        self.confirmation_dialog = Some(ConfirmationDialog {
+            service,
+            operation: operation.to_string(),
+            scope,
+            message,
+            confirmed: false,
+        });
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        let len = self.filtered_sockets().len();
+        if len == 0 {
+            return;
+        }
+
+        let current = self.table_state.selected().unwrap_or(0);
+        let new_index = if delta < 0 {
+            current.saturating_sub(delta.unsigned_abs())
+        } else {
+            (current + delta as usize).min(len - 1)
+        };
+
+        self.table_state.select(Some(new_index));
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, show_footer: bool) {
+        if let Some(dialog) = &self.confirmation_dialog {
+            dialog.render(frame, area);
+            return;
+        }
+
+        let mut constraints = vec![
+            Constraint::Length(3), // Header
+            Constraint::Min(0),    // Sockets table
+        ];
+        if show_footer {
+            constraints.push(Constraint::Length(1)); // Help footer
+        }
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(constraints)
+            .split(area);
+
+        self.render_header(frame, chunks[0]);
+        self.render_table(frame, chunks[1]);
+
+        if show_footer {
+            let help = Paragraph::new(
+                "[Enter] Activated unit | [↑↓/jk] Navigate | [S] Start | [T] Stop | [m] Scope | [Tab] Next list | [q] Quit",
+            )
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(ratatui::layout::Alignment::Center);
+            frame.render_widget(help, chunks[2]);
+        }
+    }
+
+    fn render_header(&self, frame: &mut Frame, area: Rect) {
+        let active = self.sockets.iter().filter(|s| s.is_active()).count();
+        let connections: u32 = self.sockets.iter().map(|s| s.n_connections).sum();
+        let text = if self.loading {
+            "Loading sockets...".to_string()
+        } else {
+            format!(
+                "Total: {} | Listening: {} | Connections: {} | Showing: {} | Scope: {}",
+                self.sockets.len(),
+                active,
+                connections,
+                self.filtered_sockets().len(),
+                self.scope_filter.label()
+            )
+        };
+
+        let header = Paragraph::new(text)
+            .style(Style::default().fg(Color::Yellow))
+            .alignment(ratatui::layout::Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).title(tab_title(ListTab::Sockets)));
+
+        frame.render_widget(header, area);
+    }
+
+    fn render_table(&mut self, frame: &mut Frame, area: Rect) {
+        let filtered = self.filtered_sockets();
+
+        let rows: Vec<Row> = filtered
+            .iter()
+            .map(|socket| {
+                let icon = status_emoji(&socket.active_state);
+                let refused_style = if socket.n_refused > 0 {
+                    Style::default().fg(Color::Red)
+                } else {
+                    Style::default()
+                };
+
+                Row::new(vec![
+                    Cell::from(format!("{} {}", icon, socket.name))
+                        .style(Style::default().fg(state_color(&socket.active_state))),
+                    Cell::from(socket.scope.label()).style(Style::default().fg(
+                        if socket.scope == ServiceScope::User { Color::LightBlue } else { Color::Gray },
+                    )),
+                    Cell::from(socket.listen.join(", ")),
+                    Cell::from(socket.n_accepted.to_string()),
+                    Cell::from(socket.n_connections.to_string()),
+                    Cell::from(socket.n_refused.to_string()).style(refused_style),
+                    Cell::from(socket.unit.clone()),
+                ])
+            })
+            .collect();
+
+        let widths = [
+            Constraint::Percentage(22),
+            Constraint::Length(7),
+            Constraint::Min(20),
+            Constraint::Length(9),
+            Constraint::Length(6),
+            Constraint::Length(8),
+            Constraint::Percentage(20),
+        ];
+
+        let table = Table::new(rows, widths)
+            .header(
+                Row::new(vec!["Socket", "Scope", "Listen", "Accepted", "Conns", "Refused", "Activates"])
+                    .style(
+                        Style::default()
+                            .bg(Color::DarkGray)
+                            .fg(Color::White)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                    .bottom_margin(1),
+            )
+            .block(Block::default().title(" Sockets ").borders(Borders::ALL))
+            .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+            .highlight_symbol(">> ");
+
+        frame.render_stateful_widget(table, area, &mut self.table_state);
+    }
+}
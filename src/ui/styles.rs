@@ -10,6 +10,8 @@ pub const SUCCESS: Color = Color::Green;
 pub const WARNING: Color = Color::Yellow;
 pub const ERROR: Color = Color::Red;
 pub const MUTED: Color = Color::Gray;
+pub const MASKED: Color = Color::LightMagenta;
+pub const FROZEN: Color = Color::LightCyan;
 
 // Common styles
 pub fn title_style() -> Style {
@@ -70,8 +72,10 @@ pub fn load_state_color(state: &str) -> Color {
         "loaded" => SUCCESS,
         "stub" => SUCCESS,
 
+        // Masked - cannot be started until unmasked
+        "masked" => MASKED,
+
         // Warnings - Yellow
-        "masked" => WARNING,
         "bad-setting" => WARNING,
 
         // Errors - Red
@@ -186,6 +190,36 @@ pub fn status_emoji(state: &str) -> &'static str {
     }
 }
 
+/// Get colored emoji for a unit, marking masked units whatever their state
+pub fn unit_emoji(active_state: &str, load_state: &str) -> &'static str {
+    if load_state == "masked" {
+        "🚫"
+    } else {
+        status_emoji(active_state)
+    }
+}
+
+/// Emoji for a unit whose processes are frozen
+pub const FROZEN_EMOJI: &str = "🧊";
+
+/// Get color for a unit's active state, marking masked units
+pub fn unit_state_color(active_state: &str, load_state: &str) -> Color {
+    if load_state == "masked" {
+        MASKED
+    } else {
+        state_color(active_state)
+    }
+}
+
+/// Get color for a freezer state; None while the unit runs normally
+pub fn freezer_color(state: &str) -> Option<Color> {
+    match state {
+        "frozen" => Some(FROZEN),
+        "freezing" | "thawing" => Some(WARNING),
+        _ => None,
+    }
+}
+
 /// Get color for log priority level
 pub fn priority_color(priority: u8) -> Option<Style> {
     let color = match priority {
@@ -0,0 +1,58 @@
+// List tabs - switch between unit lists with Tab/Shift+Tab
+
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ListTab {
+    Services,
+    Timers,
+    Sockets,
+    Jobs,
+}
+
+impl ListTab {
+    pub const ALL: [ListTab; 4] = [ListTab::Services, ListTab::Timers, ListTab::Sockets, ListTab::Jobs];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            ListTab::Services => "Services",
+            ListTab::Timers => "Timers",
+            ListTab::Sockets => "Sockets",
+            ListTab::Jobs => "Jobs",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        let idx = Self::ALL.iter().position(|t| t == self).unwrap_or(0);
+        Self::ALL[(idx + 1) % Self::ALL.len()]
+    }
+
+    pub fn prev(&self) -> Self {
+        let idx = Self::ALL.iter().position(|t| t == self).unwrap_or(0);
+        Self::ALL[(idx + Self::ALL.len() - 1) % Self::ALL.len()]
+    }
+}
+
+/// Tab bar for a list view's header border, highlighting the active tab
+pub fn tab_title(active: ListTab) -> Line<'static> {
+    let mut spans = vec![Span::raw(" ")];
+
+    for (i, tab) in ListTab::ALL.iter().enumerate() {
+        if i > 0 {
+            spans.push(Span::styled(" │ ", Style::default().fg(Color::DarkGray)));
+        }
+
+        let style = if *tab == active {
+            Style::default().fg(Color::Cyan).add_modifier(Modifier::BOLD | Modifier::REVERSED)
+        } else {
+            Style::default().fg(Color::Gray)
+        };
+        spans.push(Span::styled(format!(" {} ", tab.label()), style));
+    }
+
+    spans.push(Span::styled("  [Tab] Switch ", Style::default().fg(Color::DarkGray)));
+    Line::from(spans)
+}
@@ -1,7 +1,7 @@
 #[cfg(test)]
 mod tests {
     use crate::events::{Action, FilterAction};
-    use crate::systemd::{Service, ServiceDetail, LogLine, ServiceScope};
+    use crate::systemd::{Service, ServiceDetail, LogLine, ServiceScope, UnitKind};
     use crate::ui::{DashboardState, DetailState, LogsState, LogsAction, FilterType};
     use ratatui::backend::TestBackend;
     use ratatui::Terminal;
@@ -31,6 +31,8 @@ mod tests {
                 pid: 1234,
                 enabled: true,
                 scope: ServiceScope::System,
+                kind: UnitKind::Service,
+                freezer_state: String::new(),
                 loaded_at: chrono::Utc::now(),
             },
             Service {
@@ -43,6 +45,8 @@ mod tests {
                 pid: 0,
                 enabled: true,
                 scope: ServiceScope::System,
+                kind: UnitKind::Service,
+                freezer_state: String::new(),
                 loaded_at: chrono::Utc::now(),
             },
             Service {
@@ -55,6 +59,8 @@ mod tests {
                 pid: 0,
                 enabled: false,
                 scope: ServiceScope::System,
+                kind: UnitKind::Service,
+                freezer_state: String::new(),
                 loaded_at: chrono::Utc::now(),
             },
         ]);
@@ -93,6 +99,8 @@ mod tests {
                 pid: 1234,
                 enabled: true,
                 scope: ServiceScope::System,
+                kind: UnitKind::Service,
+                freezer_state: String::new(),
                 loaded_at: chrono::Utc::now(),
             },
             Service {
@@ -105,6 +113,8 @@ mod tests {
                 pid: 5678,
                 enabled: true,
                 scope: ServiceScope::System,
+                kind: UnitKind::Service,
+                freezer_state: String::new(),
                 loaded_at: chrono::Utc::now(),
             },
         ]);
@@ -142,6 +152,8 @@ mod tests {
                 pid: 1234,
                 enabled: true,
                 scope: ServiceScope::System,
+                kind: UnitKind::Service,
+                freezer_state: String::new(),
                 loaded_at: chrono::Utc::now(),
             },
             Service {
@@ -154,6 +166,8 @@ mod tests {
                 pid: 5678,
                 enabled: true,
                 scope: ServiceScope::System,
+                kind: UnitKind::Service,
+                freezer_state: String::new(),
                 loaded_at: chrono::Utc::now(),
             },
         ]);
@@ -192,13 +206,15 @@ mod tests {
                 pid: 1234,
                 enabled: true,
                 scope: ServiceScope::System,
+                kind: UnitKind::Service,
+                freezer_state: String::new(),
                 loaded_at: chrono::Utc::now(),
             },
         ]);
 
         // Test select action
         let selected = state.handle_action(Action::Select);
-        assert_eq!(selected, Some("test.service".to_string()));
+        assert_eq!(selected, Some(("test.service".to_string(), ServiceScope::System)));
     }
 
     #[test]
@@ -222,6 +238,292 @@ mod tests {
         assert!(matches!(state.filter, FilterType::Stopped));
     }
 
+    #[test]
+    fn test_dashboard_upsert_and_remove() {
+        let mut state = DashboardState::new();
+        let make = |name: &str, active_state: &str| Service {
+            id: format!("system:{}", name),
+            name: name.to_string(),
+            description: "Test Service".to_string(),
+            load_state: "loaded".to_string(),
+            active_state: active_state.to_string(),
+            sub_state: "running".to_string(),
+            pid: 0,
+            enabled: true,
+            scope: ServiceScope::System,
+            kind: UnitKind::Service,
+            freezer_state: String::new(),
+            loaded_at: chrono::Utc::now(),
+        };
+
+        state.set_services(vec![make("alpha.service", "active"), make("gamma.service", "active")]);
+
+        // New unit is inserted in sorted position
+        state.upsert_service(make("beta.service", "activating"));
+        let names: Vec<_> = state.services.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["alpha.service", "beta.service", "gamma.service"]);
+
+        // Existing unit is patched in place
+        state.upsert_service(make("beta.service", "failed"));
+        assert_eq!(state.services.len(), 3);
+        assert!(state.services[1].is_failed());
+
+        state.remove_service("system:alpha.service");
+        let names: Vec<_> = state.services.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["beta.service", "gamma.service"]);
+
+        // A full reload has no FreezerState; the watcher's last value is kept while active
+        let mut frozen = make("gamma.service", "active");
+        frozen.freezer_state = "frozen".to_string();
+        state.upsert_service(frozen);
+        state.set_services(vec![make("beta.service", "failed"), make("gamma.service", "active")]);
+        assert!(state.services[1].is_frozen());
+        state.set_services(vec![make("gamma.service", "inactive")]);
+        assert!(!state.services[0].is_frozen());
+    }
+
+    #[test]
+    fn test_dashboard_unit_kind_filter() {
+        use crate::ui::UnitKindFilter;
+
+        let make = |name: &str, kind: UnitKind, active_state: &str| Service {
+            id: format!("system:{}", name),
+            name: name.to_string(),
+            description: "Test Unit".to_string(),
+            load_state: "loaded".to_string(),
+            active_state: active_state.to_string(),
+            sub_state: "running".to_string(),
+            pid: 0,
+            enabled: false,
+            scope: ServiceScope::System,
+            kind,
+            freezer_state: String::new(),
+            loaded_at: chrono::Utc::now(),
+        };
+
+        let mut state = DashboardState::new();
+        state.set_services(vec![
+            make("boot.mount", UnitKind::Mount, "failed"),
+            make("multi-user.target", UnitKind::Target, "active"),
+            make("nginx.service", UnitKind::Service, "active"),
+        ]);
+
+        // Services only by default, matching the classic dashboard
+        assert_eq!(state.kind_filter, UnitKindFilter::Kind(UnitKind::Service));
+        assert_eq!(state.filtered_services().len(), 1);
+        assert_eq!(state.get_stats(), (1, 1, 0, 0));
+
+        state.handle_action(Action::CycleUnitKind);
+        assert_eq!(state.kind_filter, UnitKindFilter::All);
+        assert_eq!(state.filtered_services().len(), 3);
+
+        // Failed mounts are reachable by combining filters
+        while state.kind_filter != UnitKindFilter::Kind(UnitKind::Mount) {
+            state.handle_action(Action::CycleUnitKind);
+        }
+        state.handle_action(Action::ToggleFilter(FilterAction::Failed));
+        assert_eq!(state.get_selected_service().unwrap().name, "boot.mount");
+
+        // The cycle wraps back to services
+        for _ in 0..UnitKind::LISTED.len() {
+            state.handle_action(Action::CycleUnitKind);
+            if state.kind_filter == UnitKindFilter::Kind(UnitKind::Service) {
+                break;
+            }
+        }
+        assert_eq!(state.kind_filter, UnitKindFilter::Kind(UnitKind::Service));
+    }
+
+    #[test]
+    fn test_timers_state() {
+        use crate::systemd::Timer;
+        use crate::ui::{ListTab, TimersAction, TimersState};
+
+        let now = chrono::Utc::now();
+        let make = |name: &str, unit: &str, next: Option<chrono::DateTime<chrono::Utc>>, active: bool| Timer {
+            id: format!("system:{}", name),
+            name: name.to_string(),
+            description: "Test Timer".to_string(),
+            active_state: if active { "active" } else { "inactive" }.to_string(),
+            sub_state: if active { "waiting" } else { "dead" }.to_string(),
+            scope: ServiceScope::System,
+            unit: unit.to_string(),
+            next_elapse: next,
+            last_trigger: None,
+            persistent: true,
+            schedule: vec!["OnCalendar=daily".to_string()],
+        };
+
+        let mut state = TimersState::new();
+        assert!(state.loading);
+        state.set_timers(vec![
+            make("idle.timer", "idle.service", None, false),
+            make("later.timer", "later.service", Some(now + chrono::Duration::hours(2)), true),
+            make("soon.timer", "soon.service", Some(now + chrono::Duration::minutes(5)), true),
+        ]);
+        assert!(!state.loading);
+
+        // Soonest first, unscheduled last
+        let names: Vec<_> = state.timers.iter().map(|t| t.name.as_str()).collect();
+        assert_eq!(names, vec!["soon.timer", "later.timer", "idle.timer"]);
+
+        // Enter opens the triggered unit
+        assert_eq!(state.handle_action(Action::Select), TimersAction::ViewUnit("soon.service".to_string(), ServiceScope::System));
+
+        // Stopping an active timer goes through confirmation
+        assert_eq!(state.handle_action(Action::StopService), TimersAction::None);
+        assert!(state.confirmation_dialog.is_some());
+        assert_eq!(
+            state.handle_action(Action::ConfirmAction),
+            TimersAction::ExecuteServiceControl {
+                service: "soon.timer".to_string(),
+                operation: "stop".to_string(),
+                scope: ServiceScope::System,
+            }
+        );
+        assert!(state.confirmation_dialog.is_none());
+
+        // Starting an already active timer is ignored
+        state.handle_action(Action::StartService);
+        assert!(state.confirmation_dialog.is_none());
+
+        state.handle_action(Action::MoveBottom);
+        assert_eq!(state.get_selected_timer().unwrap().name, "idle.timer");
+        state.handle_action(Action::StartService);
+        assert_eq!(state.confirmation_dialog.as_ref().unwrap().operation, "start");
+        state.handle_action(Action::CancelAction);
+        assert!(state.confirmation_dialog.is_none());
+
+        assert_eq!(state.handle_action(Action::GoBack), TimersAction::GoBack);
+        assert_eq!(ListTab::Services.next(), ListTab::Timers);
+        assert_eq!(ListTab::Services.prev(), ListTab::Jobs);
+        assert_eq!(ListTab::Sockets.next(), ListTab::Jobs);
+        assert_eq!(ListTab::Jobs.next(), ListTab::Services);
+    }
+
+    #[test]
+    fn test_sockets_state() {
+        use crate::systemd::Socket;
+        use crate::ui::dashboard::ScopeFilter;
+        use crate::ui::{SocketsAction, SocketsState};
+
+        let make = |name: &str, scope: ServiceScope| Socket {
+            id: format!("{}:{}", scope.label(), name),
+            name: name.to_string(),
+            description: "Test Socket".to_string(),
+            active_state: "active".to_string(),
+            sub_state: "listening".to_string(),
+            scope,
+            unit: name.replace(".socket", ".service"),
+            listen: vec!["/run/test.sock (Stream)".to_string()],
+            n_accepted: 10,
+            n_connections: 2,
+            n_refused: 0,
+        };
+
+        let mut state = SocketsState::new();
+        state.set_sockets(vec![
+            make("sshd.socket", ServiceScope::System),
+            make("pipewire.socket", ServiceScope::User),
+            make("cups.socket", ServiceScope::System),
+        ]);
+
+        let names: Vec<_> = state.sockets.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["cups.socket", "pipewire.socket", "sshd.socket"]);
+
+        // Scope split works like the service dashboard
+        state.handle_action(Action::ToggleScope);
+        assert_eq!(state.scope_filter, ScopeFilter::System);
+        assert_eq!(state.filtered_sockets().len(), 2);
+        state.handle_action(Action::ToggleScope);
+        assert_eq!(state.filtered_sockets().len(), 1);
+        assert_eq!(
+            state.handle_action(Action::Select),
+            SocketsAction::ViewUnit("pipewire.service".to_string(), ServiceScope::User)
+        );
+
+        state.handle_action(Action::StopService);
+        assert_eq!(
+            state.handle_action(Action::ConfirmAction),
+            SocketsAction::ExecuteServiceControl {
+                service: "pipewire.socket".to_string(),
+                operation: "stop".to_string(),
+                scope: ServiceScope::User,
+            }
+        );
+    }
+
+    #[test]
+    fn test_jobs_state() {
+        use crate::systemd::Job;
+        use crate::ui::{JobsAction, JobsState};
+
+        let make = |id: u32, unit: &str, state: &str, scope: ServiceScope| Job {
+            id,
+            unit: unit.to_string(),
+            job_type: "start".to_string(),
+            state: state.to_string(),
+            scope,
+        };
+
+        let mut state = JobsState::new();
+        assert!(state.loading);
+        state.set_jobs(vec![
+            make(42, "nginx.service", "waiting", ServiceScope::System),
+            make(7, "pipewire.service", "running", ServiceScope::User),
+            make(12, "network-online.target", "running", ServiceScope::System),
+        ]);
+        assert!(!state.loading);
+
+        let ids: Vec<_> = state.jobs.iter().map(|j| j.id).collect();
+        assert_eq!(ids, vec![12, 42, 7]);
+        assert!(state.jobs[1].is_waiting());
+
+        // Selection follows the job when the queue changes
+        state.handle_action(Action::MoveDown);
+        state.set_jobs(vec![
+            make(42, "nginx.service", "running", ServiceScope::System),
+            make(7, "pipewire.service", "running", ServiceScope::User),
+        ]);
+        assert_eq!(state.get_selected_job().map(|j| j.id), Some(42));
+        assert_eq!(
+            state.handle_action(Action::Select),
+            JobsAction::ViewUnit("nginx.service".to_string(), ServiceScope::System)
+        );
+
+        // Cancel asks for confirmation first
+        assert_eq!(state.handle_action(Action::CancelJob), JobsAction::None);
+        assert!(state.confirmation_dialog.is_some());
+        assert_eq!(
+            state.handle_action(Action::ConfirmAction),
+            JobsAction::CancelJob {
+                id: 42,
+                unit: "nginx.service".to_string(),
+                scope: ServiceScope::System,
+            }
+        );
+
+        // A dialog for a job that finished meanwhile is dropped
+        state.handle_action(Action::CancelJob);
+        state.set_jobs(vec![make(7, "pipewire.service", "running", ServiceScope::User)]);
+        assert!(state.confirmation_dialog.is_none());
+        assert_eq!(state.handle_action(Action::ConfirmAction), JobsAction::None);
+    }
+
+    #[test]
+    fn test_relative_time() {
+        use crate::ui::timers::relative_time;
+
+        let now = chrono::Utc::now();
+        assert_eq!(relative_time(None, now), "-");
+        assert_eq!(relative_time(Some(now), now), "now");
+        assert_eq!(
+            relative_time(Some(now + chrono::Duration::seconds(2 * 3600 + 5 * 60 + 9)), now),
+            "in 2h 5min"
+        );
+        assert_eq!(relative_time(Some(now - chrono::Duration::days(3)), now), "3d ago");
+    }
+
     #[test]
     fn test_dashboard_stats() {
         let mut state = DashboardState::new();
@@ -236,6 +538,8 @@ mod tests {
                 pid: 1234,
                 enabled: true,
                 scope: ServiceScope::System,
+                kind: UnitKind::Service,
+                freezer_state: String::new(),
                 loaded_at: chrono::Utc::now(),
             },
             Service {
@@ -248,6 +552,8 @@ mod tests {
                 pid: 0,
                 enabled: false,
                 scope: ServiceScope::System,
+                kind: UnitKind::Service,
+                freezer_state: String::new(),
                 loaded_at: chrono::Utc::now(),
             },
             Service {
@@ -260,6 +566,8 @@ mod tests {
                 pid: 0,
                 enabled: true,
                 scope: ServiceScope::System,
+                kind: UnitKind::Service,
+                freezer_state: String::new(),
                 loaded_at: chrono::Utc::now(),
             },
         ]);
@@ -293,6 +601,8 @@ mod tests {
             pid: 1234,
             enabled: true,
             scope: ServiceScope::System,
+            kind: UnitKind::Service,
+            freezer_state: String::new(),
             loaded_at: chrono::Utc::now(),
         };
 
@@ -301,6 +611,9 @@ mod tests {
             main_pid: 1234,
             control_pid: 0,
             load_path: "/test/path".to_string(),
+            drop_in_paths: Vec::new(),
+            unit_file_state: String::new(),
+            vendor_path: String::new(),
             exec_main_start: String::new(),
             exec_main_status: String::new(),
             memory_current: 1024 * 1024,
@@ -308,6 +621,10 @@ mod tests {
             cpu_usage_nsec: 1000000000,
             tasks_current: 5,
             tasks_max: 100,
+            memory_high: u64::MAX,
+            cpu_quota_per_sec_usec: u64::MAX,
+            cpu_weight: u64::MAX,
+            io_weight: u64::MAX,
             n_restarts: 2,
             active_enter_time: chrono::Utc::now(),
             active_exit_time: chrono::Utc::now(),
@@ -324,6 +641,7 @@ mod tests {
             group: "root".to_string(),
             working_directory: "/".to_string(),
             environment: vec![],
+            type_properties: Vec::new(),
         };
 
         state.set_detail(detail);
@@ -341,6 +659,7 @@ mod tests {
         state.show_confirmation(
             "test.service".to_string(),
             "restart".to_string(),
+            ServiceScope::User,
             "Restart service 'test.service'?".to_string(),
         );
         
@@ -349,6 +668,7 @@ mod tests {
             let dialog = state.confirmation_dialog.as_ref().unwrap();
             assert_eq!(dialog.service, "test.service");
             assert_eq!(dialog.operation, "restart");
+            assert_eq!(dialog.scope, ServiceScope::User);
             assert_eq!(dialog.message, "Restart service 'test.service'?");
             assert!(!dialog.confirmed);
         }
@@ -362,9 +682,773 @@ mod tests {
         assert!(state.confirmation_dialog.is_none());
     }
 
+    /// Detail of a system service in the given active state
+    fn detail_in_state(active_state: &str) -> ServiceDetail {
+        ServiceDetail {
+            service: Service {
+                id: "crashy.service".to_string(),
+                name: "crashy.service".to_string(),
+                description: "Crashy Service".to_string(),
+                load_state: "loaded".to_string(),
+                active_state: active_state.to_string(),
+                sub_state: "failed".to_string(),
+                pid: 0,
+                enabled: true,
+                scope: ServiceScope::System,
+                kind: UnitKind::Service,
+                freezer_state: String::new(),
+                loaded_at: chrono::Utc::now(),
+            },
+            main_pid: 0,
+            control_pid: 0,
+            load_path: "/etc/systemd/system/crashy.service".to_string(),
+            drop_in_paths: Vec::new(),
+            unit_file_state: String::new(),
+            vendor_path: String::new(),
+            exec_main_start: String::new(),
+            exec_main_status: "1".to_string(),
+            memory_current: 0,
+            memory_limit: u64::MAX,
+            cpu_usage_nsec: 0,
+            tasks_current: 0,
+            tasks_max: 100,
+            memory_high: u64::MAX,
+            cpu_quota_per_sec_usec: u64::MAX,
+            cpu_weight: u64::MAX,
+            io_weight: u64::MAX,
+            n_restarts: 5,
+            active_enter_time: chrono::Utc::now(),
+            active_exit_time: chrono::Utc::now(),
+            inactive_enter_time: chrono::Utc::now(),
+            state_change_time: chrono::Utc::now(),
+            result: "exit-code".to_string(),
+            wants: vec![],
+            wanted_by: vec![],
+            after: vec![],
+            before: vec![],
+            service_type: "simple".to_string(),
+            restart: "no".to_string(),
+            user: String::new(),
+            group: String::new(),
+            working_directory: String::new(),
+            environment: vec![],
+            type_properties: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_detail_mask_and_reset_failed() {
+        use crate::ui::DetailAction;
+
+        let mut state = DetailState::new();
+        state.set_detail(detail_in_state("failed"));
+
+        // Unmask does nothing for a unit that is not masked
+        state.handle_action(Action::UnmaskService);
+        assert!(state.confirmation_dialog.is_none());
+
+        state.handle_action(Action::ResetFailed);
+        assert_eq!(
+            state.handle_action(Action::ConfirmAction),
+            DetailAction::ExecuteServiceControl {
+                service: "crashy.service".to_string(),
+                operation: "reset-failed".to_string(),
+                scope: ServiceScope::System,
+            }
+        );
+
+        state.handle_action(Action::MaskService);
+        assert_eq!(state.confirmation_dialog.as_ref().unwrap().operation, "mask");
+        state.handle_action(Action::CancelAction);
+
+        // Once masked, only unmask applies
+        let mut service = state.detail.as_ref().unwrap().service.clone();
+        service.load_state = "masked".to_string();
+        service.active_state = "inactive".to_string();
+        assert!(service.is_masked());
+        state.update_service(service);
+
+        state.handle_action(Action::MaskService);
+        state.handle_action(Action::ResetFailed);
+        assert!(state.confirmation_dialog.is_none());
+        state.handle_action(Action::UnmaskService);
+        assert_eq!(
+            state.handle_action(Action::ConfirmAction),
+            DetailAction::ExecuteServiceControl {
+                service: "crashy.service".to_string(),
+                operation: "unmask".to_string(),
+                scope: ServiceScope::System,
+            }
+        );
+    }
+
+    #[test]
+    fn test_detail_kill_dialog() {
+        use crate::systemd::{KillSignal, KillTarget};
+        use crate::ui::DetailAction;
+
+        // Nothing to signal while the unit is not running
+        let mut state = DetailState::new();
+        state.set_detail(detail_in_state("failed"));
+        state.handle_action(Action::KillService);
+        assert!(state.kill_dialog.is_none());
+
+        state.set_detail(detail_in_state("active"));
+        state.handle_action(Action::KillService);
+        assert_eq!(state.kill_dialog.as_ref().unwrap().signal(), KillSignal::Hup);
+
+        // Pick SIGUSR1 for all processes
+        for _ in 0..3 {
+            state.handle_action(Action::MoveDown);
+        }
+        state.handle_action(Action::NextTab);
+        state.handle_action(Action::NextTab);
+        assert_eq!(state.kill_dialog.as_ref().unwrap().target, KillTarget::All);
+        assert_eq!(state.handle_action(Action::Select), DetailAction::None);
+        assert!(state.kill_dialog.is_none());
+        assert_eq!(
+            state.confirmation_dialog.as_ref().unwrap().message,
+            "Send SIGUSR1 to all processes of 'crashy.service'?"
+        );
+
+        assert_eq!(
+            state.handle_action(Action::ConfirmAction),
+            DetailAction::KillUnit {
+                service: "crashy.service".to_string(),
+                scope: ServiceScope::System,
+                target: KillTarget::All,
+                signal: KillSignal::Usr1,
+            }
+        );
+
+        // Declining the confirmation forgets the chosen signal
+        state.handle_action(Action::KillService);
+        state.handle_action(Action::Select);
+        state.handle_action(Action::CancelAction);
+        state.handle_action(Action::RestartService);
+        assert!(matches!(
+            state.handle_action(Action::ConfirmAction),
+            DetailAction::ExecuteServiceControl { .. }
+        ));
+    }
+
+    #[test]
+    fn test_detail_freeze_toggle() {
+        use crate::ui::DetailAction;
+
+        let mut state = DetailState::new();
+        state.set_detail(detail_in_state("active"));
+        state.handle_action(Action::ToggleFreeze);
+        assert_eq!(
+            state.handle_action(Action::ConfirmAction),
+            DetailAction::ExecuteServiceControl {
+                service: "crashy.service".to_string(),
+                operation: "freeze".to_string(),
+                scope: ServiceScope::System,
+            }
+        );
+
+        // A frozen unit is still "active"; the same key thaws it
+        let mut service = state.detail.as_ref().unwrap().service.clone();
+        service.freezer_state = "frozen".to_string();
+        assert!(service.is_frozen());
+        state.update_service(service);
+        state.handle_action(Action::ToggleFreeze);
+        assert_eq!(state.confirmation_dialog.as_ref().unwrap().operation, "thaw");
+
+        // Stopped units have nothing to freeze
+        let mut state = DetailState::new();
+        state.set_detail(detail_in_state("inactive"));
+        state.handle_action(Action::ToggleFreeze);
+        assert!(state.confirmation_dialog.is_none());
+    }
+
+    #[test]
+    fn test_limits_editor() {
+        use crate::systemd::ResourceLimit;
+
+        let mut detail = detail_in_state("active");
+        detail.memory_limit = 1024 * 1024 * 1024;
+        detail.tasks_max = 512;
+
+        let mut state = DetailState::new();
+        state.set_detail(detail);
+        state.handle_action(Action::EditLimits);
+        let editor = state.limits_editor.as_mut().unwrap();
+
+        // Prefilled from the detail
+        assert_eq!(editor.fields[0].input, "1G");
+        assert_eq!(editor.fields[4].input, "512");
+        assert!(editor.runtime);
+        assert!(editor.changes().is_none());
+        assert_eq!(editor.error.as_deref(), Some("No limits changed"));
+
+        // Replace MemoryMax and add a CPU quota
+        editor.backspace();
+        editor.backspace();
+        for c in "768M".chars() {
+            editor.handle_char(c);
+        }
+        editor.next_field();
+        editor.next_field();
+        editor.fields[2].input.clear();
+        for c in "50".chars() {
+            editor.handle_char(c);
+        }
+
+        // The bad field is selected for correction
+        assert!(editor.changes().is_none());
+        assert_eq!(editor.selected, 2);
+        editor.handle_char('%');
+        editor.toggle_runtime();
+        assert!(!editor.runtime);
+        assert_eq!(
+            editor.changes(),
+            Some(vec![
+                (ResourceLimit::MemoryMax, 768 * 1024 * 1024),
+                (ResourceLimit::CPUQuota, 500_000),
+            ])
+        );
+    }
+
+    #[test]
+    fn test_detail_edit_override_and_diff_view() {
+        use crate::diff::diff_lines;
+        use crate::ui::{DetailAction, DiffAction, DiffState};
+
+        let mut state = DetailState::new();
+        assert_eq!(state.handle_action(Action::EditOverride), DetailAction::None);
+
+        state.set_detail(detail_in_state("active"));
+        assert_eq!(
+            state.handle_action(Action::EditOverride),
+            DetailAction::EditOverride {
+                service: "crashy.service".to_string(),
+                scope: ServiceScope::System,
+            }
+        );
+
+        let lines = diff_lines("", "[Service]\nNice=5\n");
+        let mut diff = DiffState::new("override.conf".to_string(), "crashy.service".to_string(), ServiceScope::System, lines);
+        diff.handle_action(Action::MoveBottom);
+        assert_eq!(diff.scroll, 1);
+        diff.handle_action(Action::MoveDown);
+        assert_eq!(diff.scroll, 1);
+        diff.handle_action(Action::MoveTop);
+        assert_eq!(diff.scroll, 0);
+        assert_eq!(diff.handle_action(Action::GoBack), DiffAction::GoBack("crashy.service".to_string(), ServiceScope::System));
+    }
+
+    #[test]
+    fn test_unit_file_view() {
+        use crate::systemd::UnitFile;
+        use crate::ui::{DetailAction, UnitFileAction, UnitFilePane, UnitFileView};
+
+        let mut state = DetailState::new();
+        let mut detail = detail_in_state("active");
+        detail.drop_in_paths = vec!["/etc/systemd/system/crashy.service.d/override.conf".to_string()];
+        state.set_detail(detail);
+        assert_eq!(
+            state.handle_action(Action::ViewUnitFile),
+            DetailAction::ViewUnitFile {
+                service: "crashy.service".to_string(),
+                scope: ServiceScope::System,
+                fragment: "/etc/systemd/system/crashy.service".to_string(),
+                drop_ins: vec!["/etc/systemd/system/crashy.service.d/override.conf".to_string()],
+            }
+        );
+
+        let files = vec![
+            UnitFile {
+                path: "/etc/systemd/system/crashy.service".to_string(),
+                content: "[Service]\nRestart=no\n".to_string(),
+                error: None,
+            },
+            UnitFile {
+                path: "/etc/systemd/system/crashy.service.d/override.conf".to_string(),
+                content: "[Service]\nRestart=always\n".to_string(),
+                error: None,
+            },
+        ];
+        let mut view = UnitFileView::new("crashy.service".to_string(), ServiceScope::System, files);
+        assert_eq!(view.overridden_by(0, 1).map(|f| f.short_name()), Some("crashy.service.d/override.conf".to_string()));
+        assert!(view.overridden_by(1, 1).is_none());
+
+        // Header + 2 lines, blank, header + 2 lines
+        assert_eq!(view.lines().len(), 7);
+        view.handle_action(Action::MoveBottom);
+        assert_eq!(view.scroll, 6);
+
+        view.handle_action(Action::NextTab);
+        assert_eq!(view.pane, UnitFilePane::Effective);
+        assert_eq!(view.scroll, 0);
+        let text: Vec<String> = view
+            .lines()
+            .iter()
+            .map(|line| line.spans.iter().map(|s| s.content.as_ref()).collect())
+            .collect();
+        assert_eq!(text, vec!["[Service]", "Restart=always  # crashy.service.d/override.conf"]);
+
+        assert_eq!(view.handle_action(Action::GoBack), UnitFileAction::GoBack("crashy.service".to_string(), ServiceScope::System));
+    }
+
+    #[test]
+    fn test_detail_vendor_diff() {
+        use crate::ui::DetailAction;
+
+        let mut state = DetailState::new();
+        let mut detail = detail_in_state("active");
+        assert!(detail.local_changes().is_empty());
+        state.set_detail(detail.clone());
+
+        // Nothing to compare without a shadowed vendor copy
+        assert_eq!(state.handle_action(Action::VendorDiff), DetailAction::None);
+
+        // Only drop-ins under /etc/systemd or ~/.config/systemd are local changes
+        detail.drop_in_paths = vec![
+            "/etc/systemd/system/crashy.service.d/a.conf".to_string(),
+            "/etc/systemd/system/crashy.service.d/b.conf".to_string(),
+            "/usr/lib/systemd/system/crashy.service.d/10-vendor.conf".to_string(),
+            "/run/systemd/system/crashy.service.d/50-runtime.conf".to_string(),
+        ];
+        detail.unit_file_state = "linked-runtime".to_string();
+        assert_eq!(detail.local_changes(), vec!["2 drop-ins", "linked"]);
+        detail.drop_in_paths.clear();
+
+        detail.vendor_path = "/usr/lib/systemd/system/crashy.service".to_string();
+        detail.unit_file_state = "masked".to_string();
+        state.set_detail(detail);
+        assert_eq!(
+            state.handle_action(Action::VendorDiff),
+            DetailAction::VendorDiff {
+                service: "crashy.service".to_string(),
+                scope: ServiceScope::System,
+                vendor: "/usr/lib/systemd/system/crashy.service".to_string(),
+                local: "/etc/systemd/system/crashy.service".to_string(),
+                notes: vec!["overrides vendor file".to_string(), "masked".to_string()],
+            }
+        );
+    }
+
+    #[test]
+    fn test_run_dialog() {
+        use crate::systemd::TransientKind;
+        use crate::ui::RunField;
+
+        let mut dashboard = DashboardState::new();
+        dashboard.handle_action(Action::RunTransient);
+        let dialog = dashboard.run_dialog.as_mut().unwrap();
+        assert_eq!(dialog.scope, ServiceScope::User);
+
+        // Missing command keeps the dialog on the Command field
+        dialog.selected = 3;
+        assert!(dialog.spec().is_none());
+        assert_eq!(dialog.field(), RunField::Command);
+        for c in "sh -c 'echo hi'".chars() {
+            dialog.handle_char(c);
+        }
+
+        // Choice fields toggle instead of taking text
+        dialog.next_field();
+        dialog.next_field();
+        dialog.next_field();
+        assert_eq!(dialog.field(), RunField::Kind);
+        dialog.handle_char('x');
+        dialog.toggle_choice();
+        assert_eq!(dialog.kind, TransientKind::Scope);
+        dialog.next_field();
+        dialog.toggle_choice();
+        assert_eq!(dialog.scope, ServiceScope::System);
+
+        dialog.memory_max = "lots".to_string();
+        assert!(dialog.spec().is_none());
+        assert_eq!(dialog.field(), RunField::MemoryMax);
+        dialog.memory_max = "256M".to_string();
+        dialog.runtime_max = "forever".to_string();
+        assert!(dialog.spec().is_none());
+        assert_eq!(dialog.field(), RunField::RuntimeMaxSec);
+        dialog.runtime_max = "10min".to_string();
+        dialog.name = "hello".to_string();
+
+        let spec = dialog.spec().unwrap();
+        assert_eq!(spec.name, "hello.scope");
+        assert_eq!(spec.argv[1..], ["-c", "echo hi"]);
+        assert_eq!(spec.description, "sysprox run: sh -c 'echo hi'");
+        assert_eq!(spec.memory_max, Some(256 << 20));
+        assert_eq!(spec.cpu_quota, None);
+        assert_eq!(spec.runtime_max_usec, Some(600_000_000));
+
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        terminal.draw(|f| dashboard.render(f, f.area(), true)).unwrap();
+        let screen: String = terminal.backend().buffer().content().iter().map(|c| c.symbol()).collect();
+        assert!(screen.contains("Run transient unit"));
+        assert!(screen.contains("< scope >"));
+    }
+
+    #[test]
+    fn test_detail_metrics_sparklines() {
+        use crate::systemd::{Metrics, MetricsSnapshot};
+
+        let mut state = DetailState::new();
+        state.set_detail(detail_in_state("active"));
+
+        let start = chrono::Utc::now();
+        let history: Vec<MetricsSnapshot> = (0..5)
+            .map(|i| MetricsSnapshot {
+                timestamp: start + chrono::Duration::seconds(i * 30),
+                metrics: Metrics {
+                    memory_current: (i as u64 + 1) << 20,
+                    tasks_current: 3,
+                    ..Default::default()
+                },
+                cpu_percent: i as f64 * 12.5,
+                cpu_percent_machine: i as f64 * 3.125,
+            })
+            .collect();
+
+        // Samples for another unit are ignored
+        state.set_metrics_history("other.service", ServiceScope::System, history.clone());
+        assert!(state.metrics_history.is_empty());
+        // So are samples for the same name in the other scope
+        state.set_metrics_history("crashy.service", ServiceScope::User, history.clone());
+        assert!(state.metrics_history.is_empty());
+
+        let mut terminal = Terminal::new(TestBackend::new(120, 50)).unwrap();
+        terminal.draw(|f| state.render(f, f.area())).unwrap();
+        let screen: String = terminal.backend().buffer().content().iter().map(|c| c.symbol()).collect();
+        assert!(screen.contains("CPU: waiting for samples"));
+
+        state.set_metrics_history("crashy.service", ServiceScope::System, history);
+        assert_eq!(state.metrics_history.len(), 5);
+        terminal.draw(|f| state.render(f, f.area())).unwrap();
+        let screen: String = terminal.backend().buffer().content().iter().map(|c| c.symbol()).collect();
+        assert!(screen.contains("Memory (2m) now 5 MB  peak 5 MB"));
+        assert!(screen.contains("Tasks (2m) now 3"));
+        assert!(screen.contains("CPU (2m) now 50.0%  peak 50.0%"));
+    }
+
+    #[test]
+    fn test_detail_io_rates() {
+        use crate::systemd::{Metrics, MetricsSnapshot};
+
+        let mut state = DetailState::new();
+        state.set_detail(detail_in_state("active"));
+
+        let render = |state: &mut DetailState| {
+            let mut terminal = Terminal::new(TestBackend::new(120, 50)).unwrap();
+            terminal.draw(|f| state.render(f, f.area())).unwrap();
+            terminal.backend().buffer().content().iter().map(|c| c.symbol()).collect::<String>()
+        };
+        // No row is allocated until a sample has disk or network accounting
+        let screen = render(&mut state);
+        assert!(!screen.contains("I/O & Network"));
+        assert!(screen.contains("Dependencies"));
+        assert!(screen.contains("Service Configuration"));
+
+        let start = chrono::Utc::now();
+        let snapshot = |secs: i64, metrics: Metrics| MetricsSnapshot {
+            timestamp: start + chrono::Duration::seconds(secs),
+            metrics,
+            cpu_percent: 0.0,
+            cpu_percent_machine: 0.0,
+        };
+        let first = Metrics {
+            io_read_bytes: 1 << 20,
+            io_write_bytes: 0,
+            ip_ingress_bytes: u64::MAX,
+            ip_egress_bytes: u64::MAX,
+            memory_peak: 64 << 20,
+            memory_swap_current: 0,
+            ..Default::default()
+        };
+        // 4 MiB read and 1 MiB written over 2s
+        let second = Metrics {
+            io_read_bytes: 5 << 20,
+            io_write_bytes: 1 << 20,
+            ..first
+        };
+
+        // One sample: totals but no rates yet
+        state.set_metrics_history("crashy.service", ServiceScope::System, vec![snapshot(0, first)]);
+        let screen = render(&mut state);
+        assert!(screen.contains("read …"));
+        assert!(screen.contains("(1.0 MiB read, 0 B written)"));
+
+        state.set_metrics_history("crashy.service", ServiceScope::System, vec![snapshot(0, first), snapshot(2, second)]);
+        let screen = render(&mut state);
+        assert!(screen.contains("read 2.0 MiB/s"));
+        assert!(screen.contains("write 512.0 KiB/s"));
+        assert!(screen.contains("IP accounting is off for this unit (IPAccounting=yes)"));
+        assert!(screen.contains("peak 64.0 MiB"));
+        assert!(screen.contains("swap  0 B"));
+
+        let no_io = Metrics {
+            io_read_bytes: u64::MAX,
+            io_write_bytes: u64::MAX,
+            ip_ingress_bytes: 2048,
+            ip_egress_bytes: 1024,
+            memory_peak: u64::MAX,
+            ..Default::default()
+        };
+        state.set_metrics_history("crashy.service", ServiceScope::System, vec![snapshot(0, no_io)]);
+        let screen = render(&mut state);
+        assert!(screen.contains("IO accounting is off for this unit (IOAccounting=yes)"));
+        assert!(screen.contains("(2.0 KiB in, 1.0 KiB out)"));
+        assert!(screen.contains("peak n/a"));
+
+        let unaccounted = Metrics {
+            io_read_bytes: u64::MAX,
+            io_write_bytes: u64::MAX,
+            ip_ingress_bytes: u64::MAX,
+            ip_egress_bytes: u64::MAX,
+            ..Default::default()
+        };
+        state.set_metrics_history("crashy.service", ServiceScope::System, vec![snapshot(0, unaccounted)]);
+        let screen = render(&mut state);
+        assert!(!screen.contains("I/O & Network"));
+        assert!(screen.contains("Service Configuration"));
+    }
+
+    #[test]
+    fn test_detail_pressure_section() {
+        use crate::systemd::cgroup::{MemoryBreakdown, MemoryEvents, Pressure, PressureLine};
+        use crate::systemd::CgroupStats;
+
+        let mut state = DetailState::new();
+        state.set_detail(detail_in_state("active"));
+        let render = |state: &mut DetailState| {
+            let mut terminal = Terminal::new(TestBackend::new(120, 50)).unwrap();
+            terminal.draw(|f| state.render(f, f.area())).unwrap();
+            terminal.backend().buffer().content().iter().map(|c| c.symbol()).collect::<String>()
+        };
+        // No row is allocated until cgroup files have been read
+        let screen = render(&mut state);
+        assert!(!screen.contains("Pressure (% stalled, avg10/60/300)"));
+        assert!(screen.contains("Dependencies"));
+
+        let line = |avg10: f64| PressureLine { avg10, avg60: 1.0, avg300: 0.5, total_usec: 0 };
+        let stats = CgroupStats {
+            cpu_pressure: Some(Pressure { some: line(2.5), full: None }),
+            memory_pressure: Some(Pressure { some: line(12.0), full: Some(line(8.25)) }),
+            io_pressure: None,
+            memory_stat: Some(MemoryBreakdown { anon: 10 << 20, file: 5 << 20, kernel: 1 << 20, sock: 0 }),
+            memory_events: Some(MemoryEvents { oom: 0, oom_kill: 2, high: 3, max: 1 }),
+        };
+
+        // Reads for another unit are ignored
+        state.set_cgroup_stats("other.service", ServiceScope::System, Some(stats.clone()));
+        assert!(state.cgroup_stats.is_none());
+        state.set_cgroup_stats("crashy.service", ServiceScope::User, Some(stats.clone()));
+        assert!(state.cgroup_stats.is_none());
+
+        state.set_cgroup_stats("crashy.service", ServiceScope::System, Some(stats));
+        let screen = render(&mut state);
+        assert!(screen.contains("CPU     some  2.50   1.00   0.50"));
+        assert!(screen.contains("Memory  some 12.00   1.00   0.50   full  8.25   1.00   0.50"));
+        assert!(screen.contains("IO      n/a"));
+        assert!(screen.contains("anon 10.0 MiB  file 5.0 MiB"));
+        assert!(screen.contains("kernel 1.0 MiB  sock 0 B"));
+        assert!(screen.contains("oom 0  oom_kill 2  high 3  max 1"));
+        assert!(screen.contains("Service Configuration"));
+    }
+
+    #[test]
+    fn test_detail_remove_unit() {
+        let mut state = DetailState::new();
+        let mut detail = detail_in_state("active");
+
+        // Packaged units cannot be removed
+        detail.load_path = "/usr/lib/systemd/system/crashy.service".to_string();
+        assert!(!detail.is_removable());
+        state.set_detail(detail.clone());
+        state.handle_action(Action::RemoveUnit);
+        assert!(state.remove_dialog.is_none());
+
+        // Nor can units in subdirectories of /etc/systemd/system
+        detail.load_path = "/etc/systemd/system/multi-user.target.wants/crashy.service".to_string();
+        assert!(!detail.is_removable());
+
+        // Nor can a local copy hiding a packaged unit (systemctl edit --full)
+        detail.load_path = "/etc/systemd/system/crashy.service".to_string();
+        detail.vendor_path = "/usr/lib/systemd/system/crashy.service".to_string();
+        assert!(!detail.is_removable());
+        state.set_detail(detail.clone());
+        state.handle_action(Action::RemoveUnit);
+        assert!(state.remove_dialog.is_none());
+
+        detail.vendor_path.clear();
+        assert!(detail.is_removable());
+        state.set_detail(detail);
+        state.handle_action(Action::RemoveUnit);
+
+        let dialog = state.remove_dialog.as_mut().unwrap();
+        assert_eq!(dialog.path, "/etc/systemd/system/crashy.service");
+        for c in "crashy".chars() {
+            dialog.handle_char(c);
+        }
+        assert!(!dialog.confirmed());
+        for c in ".servicx".chars() {
+            dialog.handle_char(c);
+        }
+        dialog.backspace();
+        dialog.handle_char('e');
+        assert!(dialog.confirmed());
+    }
+
+    #[test]
+    fn test_new_service_form_lint() {
+        use crate::ui::NewServiceForm;
+
+        let mut form = NewServiceForm::new();
+        form.name = "web".to_string();
+        form.exec_start = "bin/web --port 80".to_string();
+        form.environment = "1BAD=x".to_string();
+
+        let error = form.validate().unwrap_err();
+        assert_eq!(error, "2 error(s) in the unit file, see the marked fields");
+        let keys: Vec<_> = form.lint_issues.iter().filter_map(|i| i.key.clone()).collect();
+        assert_eq!(keys, vec!["ExecStart", "Environment"]);
+
+        // Typing clears the stale findings
+        form.handle_key('x');
+        assert!(form.lint_issues.is_empty());
+
+        form.exec_start = "/bin/sh -c true".to_string();
+        form.environment = "GOOD=1".to_string();
+        assert!(form.validate().is_ok());
+        assert_eq!(form.description, "User service: web");
+    }
+
+    #[test]
+    fn test_new_service_wizard() {
+        use crate::ui::NewServiceForm;
+
+        let mut form = NewServiceForm::new();
+        form.name = "backup".to_string();
+        form.exec_start = "/bin/sh -c true".to_string();
+
+        // Tab to the Scope selector and flip it with the arrows
+        form.handle_key('\t');
+        form.handle_key('\t');
+        form.handle_special_key("down");
+        assert_eq!(form.scope, ServiceScope::System);
+        form.handle_special_key("down");
+        assert_eq!(form.scope, ServiceScope::System);
+
+        // Type selector is next; Shift+Tab goes back to Scope
+        form.handle_key('\t');
+        form.handle_special_key("down");
+        assert_eq!(form.service_type, "exec");
+        form.handle_special_key("backtab");
+        form.handle_special_key("up");
+        assert_eq!(form.scope, ServiceScope::User);
+
+        // User= is only meaningful for system services
+        form.user = "backup".to_string();
+        assert_eq!(form.validate().unwrap_err(), "User= and Group= need a system-scope service");
+        form.scope = ServiceScope::System;
+
+        form.memory_max = "lots".to_string();
+        assert!(form.validate().is_err());
+        form.memory_max = "512M".to_string();
+
+        form.restart_sec = "soon".to_string();
+        assert_eq!(form.validate().unwrap_err(), "1 error(s) in the unit file, see the marked fields");
+        form.restart_sec = "1min 30s".to_string();
+
+        form.service_type = "oneshot".to_string();
+        form.hardening = "strict".to_string();
+        form.on_calendar = "daily".to_string();
+        assert!(form.validate().is_ok());
+        assert_eq!(form.description, "System service: backup");
+
+        let service = form.generate_service_file();
+        assert!(service.contains("Type=oneshot\n"));
+        assert!(service.contains("User=backup\n"));
+        assert!(service.contains("RestartSec=1min 30s\n"));
+        assert!(service.contains("MemoryMax=512M\n"));
+        assert!(service.contains("ProtectSystem=strict\n"));
+        // The timer starts the service, so it is not enabled itself
+        assert!(!service.contains("[Install]"));
+
+        let timer = form.generate_timer_file().unwrap();
+        assert!(timer.contains("OnCalendar=daily\n"));
+        assert!(timer.contains("WantedBy=timers.target\n"));
+
+        form.on_calendar.clear();
+        assert!(form.generate_timer_file().is_none());
+        assert!(form.generate_service_file().contains("WantedBy=multi-user.target\n"));
+    }
+
+    #[test]
+    fn test_new_service_preview() {
+        use crate::diff::DiffLine;
+        use crate::ui::NewServiceForm;
+
+        let mut form = NewServiceForm::new();
+        form.name = "preview".to_string();
+        form.description = "Preview".to_string();
+        form.exec_start = "/bin/sh -c true".to_string();
+
+        // Focus follows the field being edited
+        assert!(!form.is_focused_line("Description=Preview"));
+        form.handle_key('\t');
+        assert!(form.is_focused_line("Description=Preview"));
+        form.handle_key('\t');
+        assert!(form.is_focused_line("WantedBy=default.target"));
+        assert!(!form.is_focused_line("[Install]"));
+
+        // Live lint of the file as it would be written
+        form.exec_start = "nonexistent-sysprox-binary".to_string();
+        let (service, timer) = form.preview_issues();
+        assert!(service.iter().any(|i| i.key.as_deref() == Some("ExecStart")));
+        assert!(timer.is_empty());
+        form.exec_start = "/bin/sh -c true".to_string();
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("preview.service");
+        assert!(form.check_overwrite_in(dir.path()));
+
+        // Identical content is not worth confirming
+        std::fs::write(&path, form.generate_service_file()).unwrap();
+        assert!(form.check_overwrite_in(dir.path()));
+
+        std::fs::write(&path, form.generate_service_file().replace("Restart=no", "Restart=always")).unwrap();
+        assert!(!form.check_overwrite_in(dir.path()));
+        let diffs = form.overwrite_diff.clone().unwrap();
+        assert_eq!(diffs.len(), 1);
+        assert_eq!(diffs[0].0, "preview.service");
+        assert!(diffs[0].1.contains(&DiffLine::Removed("Restart=always".to_string())));
+        assert!(diffs[0].1.contains(&DiffLine::Added("Restart=no".to_string())));
+
+        // Second Ctrl+S confirms; editing the form asks again
+        assert!(form.check_overwrite_in(dir.path()));
+        form.handle_key('x');
+        assert!(form.overwrite_diff.is_none());
+        assert!(!form.check_overwrite_in(dir.path()));
+
+        // An existing timer is diffed too
+        form.overwrite_diff = None;
+        form.on_calendar = "daily".to_string();
+        let timer = form.generate_timer_file().unwrap();
+        std::fs::write(dir.path().join("preview.timer"), timer.replace("daily", "weekly")).unwrap();
+        assert!(!form.check_overwrite_in(dir.path()));
+        let diffs = form.overwrite_diff.clone().unwrap();
+        let files: Vec<&str> = diffs.iter().map(|(file, _)| file.as_str()).collect();
+        assert_eq!(files, vec!["preview.service", "preview.timer"]);
+        assert!(diffs[1].1.contains(&DiffLine::Added("OnCalendar=daily".to_string())));
+
+        let mut terminal = Terminal::new(TestBackend::new(140, 40)).unwrap();
+        terminal.draw(|f| form.render(f, f.area())).unwrap();
+        let screen: String = terminal.backend().buffer().content().iter().map(|c| c.symbol()).collect();
+        assert!(screen.contains("Overwrite preview.service and preview.timer?"));
+        assert!(screen.contains("+ Restart=no"));
+        assert!(screen.contains("+ OnCalendar=daily"));
+    }
+
     #[test]
     fn test_logs_state() {
-        let mut state = LogsState::new("test.service".to_string());
+        let mut state = LogsState::new("test.service".to_string(), ServiceScope::System);
         assert_eq!(state.service_name, "test.service");
         assert!(state.lines.is_empty());
         assert_eq!(state.offset, 0);
@@ -420,6 +1504,17 @@ mod tests {
         assert_eq!(state_icon("failed"), "✗");
         assert_eq!(state_icon("inactive"), "○");
         assert_eq!(state_icon("activating"), "◐");
+
+        // Masked units stand out regardless of their active state
+        assert_eq!(unit_emoji("inactive", "masked"), "🚫");
+        assert_eq!(unit_emoji("active", "loaded"), status_emoji("active"));
+        assert_eq!(unit_state_color("inactive", "masked"), MASKED);
+        assert_eq!(load_state_color("masked"), MASKED);
+
+        assert_eq!(freezer_color("frozen"), Some(FROZEN));
+        assert_eq!(freezer_color("thawing"), Some(WARNING));
+        assert_eq!(freezer_color("running"), None);
+        assert_eq!(freezer_color(""), None);
     }
 
     #[test]
@@ -436,6 +1531,8 @@ mod tests {
                 pid: 1234,
                 enabled: true,
                 scope: ServiceScope::System,
+                kind: UnitKind::Service,
+                freezer_state: String::new(),
                 loaded_at: chrono::Utc::now(),
             },
         ]);
@@ -473,6 +1570,117 @@ mod tests {
         assert_eq!(filter1, filter2);
     }
 
+    #[test]
+    fn test_dashboard_resource_columns() {
+        use crate::systemd::UnitResources;
+        use crate::ui::dashboard::{format_memory, format_uptime};
+        use crate::ui::SortField;
+
+        let make = |name: &str, active_state: &str| Service {
+            id: format!("system:{}", name),
+            name: name.to_string(),
+            description: "Test Service".to_string(),
+            load_state: "loaded".to_string(),
+            active_state: active_state.to_string(),
+            sub_state: "running".to_string(),
+            pid: 0,
+            enabled: true,
+            scope: ServiceScope::System,
+            kind: UnitKind::Service,
+            freezer_state: String::new(),
+            loaded_at: chrono::Utc::now(),
+        };
+        let names = |state: &DashboardState| -> Vec<String> {
+            state.filtered_services().iter().map(|s| s.name.clone()).collect()
+        };
+
+        let mut state = DashboardState::new();
+        state.set_services(vec![
+            make("alpha.service", "active"),
+            make("beta.service", "active"),
+            make("gamma.service", "active"),
+            make("idle.service", "inactive"),
+        ]);
+
+        // Columns off: nothing to poll
+        assert!(state.resource_targets().is_empty());
+        state.handle_action(Action::ToggleResourceColumns);
+        assert!(state.columns.show_resources);
+        let targets: Vec<_> = state.resource_targets().into_iter().map(|(name, _)| name).collect();
+        assert_eq!(targets, vec!["alpha.service", "beta.service", "gamma.service"]);
+
+        let now = chrono::Utc::now();
+        let sample = |cpu: f64, memory: u64, tasks: u64, uptime_secs: i64| UnitResources {
+            cpu_percent: Some(cpu),
+            memory_current: Some(memory),
+            tasks_current: Some(tasks),
+            active_since: Some(now - chrono::Duration::seconds(uptime_secs)),
+        };
+        // Keep beta selected across the re-sort
+        state.handle_action(Action::MoveDown);
+        state.set_resources(
+            [
+                ("system:alpha.service".to_string(), sample(5.0, 300 << 20, 2, 90)),
+                ("system:beta.service".to_string(), sample(80.0, 100 << 20, 9, 7200)),
+                ("system:gamma.service".to_string(), sample(20.0, 200 << 20, 4, 30)),
+            ]
+            .into_iter()
+            .collect(),
+        );
+
+        // Resource sorts start with the biggest consumer; unsampled units go last
+        state.handle_action(Action::CycleSort);
+        assert_eq!(state.columns.sort, SortField::Cpu);
+        assert!(state.columns.sort_descending);
+        assert_eq!(names(&state), vec!["beta.service", "gamma.service", "alpha.service", "idle.service"]);
+        assert_eq!(state.get_selected_service().unwrap().name, "beta.service");
+
+        state.handle_action(Action::ReverseSort);
+        assert_eq!(names(&state), vec!["alpha.service", "gamma.service", "beta.service", "idle.service"]);
+
+        state.handle_action(Action::CycleSort);
+        assert_eq!(state.columns.sort, SortField::Memory);
+        assert_eq!(names(&state)[0], "alpha.service");
+        state.handle_action(Action::CycleSort);
+        assert_eq!(names(&state)[0], "beta.service");
+        state.handle_action(Action::CycleSort);
+        assert_eq!(state.columns.sort, SortField::Uptime);
+        assert_eq!(names(&state), vec!["beta.service", "alpha.service", "gamma.service", "idle.service"]);
+
+        let mut terminal = Terminal::new(TestBackend::new(160, 20)).unwrap();
+        terminal.draw(|f| state.render(f, f.area(), true)).unwrap();
+        let screen: String = terminal.backend().buffer().content().iter().map(|c| c.symbol()).collect();
+        assert!(screen.contains("Uptime ▼"));
+        assert!(screen.contains("80.0%"));
+        assert!(screen.contains("100.0M"));
+        assert!(screen.contains("2h 0m"));
+        assert!(screen.contains("Sort: Uptime ▼"));
+
+        // Back to names, then hiding the columns resets the sort
+        state.handle_action(Action::CycleSort);
+        assert_eq!(state.columns.sort, SortField::Name);
+        assert!(!state.columns.sort_descending);
+        state.handle_action(Action::ReverseSort);
+        assert_eq!(names(&state)[0], "idle.service");
+        state.handle_action(Action::ToggleResourceColumns);
+        assert_eq!(state.columns, Default::default());
+        assert!(state.resources.is_empty());
+        assert_eq!(names(&state)[0], "alpha.service");
+
+        // Cycling the sort turns the columns on
+        state.handle_action(Action::CycleSort);
+        assert!(state.columns.show_resources);
+
+        assert_eq!(format_memory(512), "512B");
+        assert_eq!(format_memory(1536), "1.5K");
+        assert_eq!(format_memory(3 << 30), "3.0G");
+        assert_eq!(format_uptime(45), "45s");
+        assert_eq!(format_uptime(725), "12m");
+        assert_eq!(format_uptime(3 * 3600 + 300), "3h 5m");
+        assert_eq!(format_uptime(2 * 86400 + 4 * 3600), "2d 4h");
+        assert_eq!(format_uptime(-5), "0s");
+    }
+
     // Import styles for testing
     use crate::ui::styles::*;
 }
\ No newline at end of file
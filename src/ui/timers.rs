@@ -0,0 +1,327 @@
+// Timers view - timer list with next/last elapse
+
+use crate::events::Action;
+use crate::systemd::models::format_usec_span;
+use crate::systemd::{ServiceScope, Timer};
+use crate::ui::dashboard::ScopeFilter;
+use crate::ui::detail::ConfirmationDialog;
+use crate::ui::tabs::{tab_title, ListTab};
+use crate::ui::{state_color, status_emoji};
+use chrono::{DateTime, Local, Utc};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    widgets::{Block, Borders, Cell, Paragraph, Row, Table, TableState},
+    Frame,
+};
+
+#[derive(Debug)]
+pub struct TimersState {
+    pub timers: Vec<Timer>,
+    pub scope_filter: ScopeFilter,
+    pub table_state: TableState,
+    pub loading: bool,
+    pub confirmation_dialog: Option<ConfirmationDialog>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TimersAction {
+    None,
+    GoBack,
+    /// Open the detail view of the unit the timer triggers
+    ViewUnit(String, ServiceScope),
+    ExecuteServiceControl {
+        service: String,
+        operation: String,
+        scope: ServiceScope,
+    },
+}
+
+impl Default for TimersState {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimersState {
+    pub fn new() -> Self {
+        let mut table_state = TableState::default();
+        table_state.select(Some(0));
+
+        Self {
+            timers: Vec::new(),
+            scope_filter: ScopeFilter::All,
+            table_state,
+            loading: true,
+            confirmation_dialog: None,
+        }
+    }
+
+    pub fn set_timers(&mut self, mut timers: Vec<Timer>) {
+        // Soonest timer first, timers without a next elapse last
+        timers.sort_by(|a, b| match (a.next_elapse, b.next_elapse) {
+            (Some(x), Some(y)) => x.cmp(&y),
+            (Some(_), None) => std::cmp::Ordering::Less,
+            (None, Some(_)) => std::cmp::Ordering::Greater,
+            (None, None) => a.name.cmp(&b.name),
+        });
+        self.timers = timers;
+        self.loading = false;
+
+        let len = self.filtered_timers().len();
+        match self.table_state.selected() {
+            Some(idx) if idx < len => {}
+            _ => self.table_state.select(Some(0)),
+        }
+    }
+
+    pub fn filtered_timers(&self) -> Vec<&Timer> {
+        self.timers
+            .iter()
+            .filter(|t| match self.scope_filter {
+                ScopeFilter::All => true,
+                ScopeFilter::System => t.scope == ServiceScope::System,
+                ScopeFilter::User => t.scope == ServiceScope::User,
+            })
+            .collect()
+    }
+
+    pub fn get_selected_timer(&self) -> Option<&Timer> {
+        let filtered = self.filtered_timers();
+        self.table_state
+            .selected()
+            .and_then(|i| filtered.get(i).copied())
+    }
+
+    pub fn handle_action(&mut self, action: Action) -> TimersAction {
+        // Handle confirmation dialog first
+        if let Some(dialog) = &self.confirmation_dialog {
+            return match action {
+                Action::ConfirmAction => {
+                    let service = dialog.service.clone();
+                    let operation = dialog.operation.clone();
+                    let scope = dialog.scope;
+                    self.confirmation_dialog = None;
+                    TimersAction::ExecuteServiceControl { service, operation, scope }
+                }
+                Action::CancelAction | Action::GoBack => {
+                    self.confirmation_dialog = None;
+                    TimersAction::None
+                }
+                _ => TimersAction::None,
+            };
+        }
+
+        match action {
+            Action::GoBack => return TimersAction::GoBack,
+            Action::MoveUp => self.move_selection(-1),
+            Action::MoveDown => self.move_selection(1),
+            Action::MoveTop => self.table_state.select(Some(0)),
+            Action::MoveBottom => {
+                let len = self.filtered_timers().len();
+                if len > 0 {
+                    self.table_state.select(Some(len - 1));
+                }
+            }
+            Action::ToggleScope => {
+                self.scope_filter = self.scope_filter.next();
+                self.table_state.select(Some(0));
+            }
+            Action::Select => {
+                if let Some(timer) = self.get_selected_timer() {
+                    if !timer.unit.is_empty() {
+                        return TimersAction::ViewUnit(timer.unit.clone(), timer.scope);
+                    }
+                }
+            }
+            Action::StartService => {
+                if let Some(timer) = self.get_selected_timer() {
+                    if !timer.is_active() {
+                        let (name, scope) = (timer.name.clone(), timer.scope);
+                        self.show_confirmation(name.clone(), "start", scope, format!("Start timer '{}'?", name));
+                    }
+                }
+            }
+            Action::StopService => {
+                if let Some(timer) = self.get_selected_timer() {
+                    if timer.is_active() {
+                        let (name, scope) = (timer.name.clone(), timer.scope);
+                        self.show_confirmation(name.clone(), "stop", scope, format!("Stop timer '{}'?", name));
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        TimersAction::None
+    }
+
+    fn show_confirmation(&mut self, service: String, operation: &str, scope: ServiceScope, message: String) {
+        self.confirmation_dialog = Some(ConfirmationDialog {
+            service,
+            operation: operation.to_string(),
+            scope,
+            message,
+            confirmed: false,
+        });
+    }
+
+    fn move_selection(&mut self, delta: isize) {
+        let len = self.filtered_timers().len();
+        if len == 0 {
+            return;
+        }
+
+        let current = self.table_state.selected().unwrap_or(0);
+        let new_index = if delta < 0 {
+            current.saturating_sub(delta.unsigned_abs())
+        } else {
+            (current + delta as usize).min(len - 1)
+        };
+
+        self.table_state.select(Some(new_index));
+    }
+
+    pub fn render(&mut self, frame: &mut Frame, area: Rect, show_footer: bool) {
+        if let Some(dialog) = &self.confirmation_dialog {
+            dialog.render(frame, area);
+            return;
+        }
+
+        let mut constraints = vec![
+            Constraint::Length(3), // Header
+            Constraint::Min(0),    // Timers table
+        ];
+        if show_footer {
+            constraints.push(Constraint::Length(1)); // Help footer
+        }
+
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints(constraints)
+            .split(area);
+
+        self.render_header(frame, chunks[0]);
+        self.render_table(frame, chunks[1]);
+
+        if show_footer {
+            let help = Paragraph::new(
+                "[Enter] Triggered unit | [↑↓/jk] Navigate | [S] Start | [T] Stop | [m] Scope | [Tab] Next list | [q] Quit",
+            )
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(ratatui::layout::Alignment::Center);
+            frame.render_widget(help, chunks[2]);
+        }
+    }
+
+    fn render_header(&self, frame: &mut Frame, area: Rect) {
+        let active = self.timers.iter().filter(|t| t.is_active()).count();
+        let text = if self.loading {
+            "Loading timers...".to_string()
+        } else {
+            format!(
+                "Total: {} | Active: {} | Showing: {} | Scope: {}",
+                self.timers.len(),
+                active,
+                self.filtered_timers().len(),
+                self.scope_filter.label()
+            )
+        };
+
+        let header = Paragraph::new(text)
+            .style(Style::default().fg(Color::Yellow))
+            .alignment(ratatui::layout::Alignment::Center)
+            .block(Block::default().borders(Borders::ALL).title(tab_title(ListTab::Timers)));
+
+        frame.render_widget(header, area);
+    }
+
+    fn render_table(&mut self, frame: &mut Frame, area: Rect) {
+        let now = Utc::now();
+        let filtered = self.filtered_timers();
+
+        let rows: Vec<Row> = filtered
+            .iter()
+            .map(|timer| {
+                let icon = status_emoji(&timer.active_state);
+                let persistent = if timer.persistent { "✓" } else { "" };
+
+                Row::new(vec![
+                    Cell::from(format!("{} {}", icon, timer.name))
+                        .style(Style::default().fg(state_color(&timer.active_state))),
+                    Cell::from(timer.scope.label()).style(Style::default().fg(
+                        if timer.scope == ServiceScope::User { Color::LightBlue } else { Color::Gray },
+                    )),
+                    Cell::from(format_timestamp(timer.next_elapse)),
+                    Cell::from(relative_time(timer.next_elapse, now)),
+                    Cell::from(format_timestamp(timer.last_trigger)),
+                    Cell::from(relative_time(timer.last_trigger, now)),
+                    Cell::from(persistent),
+                    Cell::from(timer.unit.clone()),
+                    Cell::from(timer.schedule.join(", ")).style(Style::default().fg(Color::DarkGray)),
+                ])
+            })
+            .collect();
+
+        let widths = [
+            Constraint::Percentage(20),
+            Constraint::Length(7),
+            Constraint::Length(20),
+            Constraint::Length(12),
+            Constraint::Length(20),
+            Constraint::Length(12),
+            Constraint::Length(5),
+            Constraint::Percentage(18),
+            Constraint::Min(10),
+        ];
+
+        let table = Table::new(rows, widths)
+            .header(
+                Row::new(vec!["Timer", "Scope", "Next", "Left", "Last", "Passed", "Pers", "Triggers", "Schedule"])
+                    .style(
+                        Style::default()
+                            .bg(Color::DarkGray)
+                            .fg(Color::White)
+                            .add_modifier(Modifier::BOLD),
+                    )
+                    .bottom_margin(1),
+            )
+            .block(Block::default().title(" Timers ").borders(Borders::ALL))
+            .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+            .highlight_symbol(">> ");
+
+        frame.render_stateful_widget(table, area, &mut self.table_state);
+    }
+}
+
+/// Format an optional timestamp in local time, "-" when unset
+pub fn format_timestamp(time: Option<DateTime<Utc>>) -> String {
+    match time {
+        Some(t) => t.with_timezone(&Local).format("%a %Y-%m-%d %H:%M").to_string(),
+        None => "-".to_string(),
+    }
+}
+
+/// Describe a timestamp relative to now, e.g. "in 2h 5min" or "3d ago"
+pub fn relative_time(time: Option<DateTime<Utc>>, now: DateTime<Utc>) -> String {
+    let Some(time) = time else {
+        return "-".to_string();
+    };
+
+    let delta = time.signed_duration_since(now);
+    // Round to whole seconds; sub-second precision is noise in a list
+    let secs = delta.num_seconds();
+    if secs == 0 {
+        return "now".to_string();
+    }
+    let span = format_usec_span(secs.unsigned_abs() * 1_000_000);
+    // Keep the two most significant parts
+    let short: Vec<&str> = span.split(' ').take(2).collect();
+    let short = short.join(" ");
+
+    if secs >= 0 {
+        format!("in {}", short)
+    } else {
+        format!("{} ago", short)
+    }
+}
@@ -0,0 +1,238 @@
+// Unit file view - fragment and drop-ins (like `systemctl cat`) plus the merged config
+
+use crate::events::Action;
+use crate::systemd::ServiceScope;
+use crate::systemd::unit_file::{effective_config, overridden_lines, parse_line, EffectiveSetting, UnitFile, UnitLine};
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph, Tabs},
+    Frame,
+};
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UnitFilePane {
+    /// Every file as written on disk
+    Files,
+    /// Settings after all drop-ins are applied
+    Effective,
+}
+
+#[derive(Debug)]
+pub struct UnitFileView {
+    pub unit: String,
+    pub scope: ServiceScope,
+    pub files: Vec<UnitFile>,
+    pub settings: Vec<EffectiveSetting>,
+    /// (file, line) -> index of the file that overrides that line
+    overridden: HashMap<(usize, usize), usize>,
+    pub pane: UnitFilePane,
+    pub scroll: usize,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum UnitFileAction {
+    None,
+    GoBack(String, ServiceScope),
+}
+
+impl UnitFileView {
+    pub fn new(unit: String, scope: ServiceScope, files: Vec<UnitFile>) -> Self {
+        let settings = effective_config(&files);
+        let overridden = overridden_lines(&settings);
+
+        Self {
+            unit,
+            scope,
+            files,
+            settings,
+            overridden,
+            pane: UnitFilePane::Files,
+            scroll: 0,
+        }
+    }
+
+    /// File that overrides the given line, if any
+    pub fn overridden_by(&self, file: usize, line: usize) -> Option<&UnitFile> {
+        self.overridden.get(&(file, line)).and_then(|idx| self.files.get(*idx))
+    }
+
+    pub fn handle_action(&mut self, action: Action) -> UnitFileAction {
+        let last = self.lines().len().saturating_sub(1);
+        match action {
+            Action::GoBack | Action::Back => return UnitFileAction::GoBack(self.unit.clone(), self.scope),
+            Action::NextTab | Action::PrevTab => {
+                self.pane = match self.pane {
+                    UnitFilePane::Files => UnitFilePane::Effective,
+                    UnitFilePane::Effective => UnitFilePane::Files,
+                };
+                self.scroll = 0;
+            }
+            Action::MoveUp => self.scroll = self.scroll.saturating_sub(1),
+            Action::MoveDown => self.scroll = (self.scroll + 1).min(last),
+            Action::MoveTop => self.scroll = 0,
+            Action::MoveBottom => self.scroll = last,
+            _ => {}
+        }
+        UnitFileAction::None
+    }
+
+    /// Lines of the active pane
+    pub fn lines(&self) -> Vec<Line<'static>> {
+        match self.pane {
+            UnitFilePane::Files => self.file_lines(),
+            UnitFilePane::Effective => self.effective_lines(),
+        }
+    }
+
+    fn file_lines(&self) -> Vec<Line<'static>> {
+        if self.files.is_empty() {
+            return vec![Line::from(Span::styled(
+                "No unit file on disk (transient or generated unit)",
+                Style::default().fg(Color::DarkGray),
+            ))];
+        }
+
+        let mut lines = Vec::new();
+        for (file_idx, file) in self.files.iter().enumerate() {
+            if file_idx > 0 {
+                lines.push(Line::from(""));
+            }
+            let kind = if file.is_drop_in() { "drop-in" } else { "fragment" };
+            lines.push(Line::from(vec![
+                Span::styled(format!("# {}", file.path), Style::default().fg(Color::Magenta).add_modifier(Modifier::BOLD)),
+                Span::styled(format!("  ({})", kind), Style::default().fg(Color::DarkGray)),
+            ]));
+
+            if let Some(error) = &file.error {
+                lines.push(Line::from(Span::styled(format!("✗ Cannot read: {}", error), Style::default().fg(Color::Red))));
+                continue;
+            }
+
+            let mut continued = false;
+            for (line_no, raw) in file.content.lines().enumerate() {
+                let mut spans = vec![Span::styled(format!("{:>4} ", line_no + 1), Style::default().fg(Color::DarkGray))];
+                match self.overridden_by(file_idx, line_no) {
+                    // Continuation of a backslash-wrapped value
+                    None if continued => spans.push(Span::styled(raw.to_string(), Style::default().fg(Color::White))),
+                    Some(by) => {
+                        let dim = Style::default().fg(Color::DarkGray).add_modifier(Modifier::CROSSED_OUT);
+                        spans.push(Span::styled(raw.to_string(), dim));
+                        spans.push(Span::styled(
+                            format!("  ← overridden by {}", by.short_name()),
+                            Style::default().fg(Color::Yellow),
+                        ));
+                    }
+                    None => spans.extend(highlight_line(raw)),
+                }
+                continued = raw.trim_end().ends_with('\\') && !matches!(parse_line(raw), UnitLine::Comment(_));
+                lines.push(Line::from(spans));
+            }
+        }
+        lines
+    }
+
+    fn effective_lines(&self) -> Vec<Line<'static>> {
+        if self.settings.is_empty() {
+            return vec![Line::from(Span::styled("No settings", Style::default().fg(Color::DarkGray)))];
+        }
+
+        let mut lines = Vec::new();
+        let mut section: Option<&str> = None;
+        for setting in &self.settings {
+            if section != Some(setting.section.as_str()) {
+                if section.is_some() {
+                    lines.push(Line::from(""));
+                }
+                lines.push(Line::from(highlight_line(&format!("[{}]", setting.section))));
+                section = Some(&setting.section);
+            }
+
+            if setting.values.is_empty() {
+                let reset_by = setting
+                    .overridden
+                    .last()
+                    .and_then(|a| a.overridden_by)
+                    .and_then(|idx| self.files.get(idx))
+                    .map(|f| f.short_name())
+                    .unwrap_or_default();
+                let mut spans = highlight_line(&format!("{}=", setting.key));
+                spans.push(Span::styled(format!("  # reset to default by {}", reset_by), Style::default().fg(Color::DarkGray)));
+                lines.push(Line::from(spans));
+                continue;
+            }
+
+            for assignment in &setting.values {
+                let source = self.files.get(assignment.file).map(|f| f.short_name()).unwrap_or_default();
+                let from_drop_in = self.files.get(assignment.file).is_some_and(|f| f.is_drop_in());
+                let mut spans = highlight_line(&format!("{}={}", setting.key, assignment.value));
+                spans.push(Span::styled(
+                    format!("  # {}", source),
+                    Style::default().fg(if from_drop_in { Color::Yellow } else { Color::DarkGray }),
+                ));
+                lines.push(Line::from(spans));
+            }
+        }
+        lines
+    }
+
+    pub fn render(&self, frame: &mut Frame, area: Rect) {
+        let chunks = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(3), // Pane tabs
+                Constraint::Min(0),    // Content
+                Constraint::Length(1), // Help footer
+            ])
+            .split(area);
+
+        let drop_ins = self.files.iter().filter(|f| f.is_drop_in()).count();
+        let selected = match self.pane {
+            UnitFilePane::Files => 0,
+            UnitFilePane::Effective => 1,
+        };
+        let tabs = Tabs::new(vec![
+            format!("Files ({} + {} drop-ins)", self.files.len().saturating_sub(drop_ins), drop_ins),
+            format!("Effective ({} settings)", self.settings.len()),
+        ])
+        .select(selected)
+        .highlight_style(Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD))
+        .block(Block::default().borders(Borders::ALL).title(format!(" Unit file: {} ", self.unit)));
+        frame.render_widget(tabs, chunks[0]);
+
+        let body = Paragraph::new(self.lines().into_iter().skip(self.scroll).collect::<Vec<_>>())
+            .block(Block::default().borders(Borders::ALL));
+        frame.render_widget(body, chunks[1]);
+
+        let help = Paragraph::new("[Tab] Files/Effective | [↑↓/jk] Scroll | [g/G] Top/bottom | [Esc] Back to unit")
+            .style(Style::default().fg(Color::DarkGray))
+            .alignment(ratatui::layout::Alignment::Center);
+        frame.render_widget(help, chunks[2]);
+    }
+}
+
+/// Syntax-highlight one unit file line: sections, keys and comments
+pub fn highlight_line(raw: &str) -> Vec<Span<'static>> {
+    match parse_line(raw) {
+        UnitLine::Blank => vec![Span::raw(raw.to_string())],
+        UnitLine::Comment(_) => vec![Span::styled(
+            raw.to_string(),
+            Style::default().fg(Color::DarkGray).add_modifier(Modifier::ITALIC),
+        )],
+        UnitLine::Section(_) => vec![Span::styled(
+            raw.to_string(),
+            Style::default().fg(Color::Yellow).add_modifier(Modifier::BOLD),
+        )],
+        UnitLine::Setting { .. } => {
+            let (key, value) = raw.split_once('=').unwrap_or((raw, ""));
+            vec![
+                Span::styled(key.to_string(), Style::default().fg(Color::Cyan)),
+                Span::styled("=", Style::default().fg(Color::DarkGray)),
+                Span::styled(value.to_string(), Style::default().fg(Color::White)),
+            ]
+        }
+        UnitLine::Invalid(_) => vec![Span::styled(raw.to_string(), Style::default().fg(Color::Red))],
+    }
+}